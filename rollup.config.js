@@ -1,7 +1,50 @@
-module.exports = {
-    input: 'src/index.js',
-    output: {
-        file: 'api.js',
-        format: 'cjs'
+const path = require("path");
+
+/*
+ * The other entry points import the API from the main file instead of
+ * bundling their own copy, so they share its errors and parse information.
+ */
+const api = path.resolve(__dirname, "src/index.js");
+
+module.exports = [
+    {
+        input: 'src/index.js',
+        output: {
+            file: 'api.js',
+            format: 'cjs'
+        }
+    },
+    {
+        input: 'src/fs.js',
+        external: ['fs', api],
+        output: {
+            file: 'fs.js',
+            format: 'cjs',
+            paths: {
+                [api]: './api.js'
+            }
+        }
+    },
+    {
+        input: 'src/binary.js',
+        external: [api],
+        output: {
+            file: 'binary.js',
+            format: 'cjs',
+            paths: {
+                [api]: './api.js'
+            }
+        }
+    },
+    {
+        input: 'src/compat.js',
+        external: [api],
+        output: {
+            file: 'compat.js',
+            format: 'cjs',
+            paths: {
+                [api]: './api.js'
+            }
+        }
     }
-};
\ No newline at end of file
+];
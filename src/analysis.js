@@ -0,0 +1,366 @@
+/**
+ * @fileoverview Analyses of Momoa AST for use in lint rules.
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+import { isEquivalent } from "./compare.js";
+import { tokenize } from "./tokens.js";
+import { getWindowsPathFix } from "./fixes.js";
+import { irregularWhitespacePattern } from "./syntax.js";
+
+//-----------------------------------------------------------------------------
+// Helpers
+//-----------------------------------------------------------------------------
+
+/**
+ * Creates a location object for an offset.
+ * @param {int} offset The offset.
+ * @param {int} line The one-based line containing the offset.
+ * @param {int} lineStart The offset of the start of the line.
+ * @returns {Object} The location with `line`, `column`, and `offset`.
+ */
+function createLocation(offset, line, lineStart) {
+    return { line, column: offset - lineStart + 1, offset };
+}
+
+//-----------------------------------------------------------------------------
+// Exports
+//-----------------------------------------------------------------------------
+
+/**
+ * Finds elements of an array that are equal to an earlier element.
+ * @param {Node} node The `Array` node to check.
+ * @param {Function} [options.compare] A function that accepts two nodes and
+ *      returns `true` if they should be considered duplicates. Defaults to
+ *      `isEquivalent()`, which compares the values the nodes represent.
+ * @returns {Array<Object>} One object for each duplicate element, in
+ *      document order, with `duplicate` and `original` properties containing
+ *      the nodes and `duplicateIndex` and `originalIndex` properties
+ *      containing their positions in the array. The original is the first
+ *      equal element.
+ * @throws {TypeError} When `node` is not an `Array` node.
+ */
+export function findDuplicateElements(node, { compare = isEquivalent } = {}) {
+
+    if (!node || node.type !== "Array") {
+        throw new TypeError("Duplicate elements can only be found in an Array node.");
+    }
+
+    const duplicates = [];
+    const originalIndices = [];
+
+    node.elements.forEach((element, i) => {
+        const originalIndex = originalIndices.find(j => compare(node.elements[j], element));
+
+        if (originalIndex === undefined) {
+            originalIndices.push(i);
+        } else {
+            duplicates.push({
+                duplicate: element,
+                duplicateIndex: i,
+                original: node.elements[originalIndex],
+                originalIndex
+            });
+        }
+    });
+
+    return duplicates;
+}
+
+/**
+ * Finds members of an object whose names aren't in a list of allowed names,
+ * such as unknown keys in a configuration file, without needing a schema.
+ * @param {Node} node The `Object` node to check, or a `Document` node whose
+ *      body is an `Object`. Only the object's own members are checked.
+ * @param {Iterable<string>|Node} allowed The allowed names, either as strings
+ *      or as an `Array` node of `String` nodes, or a `Document` node whose
+ *      body is one, so the list can be kept in its own JSON file.
+ * @returns {Array<Object>} One object for each member that isn't allowed, in
+ *      document order, with a `name` property containing the member name, a
+ *      `member` property containing the `Member` node, and a `loc` property
+ *      containing the location of the member name.
+ * @throws {TypeError} When `node` is not an `Object` node or `allowed`
+ *      contains something other than strings.
+ */
+export function checkAllowedKeys(node, allowed) {
+
+    const object = node && node.type === "Document" ? node.body : node;
+    const list = allowed && allowed.type === "Document" ? allowed.body : allowed;
+
+    if (!object || object.type !== "Object") {
+        throw new TypeError("Allowed keys can only be checked in an Object node.");
+    }
+
+    const names = new Set();
+
+    for (const item of list && list.type === "Array" ? list.elements : list) {
+        const name = item && item.type === "String" ? item.value : item;
+
+        if (typeof name !== "string") {
+            throw new TypeError("Allowed keys must be strings.");
+        }
+
+        names.add(name);
+    }
+
+    return object.members
+        .filter(member => !names.has(member.name.value))
+        .map(member => ({
+            name: member.name.value,
+            member,
+            loc: member.name.loc
+        }));
+}
+
+/**
+ * Finds `\u` escapes in strings whose hex digits don't use the expected
+ * case. Escapes without letters, such as `\u0041`, never have the wrong
+ * case.
+ * @param {string} text The JSON text to check.
+ * @param {string} [options.hexCase="consistent"] The expected case:
+ *      `"lower"`, `"upper"`, or `"consistent"` to expect the case of the
+ *      first escape with letters. Escapes that mix cases never match
+ *      `"consistent"`.
+ * @param {boolean} [options.comments=false] Determines if comments are
+ *      allowed in the JSON.
+ * @returns {Array<Object>} One object for each escape with the wrong case,
+ *      in document order, with `escape` and `loc` properties.
+ * @throws {Error} When the text can't be tokenized.
+ */
+export function findHexCaseMismatches(text, { hexCase = "consistent", comments = false } = {}) {
+
+    const mismatches = [];
+    let expected = hexCase === "consistent" ? null : hexCase;
+
+    for (const token of tokenize(text, { comments })) {
+        if (token.type !== "String") {
+            continue;
+        }
+
+        const pattern = /\\(?:u[0-9a-f]{4}|.)/gi;
+        let match;
+
+        while ((match = pattern.exec(token.value))) {
+            const escape = match[0];
+            const digits = escape.slice(2);
+
+            if (escape.length !== 6 || !/[a-f]/i.test(digits)) {
+                continue;
+            }
+
+            const actual = digits === digits.toLowerCase() ? "lower"
+                : digits === digits.toUpperCase() ? "upper"
+                    : "mixed";
+
+            if (!expected && actual !== "mixed") {
+                expected = actual;
+                continue;
+            }
+
+            if (actual !== expected) {
+                const { line, column, offset } = token.loc.start;
+
+                mismatches.push({
+                    escape,
+                    loc: {
+                        start: { line, column: column + match.index, offset: offset + match.index },
+                        end: { line, column: column + match.index + 6, offset: offset + match.index + 6 }
+                    }
+                });
+            }
+        }
+    }
+
+    return mismatches;
+}
+
+/**
+ * Finds whitespace that is likely to cause problems even though it can't be
+ * seen in most editors: irregular whitespace characters, such as a no-break
+ * space, a zero-width space, or a byte order mark after the start of the
+ * text, and indentation that mixes tabs and spaces or doesn't use the
+ * expected character. Irregular whitespace inside of strings is only
+ * reported when the `strings` option is `true`.
+ * @param {string} text The text to check. It doesn't need to be valid JSON.
+ * @param {boolean} [options.comments=false] Determines if the text contains
+ *      comments, so quotes in comments aren't treated as strings.
+ * @param {string} [options.indentation="consistent"] Either `"spaces"`,
+ *      `"tabs"`, or `"consistent"` to expect the character used by the first
+ *      indented line.
+ * @param {boolean} [options.strings=false] Determines if irregular
+ *      whitespace inside of strings is reported.
+ * @returns {Array<Object>} The problems in document order, each with a
+ *      `kind` property of `"irregular-whitespace"` or `"indentation"`, a
+ *      `text` property containing the whitespace, and a `loc` property.
+ */
+export function findWhitespaceProblems(text, { comments = false, indentation = "consistent", strings = false } = {}) {
+
+    const problems = [];
+    let expected = indentation === "consistent" ? null : indentation;
+    let line = 1;
+    let lineStart = 0;
+    let inString = false;
+    let inBlockComment = false;
+    let i = 0;
+
+    function report(kind, start, end) {
+        problems.push({
+            kind,
+            text: text.slice(start, end),
+            loc: {
+                start: createLocation(start, line, lineStart),
+                end: createLocation(end, line, lineStart)
+            }
+        });
+    }
+
+    function checkIndentation() {
+        const indent = /^[ \t]*/.exec(text.slice(lineStart))[0];
+
+        if (!indent) {
+            return;
+        }
+
+        const actual = !indent.includes("\t") ? "spaces"
+            : !indent.includes(" ") ? "tabs"
+                : "mixed";
+
+        if (!expected && actual !== "mixed") {
+            expected = actual;
+            return;
+        }
+
+        if (actual !== expected) {
+            report("indentation", lineStart, lineStart + indent.length);
+        }
+    }
+
+    checkIndentation();
+
+    while (i < text.length) {
+        const c = text[i];
+
+        if (c === "\r" || c === "\n") {
+            i += c === "\r" && text[i + 1] === "\n" ? 2 : 1;
+            line++;
+            lineStart = i;
+            inString = false;
+            checkIndentation();
+            continue;
+        }
+
+        if (inString) {
+            if (c === "\\") {
+                i++;
+            } else if (c === "\"") {
+                inString = false;
+            }
+        } else if (inBlockComment) {
+            if (c === "*" && text[i + 1] === "/") {
+                inBlockComment = false;
+                i++;
+            }
+        } else if (c === "\"") {
+            inString = true;
+        } else if (comments && c === "/" && text[i + 1] === "*") {
+            inBlockComment = true;
+            i++;
+        } else if (comments && c === "/" && text[i + 1] === "/") {
+            while (i + 1 < text.length && text[i + 1] !== "\r" && text[i + 1] !== "\n") {
+                i++;
+
+                if (irregularWhitespacePattern.test(text[i])) {
+                    report("irregular-whitespace", i, i + 1);
+                }
+            }
+        }
+
+        // a byte order mark is allowed at the start of the text
+        if (irregularWhitespacePattern.test(c) && (!inString || strings) && !(i === 0 && c === "\uFEFF")) {
+            report("irregular-whitespace", i, i + 1);
+        }
+
+        i++;
+    }
+
+    return problems;
+}
+
+/**
+ * Finds strings that look like Windows paths with backslashes that aren't
+ * escaped, such as `"C:\temp"`, which is either an error or, as in this
+ * case, a different value than intended because `\t` is a tab. The text is
+ * scanned without being parsed, so strings with invalid escapes are found
+ * too.
+ * @param {string} text The text to check. It doesn't need to be valid JSON.
+ * @param {boolean} [options.comments=false] Determines if the text contains
+ *      comments, so quotes in comments aren't treated as strings.
+ * @returns {Array<Object>} One object for each string, in document order,
+ *      with a `text` property containing the string literal, a `loc`
+ *      property, and a `fix` property containing an edit that escapes the
+ *      backslashes, which can be passed to `applyEdits()`.
+ */
+export function findWindowsPaths(text, { comments = false } = {}) {
+
+    const paths = [];
+    let line = 1;
+    let lineStart = 0;
+    let i = 0;
+
+    while (i < text.length) {
+        const c = text[i];
+
+        if (c === "\r" || c === "\n") {
+            i += c === "\r" && text[i + 1] === "\n" ? 2 : 1;
+            line++;
+            lineStart = i;
+        } else if (comments && c === "/" && text[i + 1] === "/") {
+            while (i < text.length && text[i] !== "\r" && text[i] !== "\n") {
+                i++;
+            }
+        } else if (comments && c === "/" && text[i + 1] === "*") {
+            i += 2;
+
+            while (i < text.length && !(text[i] === "*" && text[i + 1] === "/")) {
+                if (text[i] === "\r" || text[i] === "\n") {
+                    i += text[i] === "\r" && text[i + 1] === "\n" ? 2 : 1;
+                    line++;
+                    lineStart = i;
+                } else {
+                    i++;
+                }
+            }
+
+            i += 2;
+        } else if (c === "\"") {
+            let end = i + 1;
+
+            while (end < text.length && text[end] !== "\"" && text[end] !== "\r" && text[end] !== "\n") {
+                end += text[end] === "\\" ? 2 : 1;
+            }
+
+            const fix = text[end] === "\"" ? getWindowsPathFix(text, i, end + 1) : undefined;
+
+            if (fix) {
+                paths.push({
+                    text: text.slice(i, end + 1),
+                    loc: {
+                        start: createLocation(i, line, lineStart),
+                        end: createLocation(end + 1, line, lineStart)
+                    },
+                    fix
+                });
+            }
+
+            i = text[end] === "\"" ? end + 1 : end;
+        } else {
+            i++;
+        }
+    }
+
+    return paths;
+}
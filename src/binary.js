@@ -0,0 +1,374 @@
+/**
+ * @fileoverview Converters from Momoa AST values to binary formats. These are
+ *      built into a separate file so that the main package doesn't grow for
+ *      people who don't need them.
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Helpers
+//-----------------------------------------------------------------------------
+
+const TWO_TO_32 = 2 ** 32;
+
+/**
+ * Encodes a string as UTF-8. Lone surrogates are encoded as U+FFFD.
+ * @param {string} value The string to encode.
+ * @returns {Array<int>} The bytes.
+ */
+function encodeUtf8(value) {
+    const bytes = [];
+
+    for (const c of value) {
+        let codePoint = c.codePointAt(0);
+
+        if (codePoint >= 0xd800 && codePoint <= 0xdfff) {
+            codePoint = 0xfffd;
+        }
+
+        if (codePoint < 0x80) {
+            bytes.push(codePoint);
+        } else if (codePoint < 0x800) {
+            bytes.push(0xc0 | codePoint >> 6, 0x80 | codePoint & 0x3f);
+        } else if (codePoint < 0x10000) {
+            bytes.push(0xe0 | codePoint >> 12, 0x80 | codePoint >> 6 & 0x3f, 0x80 | codePoint & 0x3f);
+        } else {
+            bytes.push(0xf0 | codePoint >> 18, 0x80 | codePoint >> 12 & 0x3f, 0x80 | codePoint >> 6 & 0x3f, 0x80 | codePoint & 0x3f);
+        }
+    }
+
+    return bytes;
+}
+
+/**
+ * Adds the big-endian bytes of an unsigned integer.
+ * @param {Array<int>} bytes The bytes to add to.
+ * @param {int} value The integer, which must fit in `size` bytes. Values
+ *      above 2^32 must be safe integers.
+ * @param {int} size The number of bytes: 1, 2, 4, or 8.
+ * @returns {void}
+ */
+function pushUint(bytes, value, size) {
+
+    if (size === 8) {
+        pushUint(bytes, Math.floor(value / TWO_TO_32), 4);
+        pushUint(bytes, value % TWO_TO_32, 4);
+        return;
+    }
+
+    for (let i = size - 1; i >= 0; i--) {
+        bytes.push(Math.floor(value / 2 ** (i * 8)) & 0xff);
+    }
+}
+
+/**
+ * Adds the bytes of a floating point number, using 32 bits when that
+ * doesn't lose precision and 64 bits otherwise.
+ * @param {Array<int>} bytes The bytes to add to.
+ * @param {number} value The number.
+ * @param {int} float32Marker The byte that precedes a 32-bit float.
+ * @param {int} float64Marker The byte that precedes a 64-bit float.
+ * @returns {void}
+ */
+function pushFloat(bytes, value, float32Marker, float64Marker) {
+    const isFloat32 = Object.is(Math.fround(value), value);
+    const view = new DataView(new ArrayBuffer(isFloat32 ? 4 : 8));
+
+    if (isFloat32) {
+        view.setFloat32(0, value);
+    } else {
+        view.setFloat64(0, value);
+    }
+
+    bytes.push(isFloat32 ? float32Marker : float64Marker, ...new Uint8Array(view.buffer));
+}
+
+/**
+ * Determines if a `Number` node should be written as an integer. When the
+ * original text is available, only numbers written without a fraction or
+ * exponent are integers, so `1.0` stays a float. Integers that can't be
+ * represented exactly, and `-0`, are written as floats.
+ * @param {Node} node The `Number` node.
+ * @param {string} [text] The text the node was parsed from.
+ * @returns {boolean} True if the number should be written as an integer.
+ */
+function isInteger(node, text) {
+
+    if (!Number.isSafeInteger(node.value) || Object.is(node.value, -0)) {
+        return false;
+    }
+
+    return text === undefined || !/[.eE]/.test(text.slice(node.loc.start.offset, node.loc.end.offset));
+}
+
+/**
+ * Gets the members of an `Object` node by name. When a name appears more
+ * than once, the last value is used, just like `evaluate()`.
+ * @param {Node} node The `Object` node.
+ * @returns {Map<string,Node>} The value nodes by name.
+ */
+function getMembers(node) {
+    const members = new Map();
+
+    node.members.forEach(member => {
+        members.set(member.name.value, member.value);
+    });
+
+    return members;
+}
+
+/**
+ * Converts an AST to bytes.
+ * @param {Node} root The node to convert.
+ * @param {string} [text] The text the AST was parsed from.
+ * @param {Object} format The functions that add each kind of value.
+ * @returns {Uint8Array} The bytes.
+ * @throws {Error} When the node can't be converted.
+ */
+function encode(root, text, format) {
+    const bytes = [];
+
+    /*
+     * Nodes and member names still to be added, with the next one last, so
+     * deeply nested values can't overflow the call stack.
+     */
+    const stack = [root];
+
+    while (stack.length) {
+        const node = stack.pop();
+
+        if (typeof node === "string") {
+            format.pushString(bytes, node);
+            continue;
+        }
+
+        switch (node.type) {
+        case "Document":
+            if (!node.body) {
+                throw new Error("Cannot convert an empty document.");
+            }
+
+            stack.push(node.body);
+            break;
+
+        case "Object": {
+            const members = [...getMembers(node)];
+
+            format.pushHead(bytes, "map", members.length);
+
+            for (let i = members.length - 1; i >= 0; i--) {
+                stack.push(members[i][1], members[i][0]);
+            }
+            break;
+        }
+
+        case "Array":
+            format.pushHead(bytes, "array", node.elements.length);
+
+            for (let i = node.elements.length - 1; i >= 0; i--) {
+                stack.push(node.elements[i]);
+            }
+            break;
+
+        case "String":
+            format.pushString(bytes, node.value);
+            break;
+
+        case "Number":
+            if (isInteger(node, text)) {
+                format.pushInteger(bytes, node.value);
+            } else {
+                format.pushFloat(bytes, node.value);
+            }
+            break;
+
+        case "Boolean":
+            bytes.push(node.value ? format.trueByte : format.falseByte);
+            break;
+
+        case "Null":
+            bytes.push(format.nullByte);
+            break;
+
+        case "Member":
+            throw new Error("Cannot convert an object member outside of an object.");
+
+        default:
+            throw new Error(`Unknown node type ${ node.type }.`);
+        }
+    }
+
+    return Uint8Array.from(bytes);
+}
+
+//-----------------------------------------------------------------------------
+// Formats
+//-----------------------------------------------------------------------------
+
+const cborMajorTypes = {
+    uint: 0,
+    negint: 1,
+    string: 3,
+    array: 4,
+    map: 5
+};
+
+const cbor = {
+    falseByte: 0xf4,
+    trueByte: 0xf5,
+    nullByte: 0xf6,
+
+    pushHead(bytes, kind, value) {
+        const major = cborMajorTypes[kind] << 5;
+
+        if (value < 24) {
+            bytes.push(major | value);
+        } else if (value < 0x100) {
+            bytes.push(major | 24);
+            pushUint(bytes, value, 1);
+        } else if (value < 0x10000) {
+            bytes.push(major | 25);
+            pushUint(bytes, value, 2);
+        } else if (value < TWO_TO_32) {
+            bytes.push(major | 26);
+            pushUint(bytes, value, 4);
+        } else {
+            bytes.push(major | 27);
+            pushUint(bytes, value, 8);
+        }
+    },
+
+    pushString(bytes, value) {
+        const encoded = encodeUtf8(value);
+
+        cbor.pushHead(bytes, "string", encoded.length);
+        encoded.forEach(byte => bytes.push(byte));
+    },
+
+    pushInteger(bytes, value) {
+        if (value < 0) {
+            cbor.pushHead(bytes, "negint", -1 - value);
+        } else {
+            cbor.pushHead(bytes, "uint", value);
+        }
+    },
+
+    pushFloat(bytes, value) {
+        pushFloat(bytes, value, 0xfa, 0xfb);
+    }
+};
+
+/*
+ * The markers for strings, arrays, and maps in MessagePack. Short lengths
+ * are combined with the `fixed` marker; longer lengths follow one of the
+ * `markers` for 8-bit, 16-bit, and 32-bit lengths, where `null` means the
+ * format has no marker for that size.
+ */
+const msgpackHeads = {
+    string: { fixed: 0xa0, fixedMax: 31, markers: [0xd9, 0xda, 0xdb] },
+    array: { fixed: 0x90, fixedMax: 15, markers: [null, 0xdc, 0xdd] },
+    map: { fixed: 0x80, fixedMax: 15, markers: [null, 0xde, 0xdf] }
+};
+
+const msgpack = {
+    falseByte: 0xc2,
+    trueByte: 0xc3,
+    nullByte: 0xc0,
+
+    pushHead(bytes, kind, length) {
+        const { fixed, fixedMax, markers } = msgpackHeads[kind];
+
+        if (length <= fixedMax) {
+            bytes.push(fixed | length);
+        } else if (length < 0x100 && markers[0] !== null) {
+            bytes.push(markers[0]);
+            pushUint(bytes, length, 1);
+        } else if (length < 0x10000) {
+            bytes.push(markers[1]);
+            pushUint(bytes, length, 2);
+        } else {
+            bytes.push(markers[2]);
+            pushUint(bytes, length, 4);
+        }
+    },
+
+    pushString(bytes, value) {
+        const encoded = encodeUtf8(value);
+
+        msgpack.pushHead(bytes, "string", encoded.length);
+        encoded.forEach(byte => bytes.push(byte));
+    },
+
+    pushInteger(bytes, value) {
+        if (value >= 0) {
+            if (value < 0x80) {
+                bytes.push(value);
+            } else if (value < 0x100) {
+                bytes.push(0xcc);
+                pushUint(bytes, value, 1);
+            } else if (value < 0x10000) {
+                bytes.push(0xcd);
+                pushUint(bytes, value, 2);
+            } else if (value < TWO_TO_32) {
+                bytes.push(0xce);
+                pushUint(bytes, value, 4);
+            } else {
+                bytes.push(0xcf);
+                pushUint(bytes, value, 8);
+            }
+        } else if (value >= -32) {
+            bytes.push(value & 0xff);
+        } else if (value >= -0x80) {
+            bytes.push(0xd0);
+            pushUint(bytes, value & 0xff, 1);
+        } else if (value >= -0x8000) {
+            bytes.push(0xd1);
+            pushUint(bytes, value & 0xffff, 2);
+        } else if (value >= -0x80000000) {
+            bytes.push(0xd2);
+            pushUint(bytes, value >>> 0, 4);
+        } else {
+
+            // two's complement of the high and low 32 bits
+            const high = Math.floor(value / TWO_TO_32);
+
+            bytes.push(0xd3);
+            pushUint(bytes, high >>> 0, 4);
+            pushUint(bytes, value - high * TWO_TO_32, 4);
+        }
+    },
+
+    pushFloat(bytes, value) {
+        pushFloat(bytes, value, 0xca, 0xcb);
+    }
+};
+
+//-----------------------------------------------------------------------------
+// Exports
+//-----------------------------------------------------------------------------
+
+/**
+ * Converts the value of an AST to CBOR (RFC 8949).
+ * @param {Node} node The node to convert, usually a `Document`.
+ * @param {string} [options.text] The text the AST was parsed from. When
+ *      present, numbers written with a fraction or exponent, such as `1.0`,
+ *      are converted to floats even when their value is an integer.
+ * @returns {Uint8Array} The CBOR bytes.
+ * @throws {Error} When the node can't be converted.
+ */
+export function toCBOR(node, { text } = {}) {
+    return encode(node, text, cbor);
+}
+
+/**
+ * Converts the value of an AST to MessagePack.
+ * @param {Node} node The node to convert, usually a `Document`.
+ * @param {string} [options.text] The text the AST was parsed from. When
+ *      present, numbers written with a fraction or exponent, such as `1.0`,
+ *      are converted to floats even when their value is an integer.
+ * @returns {Uint8Array} The MessagePack bytes.
+ * @throws {Error} When the node can't be converted.
+ */
+export function toMessagePack(node, { text } = {}) {
+    return encode(node, text, msgpack);
+}
@@ -0,0 +1,208 @@
+/**
+ * @fileoverview Semantic comparison of Momoa AST nodes.
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+import { getRawText } from "./print.js";
+import { isSyntheticLocation } from "./locations.js";
+
+//-----------------------------------------------------------------------------
+// Helpers
+//-----------------------------------------------------------------------------
+
+const policyValues = ["equal", "distinct"];
+
+/**
+ * Creates a map of member names to value nodes. When a name appears more
+ * than once, the last value is used, matching `JSON.parse()`.
+ * @param {Node} node The `Object` node.
+ * @returns {Map<string,Node>} The member values keyed by name.
+ */
+function getMemberValues(node) {
+    return new Map(node.members.map(member => [member.name.value, member.value]));
+}
+
+/**
+ * Determines if a `Number` node is written as an integer, without a
+ * fraction or exponent. Nodes without a source location, such as those
+ * created by `fromValue()`, are integers when their value is.
+ * @param {Node} node The `Number` node.
+ * @param {string} [source] The text the node was parsed from.
+ * @returns {boolean} True if the number is written as an integer.
+ * @throws {TypeError} When the node has a source location but there's no
+ *      source text.
+ */
+function isIntegerForm(node, source) {
+
+    if (isSyntheticLocation(node.loc)) {
+        return Number.isInteger(node.value);
+    }
+
+    if (typeof source !== "string") {
+        throw new TypeError("Number forms can only be compared with the source text of each node.");
+    }
+
+    return /^-?\d+$/.test(getRawText(node, source));
+}
+
+/**
+ * Gets the value node of a `Document` node, or the node itself for any
+ * other node.
+ * @param {Node} node The node.
+ * @returns {Node|null} The value node, or `null` for an empty document.
+ */
+function getValueNode(node) {
+    return node.type === "Document" ? node.body : node;
+}
+
+/**
+ * Determines if two nodes represent the same JSON value under a policy.
+ * @param {Node} first The first node.
+ * @param {Node} second The second node.
+ * @param {Object} policy The policy for comparing numbers.
+ * @param {Array<string>} sources The text each node was parsed from.
+ * @returns {boolean} True if the nodes represent the same value.
+ */
+function compareValues(first, second, policy, sources) {
+
+    /*
+     * Pairs of nodes still to be compared, so deeply nested values can't
+     * overflow the call stack.
+     */
+    const stack = [[getValueNode(first), getValueNode(second)]];
+
+    while (stack.length) {
+        const [a, b] = stack.pop();
+
+        // an empty document is only the same as another empty document
+        if (!a || !b) {
+            if (a !== b) {
+                return false;
+            }
+
+            continue;
+        }
+
+        if (a.type !== b.type) {
+            return false;
+        }
+
+        switch (a.type) {
+        case "Number":
+            if (policy.negativeZero === "distinct" ? !Object.is(a.value, b.value) : a.value !== b.value) {
+                return false;
+            }
+
+            if (policy.numberForms === "distinct" && isIntegerForm(a, sources[0]) !== isIntegerForm(b, sources[1])) {
+                return false;
+            }
+            break;
+
+        case "String":
+        case "Boolean":
+            if (a.value !== b.value) {
+                return false;
+            }
+            break;
+
+        case "Null":
+            break;
+
+        case "Array":
+            if (a.elements.length !== b.elements.length) {
+                return false;
+            }
+
+            for (let i = a.elements.length - 1; i >= 0; i--) {
+                stack.push([a.elements[i], b.elements[i]]);
+            }
+            break;
+
+        case "Object": {
+            const aValues = getMemberValues(a);
+            const bValues = getMemberValues(b);
+
+            if (aValues.size !== bValues.size) {
+                return false;
+            }
+
+            for (const [key, value] of aValues) {
+                if (!bValues.has(key)) {
+                    return false;
+                }
+
+                stack.push([value, bValues.get(key)]);
+            }
+            break;
+        }
+
+        default:
+            throw new Error(`Unknown node type ${ a.type }.`);
+        }
+    }
+
+    return true;
+}
+
+//-----------------------------------------------------------------------------
+// Exports
+//-----------------------------------------------------------------------------
+
+/**
+ * Compares two nodes by the JSON values they represent, regardless of
+ * formatting, escape sequences, or the order of object members. Since
+ * systems disagree on whether `-0` equals `0` and whether `1` equals `1.0`,
+ * the policy for numbers can be chosen and is returned with the result.
+ * @param {Node} a The first node.
+ * @param {Node} b The second node.
+ * @param {Object} [options] Options for the comparison.
+ * @param {string} [options.negativeZero="equal"] Either `"equal"` to treat
+ *      `-0` and `0` as equal or `"distinct"` to treat them as different.
+ * @param {string} [options.numberForms="equal"] Either `"equal"` to compare
+ *      numbers by value only or `"distinct"` to treat an integer, such as
+ *      `1`, as different from the same value written with a fraction or
+ *      exponent, such as `1.0` or `1e0`.
+ * @param {Array<string>} [options.sources] The text that `a` and `b` were
+ *      parsed from. Required when `numberForms` is `"distinct"` and a
+ *      number has a source location.
+ * @returns {Object} An object with an `equivalent` property that is `true`
+ *      if the nodes represent the same value and a `policy` property
+ *      containing the `negativeZero` and `numberForms` that were used.
+ * @throws {TypeError} When a policy is unknown or a needed source text is
+ *      missing.
+ */
+export function compareNodes(a, b, { negativeZero = "equal", numberForms = "equal", sources = [] } = {}) {
+
+    if (!policyValues.includes(negativeZero)) {
+        throw new TypeError(`Unknown negativeZero policy "${ negativeZero }".`);
+    }
+
+    if (!policyValues.includes(numberForms)) {
+        throw new TypeError(`Unknown numberForms policy "${ numberForms }".`);
+    }
+
+    const policy = Object.freeze({ negativeZero, numberForms });
+
+    return {
+        equivalent: compareValues(a, b, policy, sources),
+        policy
+    };
+}
+
+/**
+ * Determines if two nodes represent the same JSON value, regardless of
+ * formatting, escape sequences, or the order of object members.
+ * @param {Node} a The first node.
+ * @param {Node} b The second node.
+ * @param {Object} [options] The same options as `compareNodes()`.
+ * @returns {boolean} True if the nodes represent the same value.
+ * @throws {TypeError} When a policy is unknown or a needed source text is
+ *      missing.
+ */
+export function isEquivalent(a, b, options) {
+    return compareNodes(a, b, options).equivalent;
+}
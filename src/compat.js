@@ -0,0 +1,395 @@
+/**
+ * @fileoverview Adapters with the same signatures and AST formats as other
+ *      parsers to make it easier to migrate to Momoa. These are built into a
+ *      separate file so that the main package doesn't grow for people who
+ *      don't need them.
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+// imported from the main entry point so thrown errors are its error classes
+import { parse, evaluate } from "./index.js";
+
+//-----------------------------------------------------------------------------
+// Data
+//-----------------------------------------------------------------------------
+
+// Momoa tokens that have no ESTree token, because they aren't JavaScript syntax
+const NON_ESTREE_TOKEN_TYPES = new Set([
+    "LineComment",
+    "BlockComment",
+    "EOF",
+    "BOM",
+    "Shebang",
+    "RecordSeparator",
+    "Invalid"
+]);
+
+//-----------------------------------------------------------------------------
+// Helpers
+//-----------------------------------------------------------------------------
+
+/**
+ * Calls a reviver on a value and everything inside of it, deepest first, in
+ * the same way as `JSON.parse()`.
+ * @param {Object} holder The object or array containing the value.
+ * @param {string} key The key of the value in `holder`.
+ * @param {Function} reviver The reviver.
+ * @returns {*} The value returned by the reviver.
+ */
+function revive(holder, key, reviver) {
+    const value = holder[key];
+
+    if (value && typeof value === "object") {
+        Object.keys(value).forEach(childKey => {
+            const revived = revive(value, childKey, reviver);
+
+            if (revived === undefined) {
+                delete value[childKey];
+            } else {
+                value[childKey] = revived;
+            }
+        });
+    }
+
+    return reviver.call(holder, key, value);
+}
+
+/**
+ * Creates a json-to-ast location from a Momoa location.
+ * @param {Object} loc The Momoa location.
+ * @param {string|null} source The source to include in the location.
+ * @returns {Object} The json-to-ast location.
+ */
+function createLocation(loc, source) {
+    return {
+        start: { ...loc.start },
+        end: { ...loc.end },
+        source
+    };
+}
+
+/**
+ * Creates the location properties of an ESTree node: `start`, `end`,
+ * `range`, and `loc`, where columns start at 0.
+ * @param {Object} start The Momoa start location.
+ * @param {Object} end The Momoa end location.
+ * @returns {Object} The location properties.
+ */
+function createESTreeLocation(start, end) {
+    return {
+        start: start.offset,
+        end: end.offset,
+        range: [start.offset, end.offset],
+        loc: {
+            start: { line: start.line, column: start.column - 1 },
+            end: { line: end.line, column: end.column - 1 }
+        }
+    };
+}
+
+/**
+ * Gets a Momoa location that is a number of characters after another
+ * location on the same line.
+ * @param {Object} loc The location.
+ * @param {int} length The number of characters.
+ * @returns {Object} The new location.
+ */
+function shiftLocation(loc, length) {
+    return {
+        line: loc.line,
+        column: loc.column + length,
+        offset: loc.offset + length
+    };
+}
+
+/**
+ * Creates ESTree tokens in the format that ESLint expects from a Momoa
+ * token. A negative number becomes a `-` punctuator followed by a number,
+ * just like in JavaScript.
+ * @param {Token} token The Momoa token.
+ * @param {string} text The text the token came from.
+ * @returns {Array<Object>} The ESTree tokens.
+ */
+function createESTreeTokens(token, text) {
+    const { start, end } = token.loc;
+    const raw = text.slice(start.offset, end.offset);
+
+    if (token.type === "Number" && raw.startsWith("-")) {
+        const afterMinus = shiftLocation(start, 1);
+
+        return [
+            { type: "Punctuator", value: "-", ...createESTreeLocation(start, afterMinus) },
+            { type: "Numeric", value: raw.slice(1), ...createESTreeLocation(afterMinus, end) }
+        ];
+    }
+
+    return [{
+        type: token.type === "Number" ? "Numeric" : token.type,
+        value: raw,
+        ...createESTreeLocation(start, end)
+    }];
+}
+
+//-----------------------------------------------------------------------------
+// Exports
+//-----------------------------------------------------------------------------
+
+/**
+ * Parses JSON text into a JavaScript value with the same signature as
+ * `JSON.parse()`, so it can be swapped in without other changes. Errors
+ * are Momoa errors with a location rather than `SyntaxError`s.
+ * @param {string} text The text to parse.
+ * @param {Function} [reviver] A function to transform each value, called
+ *      the same way as by `JSON.parse()`.
+ * @returns {*} The value.
+ * @throws {Error} When the text isn't valid JSON.
+ */
+export function parseValue(text, reviver) {
+    const value = evaluate(parse(text));
+
+    return typeof reviver === "function" ? revive({ "": value }, "", reviver) : value;
+}
+
+/**
+ * Parses JSON text that may contain comments with the same signature as
+ * the `parse()` function in `jsonc-parser`. Unlike `jsonc-parser`, parsing
+ * stops at the first error, so `errors` contains at most one error and the
+ * return value is `undefined` when there is an error.
+ * @param {string} text The text to parse.
+ * @param {Array} [errors] An array to add the error to. The error is a Momoa
+ *      error with `error` (the error's `code`), `offset`, and `length`
+ *      properties added to match `jsonc-parser`.
+ * @param {boolean} [options.disallowComments=false] Determines if comments
+ *      are errors.
+ * @param {boolean} [options.allowEmptyContent=false] Determines if an empty
+ *      document is allowed.
+ * @returns {*} The value, or `undefined` if there was an error or the
+ *      document is empty.
+ */
+export function parseJsonc(text, errors = [], {
+    disallowComments = false,
+    allowEmptyContent = false
+} = {}) {
+
+    try {
+        return evaluate(parse(text, {
+            comments: !disallowComments,
+            allowEmpty: allowEmptyContent
+        }));
+    } catch (error) {
+
+        if (error.index === undefined) {
+            throw error;
+        }
+
+        error.error = error.code;
+        error.offset = error.index;
+        error.length = error.index < text.length ? 1 : 0;
+        errors.push(error);
+        return undefined;
+    }
+}
+
+/**
+ * Converts a Momoa AST into the AST format of `json-to-ast`, for code that
+ * was written for `json-to-ast` and hasn't been updated yet.
+ * @param {Node} node The node to convert, usually a `Document`.
+ * @param {string} text The text the AST was parsed from, used for the `raw`
+ *      properties.
+ * @param {string|null} [options.source=null] The value of the `source`
+ *      property of each location.
+ * @returns {Object} The `json-to-ast` node.
+ * @throws {Error} When the node can't be converted.
+ */
+export function toJsonToAst(node, text, { source = null } = {}) {
+
+    function raw(child) {
+        return text.slice(child.loc.start.offset, child.loc.end.offset);
+    }
+
+    function convert(child) {
+        const loc = createLocation(child.loc, source);
+
+        switch (child.type) {
+        case "Document":
+            if (!child.body) {
+                throw new Error("Cannot convert an empty document.");
+            }
+
+            return convert(child.body);
+
+        case "Object":
+            return {
+                type: "Object",
+                children: child.members.map(convert),
+                loc
+            };
+
+        case "Member":
+            return {
+                type: "Property",
+                key: {
+                    type: "Identifier",
+                    value: child.name.value,
+                    raw: raw(child.name),
+                    loc: createLocation(child.name.loc, source)
+                },
+                value: convert(child.value),
+                loc
+            };
+
+        case "Array":
+            return {
+                type: "Array",
+                children: child.elements.map(convert),
+                loc
+            };
+
+        case "String":
+        case "Number":
+        case "Boolean":
+        case "Null":
+            return {
+                type: "Literal",
+                value: child.type === "Null" ? null : child.value,
+                raw: raw(child),
+                loc
+            };
+
+        default:
+            throw new Error(`Unknown node type ${ child.type }.`);
+        }
+    }
+
+    return convert(node);
+}
+
+/**
+ * Converts a Momoa document into an ESTree-compatible AST, where the JSON
+ * value is the expression of a single `ExpressionStatement` in a `Program`,
+ * so it can be used with tools that expect JavaScript ASTs, such as an
+ * ESLint language plugin. Nodes have `start`, `end`, `range`, and `loc`
+ * properties with columns starting at 0, as ESLint expects. Negative
+ * numbers are `UnaryExpression` nodes, just like in JavaScript.
+ * @param {Node} document The `Document` node to convert. If it has `tokens`
+ *      or `comments` properties, the `Program` has them too, converted to
+ *      the format ESLint expects.
+ * @param {string} text The text the document was parsed from, used for the
+ *      `raw` properties.
+ * @returns {Object} The `Program` node.
+ * @throws {Error} When the node isn't a `Document`.
+ */
+export function toESTree(document, text) {
+
+    if (document.type !== "Document") {
+        throw new Error(`Expected a Document node but found ${ document.type }.`);
+    }
+
+    function convert(node) {
+        const { start, end } = node.loc;
+        const location = createESTreeLocation(start, end);
+
+        switch (node.type) {
+        case "Object":
+            return {
+                type: "ObjectExpression",
+                properties: node.members.map(convert),
+                ...location
+            };
+
+        case "Member":
+            return {
+                type: "Property",
+                key: convert(node.name),
+                value: convert(node.value),
+                kind: "init",
+                computed: false,
+                method: false,
+                shorthand: false,
+                ...location
+            };
+
+        case "Array":
+            return {
+                type: "ArrayExpression",
+                elements: node.elements.map(convert),
+                ...location
+            };
+
+        case "Number":
+            if (text[start.offset] === "-") {
+                const afterMinus = shiftLocation(start, 1);
+
+                return {
+                    type: "UnaryExpression",
+                    operator: "-",
+                    prefix: true,
+                    argument: {
+                        type: "Literal",
+                        value: -node.value,
+                        raw: text.slice(afterMinus.offset, end.offset),
+                        ...createESTreeLocation(afterMinus, end)
+                    },
+                    ...location
+                };
+            }
+
+            /* falls through */
+
+        case "String":
+        case "Boolean":
+        case "Null":
+            return {
+                type: "Literal",
+                value: node.type === "Null" ? null : node.value,
+                raw: text.slice(start.offset, end.offset),
+                ...location
+            };
+
+        default:
+            throw new Error(`Unknown node type ${ node.type }.`);
+        }
+    }
+
+    const { body } = document;
+    const emptyLocation = { line: 1, column: 1, offset: 0 };
+    const program = {
+        type: "Program",
+        body: body ? [{
+            type: "ExpressionStatement",
+            expression: convert(body),
+            ...createESTreeLocation(body.loc.start, body.loc.end)
+        }] : [],
+        sourceType: "script",
+        ...(body ? createESTreeLocation(body.loc.start, body.loc.end) : createESTreeLocation(emptyLocation, emptyLocation))
+    };
+
+    if (document.comments) {
+        program.comments = document.comments.map(comment => {
+            const { start, end } = comment.loc;
+            const raw = text.slice(start.offset, end.offset);
+
+            return {
+                type: comment.type === "LineComment" ? "Line" : "Block",
+                value: comment.type === "LineComment" ? raw.slice(2) : raw.slice(2, -2),
+                ...createESTreeLocation(start, end)
+            };
+        });
+    }
+
+    if (document.tokens) {
+        program.tokens = [];
+
+        for (const token of document.tokens) {
+            if (!NON_ESTREE_TOKEN_TYPES.has(token.type)) {
+                program.tokens.push(...createESTreeTokens(token, text));
+            }
+        }
+    }
+
+    return program;
+}
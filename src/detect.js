@@ -0,0 +1,304 @@
+/**
+ * @fileoverview Heuristic detection of the JSON flavor used by some text.
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+import { tokenize, isComment } from "./tokens.js";
+import { isWhitespace, isIrregularWhitespace } from "./syntax.js";
+
+//-----------------------------------------------------------------------------
+// Helpers
+//-----------------------------------------------------------------------------
+
+const QUOTE = "\"";
+const APOSTROPHE = "'";
+const SLASH = "/";
+const STAR = "*";
+
+/**
+ * Modes in order from least to most permissive.
+ */
+const modes = ["json", "jsonc", "json5"];
+
+function isIdentifierChar(c) {
+    return /[\w$]/.test(c);
+}
+
+/**
+ * Finds the most common key in a map of counts. Ties go to the key that was
+ * counted first.
+ * @param {Map<*,int>} counts The counts to check.
+ * @returns {*} The most common key or `undefined` if there are no counts.
+ */
+function mostCommon(counts) {
+    let result;
+    let max = 0;
+
+    for (const [key, count] of counts) {
+        if (count > max) {
+            result = key;
+            max = count;
+        }
+    }
+
+    return result;
+}
+
+/**
+ * Infers the indent used by some text from the lines that start with a
+ * token. Tabs win when more lines are indented with tabs than with spaces.
+ * Otherwise, the size is the most common increase in indentation from one
+ * line to the next.
+ * @param {string} text The text to check.
+ * @param {Array<Token>} tokens The tokens of the text.
+ * @returns {string} The indent or an empty string if no lines are indented.
+ */
+function inferIndent(text, tokens) {
+
+    const increases = new Map();
+    let tabLines = 0;
+    let spaceLines = 0;
+    let previousLine = 0;
+    let previousWidth = 0;
+
+    for (const token of tokens) {
+        const { line, column, offset } = token.loc.start;
+
+        if (line !== previousLine) {
+            const leading = text.slice(offset - column + 1, offset);
+
+            if (leading.includes("\t")) {
+                tabLines++;
+            } else {
+                if (leading.length) {
+                    spaceLines++;
+                }
+
+                const increase = leading.length - previousWidth;
+
+                if (increase > 0) {
+                    increases.set(increase, (increases.get(increase) || 0) + 1);
+                }
+
+                previousWidth = leading.length;
+            }
+        }
+
+        previousLine = token.loc.end.line;
+    }
+
+    if (tabLines > spaceLines) {
+        return "\t";
+    }
+
+    return spaceLines ? " ".repeat(mostCommon(increases)) : "";
+}
+
+/**
+ * Returns the more permissive of two modes.
+ * @param {string} a The first mode.
+ * @param {string} b The second mode.
+ * @returns {string} The more permissive mode.
+ */
+function maxMode(a, b) {
+    return modes.indexOf(a) > modes.indexOf(b) ? a : b;
+}
+
+//-----------------------------------------------------------------------------
+// Exports
+//-----------------------------------------------------------------------------
+
+/**
+ * Quickly scans text to suggest the least permissive JSON flavor that can
+ * represent it. This is a heuristic: it doesn't validate the text, so invalid
+ * text may still be reported as `"json"`.
+ *
+ * - `"json5"` is returned when JSON5-only syntax is found, such as
+ *   single-quoted strings, unquoted keys, `Infinity`, `NaN`, hexadecimal
+ *   numbers, explicit `+` signs, leading or trailing decimal points, or
+ *   escaped line breaks inside of strings.
+ * - `"jsonc"` is returned when comments or trailing commas are found.
+ * - `"json"` is returned otherwise.
+ *
+ * Note that Momoa's `comments` option enables comments only, so text
+ * reported as `"jsonc"` because of a trailing comma still can't be parsed.
+ * @param {string} text The text to scan.
+ * @returns {string} One of `"json"`, `"jsonc"`, or `"json5"`.
+ */
+export function detectMode(text) {
+
+    let mode = "json";
+    let i = 0;
+
+    /**
+     * Skips over a string starting at the current index.
+     * @param {string} quote The quote character that opened the string.
+     * @returns {void}
+     */
+    function skipString(quote) {
+        i++;
+
+        while (i < text.length && text[i] !== quote) {
+            if (text[i] === "\\") {
+                i++;
+
+                // escaped line breaks are only valid in JSON5
+                if (text[i] === "\n" || text[i] === "\r") {
+                    mode = "json5";
+                }
+            }
+
+            i++;
+        }
+
+        i++;
+    }
+
+    /**
+     * Skips over a comment starting at the current index.
+     * @returns {void}
+     */
+    function skipComment() {
+        if (text[i + 1] === SLASH) {
+            while (i < text.length && text[i] !== "\n" && text[i] !== "\r") {
+                i++;
+            }
+        } else {
+            const end = text.indexOf(STAR + SLASH, i + 2);
+            i = end === -1 ? text.length : end + 2;
+        }
+    }
+
+    /**
+     * Determines if the next significant character after a comma closes
+     * an array or object.
+     * @param {int} start The index immediately after the comma.
+     * @returns {boolean} True if the comma is a trailing comma.
+     */
+    function isTrailingComma(start) {
+        let j = start;
+
+        while (j < text.length) {
+            const c = text[j];
+
+            if (isWhitespace(c) || isIrregularWhitespace(c)) {
+                j++;
+            } else if (c === SLASH && text[j + 1] === SLASH) {
+                while (j < text.length && text[j] !== "\n" && text[j] !== "\r") {
+                    j++;
+                }
+            } else if (c === SLASH && text[j + 1] === STAR) {
+                const end = text.indexOf(STAR + SLASH, j + 2);
+                j = end === -1 ? text.length : end + 2;
+            } else {
+                return c === "]" || c === "}";
+            }
+        }
+
+        return false;
+    }
+
+    while (i < text.length && mode !== "json5") {
+        const c = text[i];
+
+        if (c === QUOTE) {
+            skipString(c);
+        } else if (c === APOSTROPHE) {
+            mode = "json5";
+        } else if (c === SLASH && (text[i + 1] === SLASH || text[i + 1] === STAR)) {
+            mode = maxMode(mode, "jsonc");
+            skipComment();
+        } else if (c === ",") {
+            if (isTrailingComma(i + 1)) {
+                mode = maxMode(mode, "jsonc");
+            }
+            i++;
+        } else if (c === "+" || (c === "." && !/[0-9]/.test(text[i - 1]))) {
+            mode = "json5";
+        } else if (isIdentifierChar(c)) {
+            const start = i;
+
+            while (i < text.length && (isIdentifierChar(text[i]) || text[i] === ".")) {
+                i++;
+            }
+
+            const word = text.slice(start, i);
+
+            if (!/^(?:true|false|null|\d+(?:\.\d+)?(?:[eE]\d*)?)$/.test(word)) {
+                mode = "json5";
+            }
+
+            // exponents may be followed by a sign
+            if (/[eE]$/.test(word) && (text[i] === "+" || text[i] === "-")) {
+                i++;
+            }
+        } else {
+            i++;
+        }
+    }
+
+    return mode;
+}
+
+/**
+ * Infers the formatting style of some text so that edits and generated text
+ * can match it. The style is inferred from the whitespace between tokens:
+ *
+ * - `indent` is the string used for one level of indentation (such as
+ *   `"  "` or `"\t"`), or an empty string when no lines are indented.
+ * - `spaceAfterColon` is `true` when most colons are followed by whitespace.
+ * - `trailingCommas` is `true` when a comma comes right before a closing
+ *   bracket or brace.
+ * - `lineEnding` is the most common line ending, or `"\n"` when there are no
+ *   line breaks.
+ * - `finalNewline` is `true` when the text ends with a line ending.
+ *
+ * The `indent` value can be passed as the `indent` option of the transform
+ * functions, such as `setValue()`.
+ * @param {string} text The text to check.
+ * @param {Array<Token>} [tokens] The tokens of the text, such as the `tokens`
+ *      array of a document. When omitted, the text is tokenized with comments
+ *      allowed.
+ * @returns {Object} An object with `indent`, `spaceAfterColon`,
+ *      `trailingCommas`, `lineEnding`, and `finalNewline` properties.
+ * @throws {Error} When `tokens` is omitted and the text can't be tokenized.
+ */
+export function inferStyle(text, tokens = tokenize(text, { comments: true })) {
+
+    const significant = tokens.filter(token => !isComment(token) && token.type !== "EOF");
+    const lineEndings = new Map();
+
+    // positive when more colons are followed by whitespace than not
+    let colonSpacing = 0;
+    let trailingCommas = false;
+
+    significant.forEach((token, i) => {
+        const next = significant[i + 1];
+
+        if (!next) {
+            return;
+        }
+
+        if (token.value === ":") {
+            colonSpacing += next.loc.start.offset > token.loc.end.offset ? 1 : -1;
+        } else if (token.value === "," && (next.value === "]" || next.value === "}")) {
+            trailingCommas = true;
+        }
+    });
+
+    for (const lineEnding of text.match(/\r\n|\r|\n/g) || []) {
+        lineEndings.set(lineEnding, (lineEndings.get(lineEnding) || 0) + 1);
+    }
+
+    return {
+        indent: inferIndent(text, tokens),
+        spaceAfterColon: colonSpacing > 0,
+        trailingCommas,
+        lineEnding: mostCommon(lineEndings) || "\n",
+        finalNewline: /[\r\n]$/.test(text)
+    };
+}
@@ -0,0 +1,109 @@
+/**
+ * @fileoverview Utilities for sorting and grouping batches of errors.
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Helpers
+//-----------------------------------------------------------------------------
+
+/*
+ * The severities that are recognized, from least to most severe. Unknown
+ * severities are less severe than all of these.
+ */
+const severities = ["info", "warning", "error"];
+
+/**
+ * Gets the start of an error's location.
+ * @param {Error|Object} error An error thrown by Momoa or the result of
+ *      calling its `toJSON()` method.
+ * @returns {Object} The location with `line`, `column`, and `offset`
+ *      properties.
+ */
+function getStart(error) {
+    return error.range ? error.range.start : {
+        line: error.line,
+        column: error.column,
+        offset: error.index
+    };
+}
+
+/**
+ * Gets the severity of an error. Errors thrown by Momoa don't have a
+ * `severity` property, but their serialized forms do.
+ * @param {Error|Object} error An error thrown by Momoa or the result of
+ *      calling its `toJSON()` method.
+ * @returns {string} The severity.
+ */
+function getSeverity(error) {
+    return error.severity || "error";
+}
+
+//-----------------------------------------------------------------------------
+// Exports
+//-----------------------------------------------------------------------------
+
+/**
+ * Sorts errors by the offset where they start. Errors at the same offset
+ * keep their original order.
+ * @param {Iterable<Error|Object>} errors The errors to sort. Each may be an
+ *      error thrown by Momoa or the result of calling its `toJSON()` method.
+ * @returns {Array<Error|Object>} A new array containing the errors in order.
+ */
+export function sortDiagnostics(errors) {
+    return [...errors]
+        .map((error, index) => ({ error, index, offset: getStart(error).offset }))
+        .sort((a, b) => a.offset - b.offset || a.index - b.index)
+        .map(({ error }) => error);
+}
+
+/**
+ * Groups errors by the line they start on, such as for showing errors next
+ * to the lines of a file.
+ * @param {Iterable<Error|Object>} errors The errors to group. Each may be an
+ *      error thrown by Momoa or the result of calling its `toJSON()` method.
+ * @returns {Map<int,Array<Error|Object>>} A map of one-based line numbers
+ *      to the errors that start on them. The lines are in ascending order
+ *      and the errors on each line are sorted by offset.
+ */
+export function groupDiagnosticsByLine(errors) {
+
+    const groups = new Map();
+
+    sortDiagnostics(errors).forEach(error => {
+        const { line } = getStart(error);
+
+        if (!groups.has(line)) {
+            groups.set(line, []);
+        }
+
+        groups.get(line).push(error);
+    });
+
+    return groups;
+}
+
+/**
+ * Gets the most severe severity of a batch of errors, such as for deciding
+ * whether a check failed. The severities, from most to least severe, are
+ * `"error"`, `"warning"`, and `"info"`. Errors without a `severity`
+ * property, such as errors thrown by Momoa, are `"error"`.
+ * @param {Iterable<Error|Object>} errors The errors to check. Each may be an
+ *      error thrown by Momoa or the result of calling its `toJSON()` method.
+ * @returns {string|null} The most severe severity, or `null` if there are
+ *      no errors.
+ */
+export function getMaxSeverity(errors) {
+
+    let result = null;
+
+    for (const error of errors) {
+        const severity = getSeverity(error);
+
+        if (result === null || severities.indexOf(severity) > severities.indexOf(result)) {
+            result = severity;
+        }
+    }
+
+    return result;
+}
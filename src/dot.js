@@ -0,0 +1,119 @@
+/**
+ * @fileoverview Converts a Momoa AST into a Graphviz DOT graph.
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+import { childKeys } from "./traversal.js";
+import { isSyntheticLocation } from "./locations.js";
+
+//-----------------------------------------------------------------------------
+// Helpers
+//-----------------------------------------------------------------------------
+
+/**
+ * Escapes text for use inside of a quoted DOT string. Line breaks become
+ * `\n` so each label stays on one line of the output.
+ * @param {string} text The text to escape.
+ * @returns {string} The escaped text.
+ */
+function escapeLabel(text) {
+    return text
+        .replace(/\\/g, "\\\\")
+        .replace(/"/g, "\\\"")
+        .replace(/\r\n|\r|\n/g, "\\n");
+}
+
+/**
+ * Shortens text to a maximum length, ending it with `...` when it's cut.
+ * @param {string} text The text to shorten.
+ * @param {int} maxLength The maximum length.
+ * @returns {string} The shortened text.
+ */
+function truncate(text, maxLength) {
+    return text.length > maxLength ? text.slice(0, Math.max(maxLength - 3, 0)) + "..." : text;
+}
+
+/**
+ * Gets the preview of a node's value for its label: the value of a string,
+ * number, or boolean, or the name of a member.
+ * @param {Node} node The node.
+ * @param {int} maxLength The maximum length of the preview.
+ * @returns {string} The preview, or an empty string for other nodes.
+ */
+function getPreview(node, maxLength) {
+    switch (node.type) {
+    case "String":
+        return truncate(JSON.stringify(node.value), maxLength);
+
+    case "Number":
+    case "Boolean":
+        return truncate(String(node.value), maxLength);
+
+    case "Member":
+        return truncate(JSON.stringify(node.name.value), maxLength);
+
+    default:
+        return "";
+    }
+}
+
+//-----------------------------------------------------------------------------
+// Exports
+//-----------------------------------------------------------------------------
+
+/**
+ * Converts an AST into a Graphviz DOT graph for debugging, documentation,
+ * and teaching. Each node is labeled with its type, a preview of its value
+ * (or its name, for a member), and its range of offsets unless its
+ * location is synthetic, and each edge is labeled with the property or
+ * index that connects the nodes.
+ * @param {Node} root The node to start from, usually a `Document`.
+ * @param {int} [options.previewLength=20] The maximum number of characters
+ *      in a preview. Longer previews end with `...`.
+ * @param {string} [options.name="AST"] The name of the graph.
+ * @returns {string} The DOT text.
+ */
+export function toDot(root, { previewLength = 20, name = "AST" } = {}) {
+
+    const lines = [
+        `digraph "${ escapeLabel(name) }" {`,
+        "    node [shape=box, fontname=\"monospace\"];"
+    ];
+    let nextId = 0;
+
+    function visit(node) {
+        const id = `n${ nextId++ }`;
+        const preview = getPreview(node, previewLength);
+        const label = [preview ? `${ node.type } ${ preview }` : node.type];
+
+        // nodes without a source location don't have a range to show
+        if (!isSyntheticLocation(node.loc)) {
+            label.push(`[${ node.loc.start.offset }, ${ node.loc.end.offset }]`);
+        }
+
+        lines.push(`    ${ id } [label="${ escapeLabel(label.join("\n")) }"];`);
+
+        (childKeys.get(node.type) || []).forEach(key => {
+            const value = node[key];
+
+            if (Array.isArray(value)) {
+                value.forEach((child, index) => {
+                    lines.push(`    ${ id } -> ${ visit(child) } [label="${ index }"];`);
+                });
+            } else if (value) {
+                lines.push(`    ${ id } -> ${ visit(value) } [label="${ key }"];`);
+            }
+        });
+
+        return id;
+    }
+
+    visit(root);
+    lines.push("}");
+
+    return lines.join("\n");
+}
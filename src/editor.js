@@ -0,0 +1,417 @@
+/**
+ * @fileoverview Helpers for editor integrations, such as language servers.
+ *      Positions and ranges returned from these functions follow the
+ *      Language Server Protocol: lines and characters are zero-based.
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+import { childKeys } from "./traversal.js";
+import { formatPointer } from "./pointer.js";
+
+//-----------------------------------------------------------------------------
+// Helpers
+//-----------------------------------------------------------------------------
+
+/**
+ * The LSP `SymbolKind` for each value node type.
+ */
+const symbolKinds = new Map([
+    ["String", 15],
+    ["Number", 16],
+    ["Boolean", 17],
+    ["Array", 18],
+    ["Object", 19],
+    ["Null", 21]
+]);
+
+/**
+ * The closing bracket for each opening bracket.
+ */
+const bracketPairs = new Map([
+    ["{", "}"],
+    ["[", "]"]
+]);
+
+/**
+ * Ensures a Momoa location has a line and column. Locations created with the
+ * `offsetsOnly` option only have an offset and can't be converted.
+ * @param {Object} location The location to check.
+ * @returns {Object} The location.
+ * @throws {TypeError} When the location doesn't have a line.
+ */
+function requireLines(location) {
+
+    if (typeof location.line !== "number") {
+        throw new TypeError("Locations must have lines and columns. Don't use the offsetsOnly option.");
+    }
+
+    return location;
+}
+
+/**
+ * Converts a Momoa location into an LSP position.
+ * @param {Object} location The location with one-based `line` and `column`.
+ * @returns {Object} An LSP position with zero-based `line` and `character`.
+ * @throws {TypeError} When the location doesn't have a line.
+ */
+function toPosition(location) {
+    const { line, column } = requireLines(location);
+
+    return {
+        line: line - 1,
+        character: column - 1
+    };
+}
+
+/**
+ * Converts a Momoa `loc` into an LSP range.
+ * @param {Object} loc The `loc` property of a node or token.
+ * @returns {Object} An LSP range.
+ */
+function toRange(loc) {
+    return {
+        start: toPosition(loc.start),
+        end: toPosition(loc.end)
+    };
+}
+
+/**
+ * Determines if an LSP position is inside of a Momoa `loc`. A position at
+ * the very end of the `loc` is considered inside.
+ * @param {Object} position The LSP position.
+ * @param {Object} loc The `loc` property of a node.
+ * @returns {boolean} True if the position is inside, false if not.
+ * @throws {TypeError} When the `loc` doesn't have lines.
+ */
+function containsPosition(position, { start, end }) {
+    requireLines(start);
+
+    const line = position.line + 1;
+    const column = position.character + 1;
+
+    const afterStart = line > start.line || (line === start.line && column >= start.column);
+    const beforeEnd = line < end.line || (line === end.line && column <= end.column);
+
+    return afterStart && beforeEnd;
+}
+
+/**
+ * Finds the nodes containing an LSP position, from outermost to innermost.
+ * @param {Node} root The node to start from.
+ * @param {Object} position The LSP position.
+ * @returns {Array<Node>} The nodes containing the position.
+ */
+function findNodesAt(root, position) {
+
+    const nodes = [];
+    let node = root;
+
+    while (node) {
+        nodes.push(node);
+
+        const children = [];
+
+        for (const key of childKeys.get(node.type)) {
+            children.push(...[].concat(node[key]).filter(Boolean));
+        }
+
+        node = children.find(child => containsPosition(position, child.loc));
+    }
+
+    return nodes;
+}
+
+/**
+ * Creates the symbols for the children of a node.
+ * @param {Node} node The node whose children should be converted.
+ * @returns {Array<Object>} The symbols for the children.
+ */
+function createChildSymbols(node) {
+
+    if (node.type === "Object") {
+        return node.members.map(member => ({
+            name: member.name.value || "\"\"",
+            kind: symbolKinds.get(member.value.type),
+            range: toRange(member.loc),
+            selectionRange: toRange(member.name.loc),
+            children: createChildSymbols(member.value)
+        }));
+    }
+
+    if (node.type === "Array") {
+        return node.elements.map((element, i) => ({
+            name: String(i),
+            kind: symbolKinds.get(element.type),
+            range: toRange(element.loc),
+            selectionRange: toRange(element.loc),
+            children: createChildSymbols(element)
+        }));
+    }
+
+    return [];
+}
+
+/**
+ * Gets the tokens for a document or returns the tokens that were passed.
+ * @param {Node|Array<Token>} documentOrTokens A `Document` node parsed with
+ *      the `tokens` option or an array of tokens.
+ * @returns {Array<Token>} The tokens.
+ * @throws {TypeError} When a document without tokens is passed.
+ */
+function getTokens(documentOrTokens) {
+
+    if (Array.isArray(documentOrTokens)) {
+        return documentOrTokens;
+    }
+
+    if (!documentOrTokens.tokens) {
+        throw new TypeError("Document must be parsed with the tokens option.");
+    }
+
+    return documentOrTokens.tokens;
+}
+
+//-----------------------------------------------------------------------------
+// Exports
+//-----------------------------------------------------------------------------
+
+/**
+ * Creates a hierarchical outline of a document in the shape of the LSP
+ * `DocumentSymbol` type. Each object member becomes a symbol named after
+ * the member and each array element becomes a symbol named after its index.
+ * @param {Node} document The `Document` node to outline.
+ * @returns {Array<Object>} The symbols for the top-level members or elements,
+ *      each with `name`, `kind`, `range`, `selectionRange`, and `children`
+ *      properties.
+ * @throws {TypeError} When the document was parsed with `offsetsOnly`.
+ */
+export function getDocumentSymbols(document) {
+    requireLines(document.loc.start);
+
+    return document.body ? createChildSymbols(document.body) : [];
+}
+
+/**
+ * Calculates the regions of a document that an editor can fold: objects,
+ * arrays, and block comments that span more than one line. Each range is in
+ * the shape of the LSP `FoldingRange` type, where `startLine` is the line of
+ * the opening token and `endLine` is the line of the closing token.
+ * @param {Node|Array<Token>} documentOrTokens A `Document` node parsed with
+ *      the `tokens` option or an array of tokens. Include comment tokens to
+ *      fold block comments.
+ * @returns {Array<Object>} The folding ranges, sorted by `startLine`. Ranges
+ *      for block comments have a `kind` of `"comment"`.
+ * @throws {TypeError} When a document without tokens is passed or the
+ *      tokens were created with `offsetsOnly`.
+ */
+export function getFoldingRanges(documentOrTokens) {
+
+    const ranges = [];
+    const openTokens = [];
+
+    for (const token of getTokens(documentOrTokens)) {
+
+        requireLines(token.loc.start);
+
+        if (token.type === "BlockComment") {
+            if (token.loc.start.line < token.loc.end.line) {
+                ranges.push({
+                    startLine: token.loc.start.line - 1,
+                    endLine: token.loc.end.line - 1,
+                    kind: "comment"
+                });
+            }
+
+            continue;
+        }
+
+        if (token.value === "{" || token.value === "[") {
+            openTokens.push(token);
+        } else if ((token.value === "}" || token.value === "]") && openTokens.length) {
+            const openToken = openTokens.pop();
+
+            if (openToken.loc.start.line < token.loc.start.line) {
+                ranges.push({
+                    startLine: openToken.loc.start.line - 1,
+                    endLine: token.loc.start.line - 1
+                });
+            }
+        }
+    }
+
+    return ranges.sort((a, b) => a.startLine - b.startLine);
+}
+
+/**
+ * Calculates the chain of ranges an editor should select when repeatedly
+ * expanding the selection from a position, in the shape of the LSP
+ * `SelectionRange` type. The chain starts with the innermost node at the
+ * position and continues through each enclosing value, member, container,
+ * and the document, skipping ranges that are the same as their child's.
+ * @param {Node} document The `Document` node.
+ * @param {Object} position The LSP position with zero-based `line` and
+ *      `character` properties.
+ * @returns {Object|null} The innermost selection range, whose `parent`
+ *      property links to the next larger range, or `null` if the position
+ *      is outside of the document.
+ * @throws {TypeError} When the document was parsed with `offsetsOnly`.
+ */
+export function getSelectionRange(document, position) {
+
+    if (!containsPosition(position, document.loc)) {
+        return null;
+    }
+
+    let selectionRange = null;
+
+    for (const node of findNodesAt(document, position)) {
+        const range = toRange(node.loc);
+
+        if (selectionRange && JSON.stringify(range) === JSON.stringify(selectionRange.range)) {
+            continue;
+        }
+
+        selectionRange = selectionRange ? { range, parent: selectionRange } : { range };
+    }
+
+    return selectionRange;
+}
+
+/**
+ * Gathers the information an editor needs to show a hover for a position.
+ * When the position is on a member name, the information is for that
+ * member's value.
+ * @param {Node} document The `Document` node.
+ * @param {Object} position The LSP position with zero-based `line` and
+ *      `character` properties.
+ * @returns {Object|null} An object with the following properties, or `null`
+ *      if there is no value at the position:
+ *      - `node` - the value node.
+ *      - `pointer` - the JSON Pointer to the value.
+ *      - `preview` - a short description of the value: the JSON text of a
+ *        decoded scalar or the number of members or elements in a container.
+ *      - `container` - the `Object` or `Array` node that contains the value,
+ *        or `null` for the document body.
+ *      - `key` - the member name or element index of the value in its
+ *        container, or `null` for the document body.
+ *      - `range` - the LSP range of the value.
+ * @throws {TypeError} When the document was parsed with `offsetsOnly`.
+ */
+export function getHoverInfo(document, position) {
+
+    if (!document.body || !containsPosition(position, document.loc)) {
+        return null;
+    }
+
+    const nodes = findNodesAt(document, position);
+    const path = [];
+    let container = null;
+    let key = null;
+    let node = null;
+
+    for (let i = 1; i < nodes.length; i++) {
+        const current = nodes[i];
+        const parent = nodes[i - 1];
+
+        if (current.type === "Member") {
+            container = parent;
+            key = current.name.value;
+            node = current.value;
+            path.push(key);
+
+            // a member name is part of the member, not a separate value
+            if (nodes[i + 1] === current.name) {
+                break;
+            }
+        } else if (parent.type === "Array") {
+            container = parent;
+            key = parent.elements.indexOf(current);
+            node = current;
+            path.push(key);
+        } else if (parent.type === "Document") {
+            node = current;
+        }
+    }
+
+    if (!node) {
+        return null;
+    }
+
+    let preview;
+
+    switch (node.type) {
+    case "Object":
+        preview = `object with ${ node.members.length } member${ node.members.length === 1 ? "" : "s" }`;
+        break;
+
+    case "Array":
+        preview = `array with ${ node.elements.length } element${ node.elements.length === 1 ? "" : "s" }`;
+        break;
+
+    default:
+        preview = node.type === "Null" ? "null" : JSON.stringify(node.value);
+    }
+
+    return {
+        node,
+        pointer: formatPointer(path),
+        preview,
+        container,
+        key,
+        range: toRange(node.loc)
+    };
+}
+
+/**
+ * Finds the bracket that matches the bracket at a position, for features
+ * such as "jump to matching bracket". Because the search uses tokens,
+ * brackets inside of strings and comments are ignored.
+ * @param {Node|Array<Token>} documentOrTokens A `Document` node parsed with
+ *      the `tokens` option or an array of tokens.
+ * @param {Object} position The LSP position with zero-based `line` and
+ *      `character` properties. The position may be immediately before or
+ *      immediately after a bracket; when both apply, the bracket after the
+ *      position is used.
+ * @returns {Object|null} The LSP range of the matching bracket or `null` if
+ *      there is no bracket at the position or it has no match.
+ * @throws {TypeError} When a document without tokens is passed or the
+ *      tokens were created with `offsetsOnly`.
+ */
+export function getMatchingBracket(documentOrTokens, position) {
+
+    const tokens = getTokens(documentOrTokens);
+    const isBracket = token => token.type === "Punctuator" && /[[\]{}]/.test(token.value);
+    const candidates = tokens.filter(token => isBracket(token) && containsPosition(position, token.loc));
+
+    if (!candidates.length) {
+        return null;
+    }
+
+    const token = candidates[candidates.length - 1];
+    const index = tokens.indexOf(token);
+    const isOpening = bracketPairs.has(token.value);
+    const open = isOpening ? token.value : [...bracketPairs.keys()].find(key => bracketPairs.get(key) === token.value);
+    const close = bracketPairs.get(open);
+    const step = isOpening ? 1 : -1;
+    let depth = 0;
+
+    for (let i = index; i >= 0 && i < tokens.length; i += step) {
+        const { value } = tokens[i];
+
+        if (value === token.value) {
+            depth++;
+        } else if (value === (isOpening ? close : open)) {
+            depth--;
+
+            if (depth === 0) {
+                return toRange(tokens[i].loc);
+            }
+        }
+    }
+
+    return null;
+}
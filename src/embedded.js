@@ -0,0 +1,170 @@
+/**
+ * @fileoverview Parses JSON that is embedded inside of a JSON string.
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+import { parse, addContext } from "./parse.js";
+import { traverse } from "./traversal.js";
+import { escapeToChar } from "./syntax.js";
+import { ErrorWithLocation, formatErrorMessage, setErrorMessage } from "./errors.js";
+import { createLineIndex } from "./locations.js";
+
+//-----------------------------------------------------------------------------
+// Helpers
+//-----------------------------------------------------------------------------
+
+/**
+ * Decodes the raw text of a string literal while tracking where each
+ * decoded character came from.
+ * @param {string} raw The raw string literal, including quotes.
+ * @param {int} rawOffset The offset of the literal in the outer text.
+ * @returns {{value:string,offsets:Array<int>}} The decoded value and an
+ *      array mapping each index in the decoded value (plus one past the
+ *      end) to an offset in the outer text.
+ */
+function decodeWithOffsets(raw, rawOffset) {
+
+    let value = "";
+    const offsets = [];
+
+    // skip the opening quotation mark and stop before the closing one
+    let i = 1;
+
+    while (i < raw.length - 1) {
+        const c = raw.charAt(i);
+
+        offsets.push(rawOffset + i);
+
+        if (c !== "\\") {
+            value += c;
+            i++;
+            continue;
+        }
+
+        const escapeChar = raw.charAt(i + 1);
+
+        if (escapeChar === "u") {
+            value += String.fromCharCode(parseInt(raw.slice(i + 2, i + 6), 16));
+            i += 6;
+        } else {
+            value += escapeToChar.get(escapeChar);
+            i += 2;
+        }
+    }
+
+    offsets.push(rawOffset + raw.length - 1);
+
+    return { value, offsets };
+}
+
+//-----------------------------------------------------------------------------
+// Exports
+//-----------------------------------------------------------------------------
+
+/**
+ * Parses the value of a `String` node as JSON. Locations in the resulting
+ * AST (including tokens, ranges, and errors) refer to the outer text rather
+ * than to the decoded string, so an escape sequence such as `\"` maps back
+ * to its position in the string literal.
+ * @param {Node} node The `String` node containing the embedded JSON. It must
+ *      have been parsed from `text`.
+ * @param {string} text The text that `node` was parsed from.
+ * @param {Object} [options] Options to pass to `parse()`.
+ * @returns {Object} The AST representing the embedded JSON.
+ * @throws {TypeError} When `node` is not a `String` node.
+ * @throws {Error} When there is a parsing error.
+ */
+export function parseEmbedded(node, text, options = {}) {
+
+    if (!node || node.type !== "String") {
+        throw new TypeError("Embedded JSON can only be parsed from a String node.");
+    }
+
+    const { sourceName, ...parseOptions } = options;
+    const rawOffset = node.loc.start.offset;
+    const raw = text.slice(rawOffset, node.loc.end.offset);
+    const { value, offsets } = decodeWithOffsets(raw, rawOffset);
+    const lineIndex = createLineIndex(text);
+
+    function mapLocation(loc) {
+        return lineIndex.getLocation(offsets[loc.offset]);
+    }
+
+    function mapLocations(item) {
+        item.loc = {
+            start: mapLocation(item.loc.start),
+            end: mapLocation(item.loc.end)
+        };
+
+        if (item.range) {
+            item.range = [item.loc.start.offset, item.loc.end.offset];
+        }
+    }
+
+    let ast;
+
+    try {
+        ast = parse(value, parseOptions);
+    } catch (ex) {
+        if (ex instanceof ErrorWithLocation) {
+            const loc = mapLocation({ offset: ex.index });
+            const outerLocation = sourceName
+                ? `(${ sourceName }:${ loc.line }:${ loc.column })`
+                : `(${ loc.line }:${ loc.column })`;
+
+            // where an array or object was opened is part of some messages
+            if (ex.data.openLine) {
+                const openLoc = mapLocation({ offset: createLineIndex(value).getOffset(ex.data.openLine, ex.data.openColumn) });
+
+                ex.data.openLine = openLoc.line;
+                ex.data.openColumn = openLoc.column;
+            }
+
+            ex.line = loc.line;
+            ex.column = loc.column;
+            ex.index = loc.offset;
+
+            // the message is rebuilt so every location in it is in the outer text
+            setErrorMessage(ex, `${ formatErrorMessage(ex, {}) } ${ outerLocation }`);
+
+            // fixes are for the decoded text, which can't be edited directly
+            delete ex.suggestedFix;
+
+            if (sourceName) {
+                ex.sourceName = sourceName;
+            }
+
+            ex.context.forEach(mapLocations);
+            addContext(ex, ex.context);
+        }
+
+        throw ex;
+    }
+
+    traverse(ast, {
+        enter: mapLocations
+    });
+
+    if (ast.tokens) {
+        ast.tokens.forEach(mapLocations);
+    } else if (ast.comments) {
+
+        // comments are shared with tokens, so only map them once
+        ast.comments.forEach(mapLocations);
+    }
+
+    // the document range was created as an object, so rebuild it the same way
+    if (ast.range) {
+        ast.range = { range: [ast.loc.start.offset, ast.loc.end.offset] };
+    }
+
+    if (sourceName) {
+        ast.sourceName = sourceName;
+    }
+
+    return ast;
+}
@@ -3,21 +3,76 @@
  * @author Nicholas C. Zakas
  */
 
+//-----------------------------------------------------------------------------
+// Helpers
+//-----------------------------------------------------------------------------
+
+/**
+ * Replaces each `{name}` placeholder in a message template with the value
+ * of the matching property of the data. Placeholders without a matching
+ * property are left alone.
+ * @param {string} template The message template.
+ * @param {Object} data The values for the placeholders.
+ * @returns {string} The message.
+ */
+function formatTemplate(template, data) {
+    return template.replace(/\{(\w+)\}/g, (match, name) => (
+        Object.prototype.hasOwnProperty.call(data, name) ? String(data[name]) : match
+    ));
+}
+
+/*
+ * The message templates for each limit that can cause a `LimitExceeded`
+ * error, keyed by the name of the option.
+ */
+const limitTemplates = new Map([
+    ["maxExponentDigits", "Number exponent is longer than the maximum of {maximum} digits."],
+    ["maxInputBytes", "Input is longer than the maximum of {maximum} bytes."],
+    ["maxStringLength", "String is longer than the maximum of {maximum} characters."]
+]);
+
+//-----------------------------------------------------------------------------
+// Exports
+//-----------------------------------------------------------------------------
 
 /**
  * Base class that attaches location to an error.
  */
 export class ErrorWithLocation extends Error {
 
+    /**
+     * The `code` of each error of this class.
+     * @type string
+     */
+    static get code() {
+        return "ErrorWithLocation";
+    }
+
     /**
      * 
-     * @param {string} message The error message to report. 
+     * @param {string} message The error message to report. When `data` is
+     *      passed, this is a template whose `{name}` placeholders are
+     *      replaced with the values in `data`.
      * @param {int} loc.line The line on which the error occurred.
      * @param {int} loc.column The column in the line where the error occurrred.
-     * @param {int} loc.index The index in the string where the error occurred.
+     * @param {int} loc.offset The index in the string where the error occurred.
+     *      A location where `line` is 0 is synthetic, such as the location
+     *      of a node created by `fromValue()`, and isn't added to the
+     *      message.
+     * @param {Object} [data] The values used in the message.
      */
-    constructor(message, { line, column, index }) {
-        super(`${ message } (${ line }:${ column})`);
+    constructor(message, { line, column, offset }, data) {
+        const text = data ? formatTemplate(message, data) : message;
+
+        super(line === 0 ? text : `${ text } (${ line }:${ column})`);
+
+        /**
+         * A code identifying the kind of error, from the static `code` of
+         * the class so it doesn't change when class names are minified.
+         * @type string
+         * @property code
+         */
+        this.code = new.target.code;
 
         /**
          * The line on which the error occurred.
@@ -38,7 +93,61 @@ export class ErrorWithLocation extends Error {
          * @type int
          * @property index
          */
-        this.index = index;
+        this.index = offset;
+
+        /**
+         * The template the message was created from, without the location.
+         * @type string
+         * @property messageTemplate
+         */
+        this.messageTemplate = message;
+
+        /**
+         * The values used in the message, such as the character or token
+         * that was found, so the message can be reworded with
+         * `formatErrorMessage()`.
+         * @type Object
+         * @property data
+         */
+        this.data = data || {};
+    }
+
+    /**
+     * The location where the error occurred, in the same shape as the
+     * locations of nodes and tokens.
+     * @type Object
+     * @property location
+     */
+    get location() {
+        return {
+            line: this.line,
+            column: this.column,
+            offset: this.index
+        };
+    }
+
+    /**
+     * Returns a representation of the error that is safe to serialize
+     * with `JSON.stringify()`. The shape of this object is stable across
+     * releases.
+     * @returns {Object} An object with `code`, `message`, `severity`,
+     *      `range`, `sourceName`, and `suggestedFix` properties. The `range`
+     *      is `null` when the location is synthetic.
+     */
+    toJSON() {
+        const { location } = this;
+
+        return {
+            code: this.code,
+            message: this.message,
+            severity: "error",
+            range: this.line === 0 ? null : {
+                start: location,
+                end: { ...location }
+            },
+            sourceName: this.sourceName || null,
+            suggestedFix: this.suggestedFix || null
+        };
     }
 
 }
@@ -48,13 +157,21 @@ export class ErrorWithLocation extends Error {
  */
 export class UnexpectedChar extends ErrorWithLocation {
 
+    /**
+     * The `code` of each error of this class.
+     * @type string
+     */
+    static get code() {
+        return "UnexpectedChar";
+    }
+
     /**
      * Creates a new instance.
      * @param {string} unexpected The character that was found.
      * @param {Object} loc The location information for the found character.
      */
     constructor(unexpected, loc) {
-        super(`Unexpected character ${ unexpected } found.`, loc);
+        super("Unexpected character {character} found.", loc, { character: unexpected });
     }
 }
 
@@ -63,6 +180,14 @@ export class UnexpectedChar extends ErrorWithLocation {
  */
 export class UnexpectedToken extends ErrorWithLocation {
 
+    /**
+     * The `code` of each error of this class.
+     * @type string
+     */
+    static get code() {
+        return "UnexpectedToken";
+    }
+
     /**
      * Creates a new instance.
      * @param {string} expected The character that was expected. 
@@ -70,7 +195,10 @@ export class UnexpectedToken extends ErrorWithLocation {
      * @param {Object} loc The location information for the found character.
      */
     constructor(token) {
-        super(`Unexpected token ${ token.type }(${ token.value }) found.`, token.loc.start);
+        super("Unexpected token {tokenType}({tokenValue}) found.", token.loc.start, {
+            tokenType: token.type,
+            tokenValue: token.value
+        });
     }
 }
 
@@ -79,6 +207,14 @@ export class UnexpectedToken extends ErrorWithLocation {
  */
 export class UnexpectedEOF extends ErrorWithLocation {
 
+    /**
+     * The `code` of each error of this class.
+     * @type string
+     */
+    static get code() {
+        return "UnexpectedEOF";
+    }
+
     /**
      * Creates a new instance.
      * @param {Object} loc The location information for the found character.
@@ -87,3 +223,426 @@ export class UnexpectedEOF extends ErrorWithLocation {
         super("Unexpected end of input found.", loc);
     }
 }
+
+/**
+ * Error thrown when the input contains no JSON value.
+ */
+export class EmptyDocument extends ErrorWithLocation {
+
+    /**
+     * The `code` of each error of this class.
+     * @type string
+     */
+    static get code() {
+        return "EmptyDocument";
+    }
+
+    /**
+     * Creates a new instance.
+     * @param {Object} loc The location information for the start of input.
+     */
+    constructor(loc) {
+        super("Document is empty.", loc);
+    }
+}
+
+/**
+ * Error thrown when a line is longer than the allowed maximum.
+ */
+export class LineTooLong extends ErrorWithLocation {
+
+    /**
+     * The `code` of each error of this class.
+     * @type string
+     */
+    static get code() {
+        return "LineTooLong";
+    }
+
+    /**
+     * Creates a new instance.
+     * @param {int} maxLineLength The maximum number of characters allowed
+     *      on a line.
+     * @param {Object} loc The location information for the first character
+     *      past the maximum.
+     */
+    constructor(maxLineLength, loc) {
+        super("Line {line} is longer than the maximum of {maxLineLength} characters.", loc, {
+            line: loc.line,
+            maxLineLength
+        });
+    }
+}
+
+/**
+ * Error thrown when a value doesn't have the expected type.
+ */
+export class UnexpectedType extends ErrorWithLocation {
+
+    /**
+     * The `code` of each error of this class.
+     * @type string
+     */
+    static get code() {
+        return "UnexpectedType";
+    }
+
+    /**
+     * Creates a new instance.
+     * @param {string} expected The node type that was expected.
+     * @param {Node} node The node that was found.
+     * @param {string} pointer The JSON Pointer to the node.
+     */
+    constructor(expected, node, pointer) {
+        super("Expected {expected} at \"{pointer}\" but found {found}.", node.loc.start, {
+            expected,
+            pointer,
+            found: node.type
+        });
+    }
+}
+
+/**
+ * Error thrown when parsing is cancelled before it's complete.
+ */
+export class ParseCancelled extends ErrorWithLocation {
+
+    /**
+     * The `code` of each error of this class.
+     * @type string
+     */
+    static get code() {
+        return "ParseCancelled";
+    }
+
+    /**
+     * Creates a new instance.
+     * @param {Object} loc The location information for the last token read.
+     */
+    constructor(loc) {
+        super("Parsing was cancelled.", loc);
+    }
+}
+
+/**
+ * Error thrown when an array or object is closed by the wrong bracket.
+ */
+export class MismatchedBracket extends ErrorWithLocation {
+
+    /**
+     * The `code` of each error of this class.
+     * @type string
+     */
+    static get code() {
+        return "MismatchedBracket";
+    }
+
+    /**
+     * Creates a new instance.
+     * @param {Token} token The closing bracket that was found.
+     * @param {Token} openToken The bracket that opened the array or object.
+     */
+    constructor(token, openToken) {
+        super("Mismatched closing bracket {bracket} found; {container} opened at {openLine}:{openColumn}.", token.loc.start, {
+            bracket: token.value,
+            container: openToken.value === "[" ? "array" : "object",
+            openLine: openToken.loc.start.line,
+            openColumn: openToken.loc.start.column
+        });
+    }
+}
+
+/**
+ * Error thrown when a colon is found between array elements.
+ */
+export class UnexpectedColon extends ErrorWithLocation {
+
+    /**
+     * The `code` of each error of this class.
+     * @type string
+     */
+    static get code() {
+        return "UnexpectedColon";
+    }
+
+    /**
+     * Creates a new instance.
+     * @param {Token} token The colon that was found.
+     * @param {Token} openToken The bracket that opened the array.
+     */
+    constructor(token, openToken) {
+        super("Unexpected colon found in array opened at {openLine}:{openColumn}; arrays contain values, not members.", token.loc.start, {
+            openLine: openToken.loc.start.line,
+            openColumn: openToken.loc.start.column
+        });
+    }
+}
+
+/**
+ * Error thrown when the root value of a document isn't an allowed type.
+ */
+export class UnexpectedRootType extends ErrorWithLocation {
+
+    /**
+     * The `code` of each error of this class.
+     * @type string
+     */
+    static get code() {
+        return "UnexpectedRootType";
+    }
+
+    /**
+     * Creates a new instance.
+     * @param {Array<string>} types The node types that are allowed.
+     * @param {Node} node The root value that was found.
+     */
+    constructor(types, node) {
+        const expected = types.length > 2
+            ? `${ types.slice(0, -1).join(", ") }, or ${ types[types.length - 1] }`
+            : types.join(" or ");
+        super("Expected the root value to be {expected} but found {found}.", node.loc.start, {
+            expected,
+            found: node.type,
+            types: [...types]
+        });
+    }
+}
+
+/**
+ * Error thrown when a number has a fraction or exponent but only integers
+ * are allowed.
+ */
+export class NonIntegerNumber extends ErrorWithLocation {
+
+    /**
+     * The `code` of each error of this class.
+     * @type string
+     */
+    static get code() {
+        return "NonIntegerNumber";
+    }
+
+    /**
+     * Creates a new instance.
+     * @param {Token} token The number token that was found.
+     */
+    constructor(token) {
+        super("Expected an integer but found {number}.", token.loc.start, { number: token.value });
+    }
+}
+
+/**
+ * Error thrown when a string is rejected by the `validateString` option.
+ */
+export class InvalidString extends ErrorWithLocation {
+
+    /**
+     * The `code` of each error of this class.
+     * @type string
+     */
+    static get code() {
+        return "InvalidString";
+    }
+
+    /**
+     * Creates a new instance.
+     * @param {string} message The message returned by the validator.
+     * @param {Object} loc The location information for the string.
+     */
+    constructor(message, loc) {
+        super("{message}", loc, { message });
+    }
+}
+
+/**
+ * Error thrown when an object member name isn't a string.
+ */
+export class ObjectKeyMustBeString extends ErrorWithLocation {
+
+    /**
+     * The `code` of each error of this class.
+     * @type string
+     */
+    static get code() {
+        return "ObjectKeyMustBeString";
+    }
+
+    /**
+     * Creates a new instance.
+     * @param {Token} token The token found where the member name should be.
+     */
+    constructor(token) {
+        super("Object member names must be strings but found {tokenType}({tokenValue}).", token.loc.start, {
+            tokenType: token.type,
+            tokenValue: token.value
+        });
+    }
+}
+
+/**
+ * Error thrown when the input exceeds one of the configured limits.
+ */
+export class LimitExceeded extends ErrorWithLocation {
+
+    /**
+     * The `code` of each error of this class.
+     * @type string
+     */
+    static get code() {
+        return "LimitExceeded";
+    }
+
+    /**
+     * Creates a new instance.
+     * @param {string} limit The name of the option setting the limit, such
+     *      as `"maxStringLength"`.
+     * @param {int} maximum The value of the limit.
+     * @param {Object} loc The location information for where the limit was
+     *      exceeded.
+     */
+    constructor(limit, maximum, loc) {
+        super(limitTemplates.get(limit), loc, { limit, maximum });
+    }
+}
+
+/**
+ * Error thrown when bytes aren't valid UTF-8.
+ */
+export class InvalidUtf8 extends ErrorWithLocation {
+
+    /**
+     * The `code` of each error of this class.
+     * @type string
+     */
+    static get code() {
+        return "InvalidUtf8";
+    }
+
+    /**
+     * Creates a new instance.
+     * @param {Object} loc The location information for the first invalid
+     *      character in the decoded text.
+     * @param {int} byteOffset The offset of the first invalid byte.
+     */
+    constructor(loc, byteOffset) {
+        super("Invalid UTF-8 byte sequence found at byte {byteOffset}.", loc, { byteOffset });
+
+        /**
+         * The offset of the first invalid byte.
+         * @type int
+         * @property byteOffset
+         */
+        this.byteOffset = byteOffset;
+    }
+}
+
+/**
+ * Error thrown when a comment is found but comments aren't allowed.
+ */
+export class CommentsNotAllowed extends ErrorWithLocation {
+
+    /**
+     * The `code` of each error of this class.
+     * @type string
+     */
+    static get code() {
+        return "CommentsNotAllowed";
+    }
+
+    /**
+     * Creates a new instance.
+     * @param {Object} loc The location information for the start of the
+     *      comment.
+     */
+    constructor(loc) {
+        super("Comments aren't allowed in JSON. Use the comments option to parse JSONC.", loc);
+    }
+}
+
+/**
+ * Error thrown when a whitespace character that JSON doesn't allow is found.
+ */
+export class IrregularWhitespace extends ErrorWithLocation {
+
+    /**
+     * The `code` of each error of this class.
+     * @type string
+     */
+    static get code() {
+        return "IrregularWhitespace";
+    }
+
+    /**
+     * Creates a new instance.
+     * @param {string} character The whitespace character that was found.
+     * @param {string} name The Unicode name of the character.
+     * @param {Object} loc The location information for the character.
+     */
+    constructor(character, name, loc) {
+        const codePoint = "U+" + character.charCodeAt(0).toString(16).toUpperCase().padStart(4, "0");
+
+        super("Irregular whitespace {codePoint} ({name}) found. Only spaces, tabs, carriage returns, and line feeds are allowed.", loc, {
+            codePoint,
+            name
+        });
+
+        /**
+         * The code point of the character, such as `"U+00A0"`.
+         * @type string
+         * @property codePoint
+         */
+        this.codePoint = codePoint;
+    }
+}
+
+/**
+ * Creates the message for an error using different wording, such as a
+ * translation, while keeping the error's code and data. The location isn't
+ * included in the message.
+ * @param {Error} error The error to create the message for.
+ * @param {Object|Function} messages Either an object whose keys are error
+ *      codes and whose values are message templates or functions that
+ *      receive the error's `data` and return a message, or a function that
+ *      receives the error's `code` and `data` and returns a message. A
+ *      template's `{name}` placeholders are replaced with the values in
+ *      `data`. When there isn't a message for the error's code, the
+ *      default message is used.
+ * @returns {string} The message.
+ */
+export function formatErrorMessage(error, messages) {
+
+    if (!(error instanceof ErrorWithLocation)) {
+        return error.message;
+    }
+
+    let template = typeof messages === "function"
+        ? messages(error.code, error.data)
+        : messages[error.code];
+
+    if (typeof template === "function") {
+        template = template(error.data);
+    }
+
+    return formatTemplate(typeof template === "string" ? template : error.messageTemplate, error.data);
+}
+
+/**
+ * Changes the message of an error that was already created, such as to add
+ * the name of the source, and updates the first line of its stack trace
+ * to match, because the stack trace includes the message it was created
+ * with.
+ * @param {Error} error The error to update.
+ * @param {string} message The new message.
+ * @returns {void}
+ */
+export function setErrorMessage(error, message) {
+
+    const { stack } = error;
+    const index = typeof stack === "string" ? stack.indexOf(error.message) : -1;
+
+    if (index !== -1) {
+        error.stack = stack.slice(0, index) + message + stack.slice(index + error.message.length);
+    }
+
+    error.message = message;
+}
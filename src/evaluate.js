@@ -37,7 +37,9 @@ export function evaluate(node) {
     }    
 
     case "Document":
-        return evaluate(node.body);
+
+        // an empty document (see `allowEmpty`) has no value
+        return node.body ? evaluate(node.body) : undefined;
 
     case "Property":
         throw new Error("Cannot evaluate object property outside of an object.");
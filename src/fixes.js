@@ -0,0 +1,344 @@
+/**
+ * @fileoverview Suggested fixes for common syntax errors.
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+import { UnexpectedChar, UnexpectedToken, IrregularWhitespace } from "./errors.js";
+import { isComment } from "./tokens.js";
+import { isWhitespace, isIrregularWhitespace } from "./syntax.js";
+import { createLineIndex } from "./locations.js";
+
+//-----------------------------------------------------------------------------
+// Helpers
+//-----------------------------------------------------------------------------
+
+const valueStartTypes = new Set(["String", "Number", "Boolean", "Null"]);
+
+// a drive letter followed by a backslash that doesn't escape a backslash
+const windowsPathPattern = /^[a-z]:\\(?![\\"])/i;
+
+function isIdentifierStart(c) {
+    return /[a-z_$]/i.test(c);
+}
+
+function isIdentifierChar(c) {
+    return /[\w$]/.test(c);
+}
+
+/**
+ * Determines if a token can start a value.
+ * @param {Token} token The token to check.
+ * @returns {boolean} True if the token starts a value.
+ */
+function isValueStart(token) {
+    return valueStartTypes.has(token.type) ||
+        token.value === "[" || token.value === "{";
+}
+
+/**
+ * Determines if a token can end a value.
+ * @param {Token} token The token to check.
+ * @returns {boolean} True if the token ends a value.
+ */
+function isValueEnd(token) {
+    return valueStartTypes.has(token.type) ||
+        token.value === "]" || token.value === "}";
+}
+
+/**
+ * Finds the index of the significant token before a given index.
+ * @param {Array<Token>} tokens The tokens to search.
+ * @param {int} index The index to start searching before.
+ * @returns {int} The index of the previous token or -1 if there isn't one.
+ */
+function findPreviousIndex(tokens, index) {
+    let i = index - 1;
+
+    while (i >= 0 && isComment(tokens[i])) {
+        i--;
+    }
+
+    return i;
+}
+
+/**
+ * Finds the opening bracket of the array or object that contains a token.
+ * @param {Array<Token>} tokens The tokens to search.
+ * @param {int} index The index of the contained token.
+ * @returns {string|undefined} Either `"["`, `"{"`, or `undefined` if the
+ *      token isn't inside an array or object.
+ */
+function findContainer(tokens, index) {
+    let depth = 0;
+
+    for (let i = index - 1; i >= 0; i--) {
+        const { value } = tokens[i];
+
+        if (value === "]" || value === "}") {
+            depth++;
+        } else if (value === "[" || value === "{") {
+            if (depth === 0) {
+                return value;
+            }
+
+            depth--;
+        }
+    }
+
+    return undefined;
+}
+
+/**
+ * Creates a fix for a string that uses single quotes.
+ * @param {string} text The source text.
+ * @param {int} start The offset of the opening quote.
+ * @returns {Object|undefined} The fix or `undefined` if the string isn't
+ *      closed on the same line.
+ */
+function fixSingleQuotes(text, start) {
+
+    let value = "";
+
+    for (let i = start + 1; i < text.length; i++) {
+        const c = text[i];
+
+        if (c === "'") {
+            return {
+                message: "Use double quotes.",
+                range: [start, i + 1],
+                text: `"${ value }"`
+            };
+        }
+
+        if (c === "\n" || c === "\r") {
+            return undefined;
+        }
+
+        if (c === "\\") {
+            const next = text[++i];
+            value += next === "'" ? next : c + next;
+        } else {
+            value += c === "\"" ? "\\\"" : c;
+        }
+    }
+
+    return undefined;
+}
+
+/**
+ * Finds the double-quoted string on a line that contains an offset.
+ * @param {string} text The source text.
+ * @param {int} index The offset inside of the string.
+ * @returns {Array<int>|undefined} The offsets of the opening and closing
+ *      quotes or `undefined` if the offset isn't inside of a string that's
+ *      closed on the same line.
+ */
+function findEnclosingString(text, index) {
+
+    let i = index - createLineIndex(text).getLocation(index).column + 1;
+
+    while (i < index) {
+        if (text[i] !== "\"") {
+            i++;
+            continue;
+        }
+
+        let end = i + 1;
+
+        while (end < text.length && text[end] !== "\"" && text[end] !== "\r" && text[end] !== "\n") {
+            end += text[end] === "\\" ? 2 : 1;
+        }
+
+        if (end > index) {
+            return text[end] === "\"" ? [i, end] : undefined;
+        }
+
+        i = end + 1;
+    }
+
+    return undefined;
+}
+
+/**
+ * Creates a fix for an object key without quotes.
+ * @param {string} text The source text.
+ * @param {int} index The offset of a character inside of the key.
+ * @returns {Object|undefined} The fix or `undefined` if the text at `index`
+ *      doesn't look like an unquoted key.
+ */
+function fixUnquotedKey(text, index) {
+
+    let start = index;
+    let end = index;
+
+    while (start > 0 && isIdentifierChar(text[start - 1])) {
+        start--;
+    }
+
+    while (end < text.length && isIdentifierChar(text[end])) {
+        end++;
+    }
+
+    if (!isIdentifierStart(text[start])) {
+        return undefined;
+    }
+
+    let before = start - 1;
+    let after = end;
+
+    while (before >= 0 && (isWhitespace(text[before]) || isIrregularWhitespace(text[before]))) {
+        before--;
+    }
+
+    while (after < text.length && (isWhitespace(text[after]) || isIrregularWhitespace(text[after]))) {
+        after++;
+    }
+
+    if ((text[before] !== "{" && text[before] !== ",") || text[after] !== ":") {
+        return undefined;
+    }
+
+    const key = text.slice(start, end);
+
+    return {
+        message: `Add quotes around "${ key }".`,
+        range: [start, end],
+        text: `"${ key }"`
+    };
+}
+
+/**
+ * Creates a fix for an unexpected token.
+ * @param {Array<Token>} tokens The tokens of the document.
+ * @param {int} offset The offset of the unexpected token.
+ * @returns {Object|undefined} The fix or `undefined` if there isn't one.
+ */
+function fixUnexpectedToken(tokens, offset) {
+
+    const index = tokens.findIndex(token => token.loc.start.offset === offset);
+
+    if (index === -1) {
+        return undefined;
+    }
+
+    const token = tokens[index];
+    const previousIndex = findPreviousIndex(tokens, index);
+    const previous = tokens[previousIndex];
+
+    if (!previous) {
+        return undefined;
+    }
+
+    // trailing comma
+    if (previous.value === "," && (token.value === "]" || token.value === "}")) {
+        return {
+            message: "Remove the trailing comma.",
+            range: [previous.loc.start.offset, previous.loc.end.offset],
+            text: ""
+        };
+    }
+
+    // missing comma
+    if (isValueEnd(previous) && isValueStart(token)) {
+
+        /*
+         * In an object, a string right after an opening brace or comma is
+         * a key, so the problem is a missing colon rather than a comma.
+         */
+        if (findContainer(tokens, previousIndex) === "{") {
+            const beforePrevious = tokens[findPreviousIndex(tokens, previousIndex)];
+
+            if (beforePrevious && (beforePrevious.value === "{" || beforePrevious.value === ",")) {
+                return undefined;
+            }
+        } else if (!findContainer(tokens, previousIndex)) {
+            return undefined;
+        }
+
+        const end = previous.loc.end.offset;
+
+        return {
+            message: "Add a comma.",
+            range: [end, end],
+            text: ","
+        };
+    }
+
+    return undefined;
+}
+
+//-----------------------------------------------------------------------------
+// Exports
+//-----------------------------------------------------------------------------
+
+/**
+ * Calculates a fix for a parsing error caused by a common mistake: a
+ * single-quoted string, an unquoted object key, a trailing comma, a
+ * missing comma between members or elements, a Windows path with
+ * backslashes that aren't escaped, or irregular whitespace. The fix is an edit object
+ * that can be passed to `applyEdits()` along with a `message` describing
+ * the fix.
+ * @param {ErrorWithLocation} error The error to fix.
+ * @param {string} text The text that was parsed.
+ * @param {Array<Token>} [tokens] The tokens of the text, if tokenizing
+ *      succeeded.
+ * @returns {Object|undefined} The fix or `undefined` if there isn't one.
+ */
+export function getSuggestedFix(error, text, tokens) {
+
+    if (error instanceof UnexpectedChar) {
+
+        if (text[error.index] === "'") {
+            return fixSingleQuotes(text, error.index);
+        }
+
+        const string = findEnclosingString(text, error.index);
+
+        return string
+            ? getWindowsPathFix(text, string[0], string[1] + 1)
+            : fixUnquotedKey(text, error.index);
+    }
+
+    if (error instanceof IrregularWhitespace) {
+        return {
+            message: "Replace with a space.",
+            range: [error.index, error.index + 1],
+            text: " "
+        };
+    }
+
+    if (error instanceof UnexpectedToken && tokens) {
+        return fixUnexpectedToken(tokens, error.index);
+    }
+
+    return undefined;
+}
+
+/**
+ * Calculates a fix for a string that looks like a Windows path whose
+ * backslashes aren't escaped, such as `"C:\temp"`, by doubling each
+ * backslash that doesn't already escape a backslash or a quote.
+ * @param {string} text The source text.
+ * @param {int} start The offset of the opening quote.
+ * @param {int} end The offset after the closing quote.
+ * @returns {Object|undefined} The fix or `undefined` if the string doesn't
+ *      look like a Windows path.
+ */
+export function getWindowsPathFix(text, start, end) {
+
+    const content = text.slice(start + 1, end - 1);
+
+    if (!windowsPathPattern.test(content)) {
+        return undefined;
+    }
+
+    return {
+        message: "Escape the backslashes in the path.",
+        range: [start, end],
+        text: `"${ content.replace(/\\([\s\S])/g, (match, c) => (c === "\\" || c === "\"" ? match : `\\\\${ c }`)) }"`
+    };
+}
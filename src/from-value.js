@@ -0,0 +1,184 @@
+/**
+ * @fileoverview Converts JavaScript values into a Momoa AST.
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+import { types as t } from "./types.js";
+import { createSyntheticLocation } from "./locations.js";
+
+//-----------------------------------------------------------------------------
+// Helpers
+//-----------------------------------------------------------------------------
+
+/**
+ * Gets the value that represents a value in JSON. Like `JSON.stringify()`,
+ * an object with a `toJSON()` method is represented by what it returns.
+ * @param {*} value The value.
+ * @param {string} key The member name or array index of the value, or an
+ *      empty string for the root value.
+ * @returns {*} The value to represent.
+ */
+function getJSONValue(value, key) {
+    if (value !== null && typeof value === "object" && typeof value.toJSON === "function") {
+        return value.toJSON(key);
+    }
+
+    return value;
+}
+
+/**
+ * Determines if a value is a plain object, which is one created by an
+ * object literal, `new Object()`, or `Object.create(null)`.
+ * @param {Object} value The value to check.
+ * @returns {boolean} True if the value is a plain object, false if not.
+ */
+function isPlainObject(value) {
+    const prototype = Object.getPrototypeOf(value);
+    return prototype === Object.prototype || prototype === null;
+}
+
+/**
+ * Creates a node for the given value.
+ * @param {*} root The value to convert.
+ * @returns {Node} The node representing the value.
+ * @throws {TypeError} When the value cannot be represented in JSON.
+ */
+function createNode(root) {
+
+    let result = null;
+
+    // the objects and arrays containing the value being converted
+    const ancestors = new Set();
+
+    /*
+     * Values still to be converted, with the next one last, so deeply
+     * nested values can't overflow the call stack. Each has a function
+     * to add its node to its parent. An `exit` item marks the end of an
+     * object's or array's values.
+     */
+    const stack = [{
+        value: root,
+        key: "",
+        add(node) {
+            result = node;
+        }
+    }];
+
+    while (stack.length) {
+        const item = stack.pop();
+
+        if (item.exit) {
+            ancestors.delete(item.exit);
+            continue;
+        }
+
+        const value = getJSONValue(item.value, item.key);
+
+        if (value === null) {
+            item.add(t.null({ loc: createSyntheticLocation() }));
+            continue;
+        }
+
+        switch (typeof value) {
+        case "string":
+            item.add(t.string(value, { loc: createSyntheticLocation() }));
+            break;
+
+        case "boolean":
+            item.add(t.boolean(value, { loc: createSyntheticLocation() }));
+            break;
+
+        case "number":
+            if (!Number.isFinite(value)) {
+                throw new TypeError(`Cannot represent ${ value } in JSON.`);
+            }
+
+            item.add(t.number(value, { loc: createSyntheticLocation() }));
+            break;
+
+        case "object": {
+            if (ancestors.has(value)) {
+                throw new TypeError("Cannot represent a circular structure in JSON.");
+            }
+
+            const isArray = Array.isArray(value);
+
+            if (!isArray && !isPlainObject(value)) {
+                const name = typeof value.constructor === "function" && value.constructor.name;
+                const description = name ? `a ${ name } object` : "an object that isn't a plain object";
+
+                throw new TypeError(`Cannot represent ${ description } in JSON.`);
+            }
+
+            ancestors.add(value);
+            stack.push({ exit: value });
+
+            if (isArray) {
+                const elements = [];
+
+                item.add(t.array(elements, { loc: createSyntheticLocation() }));
+
+                for (let i = value.length - 1; i >= 0; i--) {
+                    stack.push({
+                        value: value[i],
+                        key: String(i),
+                        add(node) {
+                            elements.push(node);
+                        }
+                    });
+                }
+            } else {
+                const members = [];
+                const keys = Object.keys(value);
+
+                item.add(t.object(members, { loc: createSyntheticLocation() }));
+
+                for (let i = keys.length - 1; i >= 0; i--) {
+                    const key = keys[i];
+
+                    stack.push({
+                        value: value[key],
+                        key,
+                        add(node) {
+                            members.push(t.member(
+                                t.string(key, { loc: createSyntheticLocation() }),
+                                node,
+                                { loc: createSyntheticLocation() }
+                            ));
+                        }
+                    });
+                }
+            }
+            break;
+        }
+
+        default:
+            throw new TypeError(`Cannot represent a value of type ${ typeof value } in JSON.`);
+        }
+    }
+
+    return result;
+}
+
+//-----------------------------------------------------------------------------
+// Exports
+//-----------------------------------------------------------------------------
+
+/**
+ * Converts a JavaScript value into a Momoa AST. Because the nodes don't come
+ * from source text, each node receives a synthetic location where every
+ * `line`, `column`, and `offset` is 0.
+ * @param {*} value The value to convert. Must be a string, finite number,
+ *      boolean, `null`, array, or plain object, or an object with a
+ *      `toJSON()` method that returns one, such as a `Date`.
+ * @returns {Node} A `Document` node representing the value.
+ * @throws {TypeError} When the value (or a value it contains) cannot be
+ *      represented in JSON, such as a `Map`, or contains itself.
+ */
+export function fromValue(value) {
+    return t.document(createNode(value), { loc: createSyntheticLocation() });
+}
@@ -0,0 +1,97 @@
+/**
+ * @fileoverview File system helpers for parsing JSON files. These are only
+ *      available in Node.js and are built into a separate file so that the
+ *      main package doesn't depend on Node.js built-in modules.
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+import { promises as fsp } from "fs";
+// the main entry point is external when bundled, so errors and parse information are shared
+import { parseBytes } from "./index.js";
+
+//-----------------------------------------------------------------------------
+// Data
+//-----------------------------------------------------------------------------
+
+// the most files read at once so large sets of files don't run out of handles
+const MAX_OPEN_FILES = 16;
+
+//-----------------------------------------------------------------------------
+// Exports
+//-----------------------------------------------------------------------------
+
+/**
+ * Reads and parses a file. The file is parsed with its path as the
+ * `sourceName` option, and errors that occur while reading the file also
+ * have the path as their `sourceName` property, so every error identifies
+ * the file it came from.
+ * @param {string} filePath The path of the file to parse.
+ * @param {Object} [options] Options to pass to `parse()`.
+ * @returns {Promise<Object>} The `Document` node.
+ * @throws {Error} When the file can't be read, isn't valid UTF-8, or can't
+ *      be parsed.
+ */
+export async function parseFile(filePath, options) {
+
+    let bytes;
+
+    try {
+        bytes = await fsp.readFile(filePath);
+    } catch (error) {
+        error.sourceName = filePath;
+        throw error;
+    }
+
+    return parseBytes(bytes, { ...options, sourceName: filePath });
+}
+
+/**
+ * Reads and parses multiple files in parallel, up to 16 at a time. Each file
+ * is parsed with its path as the `sourceName` option so errors identify the
+ * file they came from. A failure in one file doesn't prevent the others
+ * from being parsed.
+ * @param {Iterable<string>} filePaths The paths of the files to parse.
+ * @param {Object} [options] Options to pass to `parse()`.
+ * @returns {Promise<Map<string,Object>>} A map of each file path to an
+ *      object with either an `ast` property containing the parsed document
+ *      or an `error` property containing the error that occurred while
+ *      reading or parsing the file.
+ */
+export async function parseFiles(filePaths, options) {
+
+    const paths = [...filePaths];
+    const results = new Array(paths.length);
+    let nextIndex = 0;
+
+    /**
+     * Parses files one at a time until there are none left.
+     * @returns {Promise<void>}
+     */
+    async function parseNextFiles() {
+        while (nextIndex < paths.length) {
+            const index = nextIndex++;
+
+            try {
+                results[index] = {
+                    ast: await parseFile(paths[index], options)
+                };
+            } catch (error) {
+                results[index] = { error };
+            }
+        }
+    }
+
+    const readers = [];
+
+    for (let i = 0; i < Math.min(paths.length, MAX_OPEN_FILES); i++) {
+        readers.push(parseNextFiles());
+    }
+
+    await Promise.all(readers);
+
+    return new Map(paths.map((filePath, i) => [filePath, results[i]]));
+}
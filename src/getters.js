@@ -0,0 +1,197 @@
+/**
+ * @fileoverview Typed getters for reading values by JSON Pointer.
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+import { resolvePointer, formatPointer } from "./pointer.js";
+import { evaluate } from "./evaluate.js";
+import { UnexpectedType } from "./errors.js";
+
+//-----------------------------------------------------------------------------
+// Helpers
+//-----------------------------------------------------------------------------
+
+/**
+ * Finds the node at a pointer and evaluates it if it has the expected type.
+ * @param {Node} root The node to start from.
+ * @param {string|Array<string|int>} pointer The JSON Pointer or an array of
+ *      unescaped segments.
+ * @param {string} type The expected node type.
+ * @param {*} defaultValue The value to return when there is no node.
+ * @param {boolean} [nullable=false] Determines if a `null` value is
+ *      returned as `null` instead of causing an error.
+ * @returns {*} The evaluated node or the default value.
+ * @throws {UnexpectedType} When the node has a different type.
+ */
+function getValue(root, pointer, type, defaultValue, nullable = false) {
+
+    const node = resolvePointer(root, pointer);
+
+    if (!node) {
+        return defaultValue;
+    }
+
+    if (nullable && node.type === "Null") {
+        return null;
+    }
+
+    if (node.type !== type) {
+        const text = typeof pointer === "string" ? pointer : formatPointer(pointer);
+        throw new UnexpectedType(nullable ? `${ type } or Null` : type, node, text);
+    }
+
+    return evaluate(node);
+}
+
+//-----------------------------------------------------------------------------
+// Exports
+//-----------------------------------------------------------------------------
+
+/**
+ * Gets the string that a JSON Pointer refers to.
+ * @param {Node} root The node to start from.
+ * @param {string|Array<string|int>} pointer The JSON Pointer or an array of
+ *      unescaped segments.
+ * @param {*} [defaultValue] The value to return when there is no match.
+ * @returns {string|*} The string or the default value.
+ * @throws {UnexpectedType} When the value isn't a string.
+ */
+export function getString(root, pointer, defaultValue) {
+    return getValue(root, pointer, "String", defaultValue);
+}
+
+/**
+ * Gets the number that a JSON Pointer refers to.
+ * @param {Node} root The node to start from.
+ * @param {string|Array<string|int>} pointer The JSON Pointer or an array of
+ *      unescaped segments.
+ * @param {*} [defaultValue] The value to return when there is no match.
+ * @returns {number|*} The number or the default value.
+ * @throws {UnexpectedType} When the value isn't a number.
+ */
+export function getNumber(root, pointer, defaultValue) {
+    return getValue(root, pointer, "Number", defaultValue);
+}
+
+/**
+ * Gets the boolean that a JSON Pointer refers to.
+ * @param {Node} root The node to start from.
+ * @param {string|Array<string|int>} pointer The JSON Pointer or an array of
+ *      unescaped segments.
+ * @param {*} [defaultValue] The value to return when there is no match.
+ * @returns {boolean|*} The boolean or the default value.
+ * @throws {UnexpectedType} When the value isn't a boolean.
+ */
+export function getBoolean(root, pointer, defaultValue) {
+    return getValue(root, pointer, "Boolean", defaultValue);
+}
+
+/**
+ * Gets the array that a JSON Pointer refers to as a JavaScript array.
+ * @param {Node} root The node to start from.
+ * @param {string|Array<string|int>} pointer The JSON Pointer or an array of
+ *      unescaped segments.
+ * @param {*} [defaultValue] The value to return when there is no match.
+ * @returns {Array|*} The array or the default value.
+ * @throws {UnexpectedType} When the value isn't an array.
+ */
+export function getArray(root, pointer, defaultValue) {
+    return getValue(root, pointer, "Array", defaultValue);
+}
+
+/**
+ * Gets the object that a JSON Pointer refers to as a JavaScript object.
+ * @param {Node} root The node to start from.
+ * @param {string|Array<string|int>} pointer The JSON Pointer or an array of
+ *      unescaped segments.
+ * @param {*} [defaultValue] The value to return when there is no match.
+ * @returns {Object|*} The object or the default value.
+ * @throws {UnexpectedType} When the value isn't an object.
+ */
+export function getObject(root, pointer, defaultValue) {
+    return getValue(root, pointer, "Object", defaultValue);
+}
+
+/**
+ * Gets the string that a JSON Pointer refers to, treating `null` the same
+ * as a missing value.
+ * @param {Node} root The node to start from.
+ * @param {string|Array<string|int>} pointer The JSON Pointer or an array of
+ *      unescaped segments.
+ * @param {boolean} [options.nullable=true] Determines if a `null` value is
+ *      returned as `null` instead of causing an error.
+ * @returns {string|null} The string or `null` if the value is `null` or
+ *      there is no match.
+ * @throws {UnexpectedType} When the value isn't a string or `null`.
+ */
+export function getOptionalString(root, pointer, { nullable = true } = {}) {
+    return getValue(root, pointer, "String", null, nullable);
+}
+
+/**
+ * Gets the number that a JSON Pointer refers to, treating `null` the same
+ * as a missing value.
+ * @param {Node} root The node to start from.
+ * @param {string|Array<string|int>} pointer The JSON Pointer or an array of
+ *      unescaped segments.
+ * @param {boolean} [options.nullable=true] Determines if a `null` value is
+ *      returned as `null` instead of causing an error.
+ * @returns {number|null} The number or `null` if the value is `null` or
+ *      there is no match.
+ * @throws {UnexpectedType} When the value isn't a number or `null`.
+ */
+export function getOptionalNumber(root, pointer, { nullable = true } = {}) {
+    return getValue(root, pointer, "Number", null, nullable);
+}
+
+/**
+ * Gets the boolean that a JSON Pointer refers to, treating `null` the same
+ * as a missing value.
+ * @param {Node} root The node to start from.
+ * @param {string|Array<string|int>} pointer The JSON Pointer or an array of
+ *      unescaped segments.
+ * @param {boolean} [options.nullable=true] Determines if a `null` value is
+ *      returned as `null` instead of causing an error.
+ * @returns {boolean|null} The boolean or `null` if the value is `null` or
+ *      there is no match.
+ * @throws {UnexpectedType} When the value isn't a boolean or `null`.
+ */
+export function getOptionalBoolean(root, pointer, { nullable = true } = {}) {
+    return getValue(root, pointer, "Boolean", null, nullable);
+}
+
+/**
+ * Gets the array that a JSON Pointer refers to as a JavaScript array,
+ * treating `null` the same as a missing value.
+ * @param {Node} root The node to start from.
+ * @param {string|Array<string|int>} pointer The JSON Pointer or an array of
+ *      unescaped segments.
+ * @param {boolean} [options.nullable=true] Determines if a `null` value is
+ *      returned as `null` instead of causing an error.
+ * @returns {Array|null} The array or `null` if the value is `null` or there
+ *      is no match.
+ * @throws {UnexpectedType} When the value isn't an array or `null`.
+ */
+export function getOptionalArray(root, pointer, { nullable = true } = {}) {
+    return getValue(root, pointer, "Array", null, nullable);
+}
+
+/**
+ * Gets the object that a JSON Pointer refers to as a JavaScript object,
+ * treating `null` the same as a missing value.
+ * @param {Node} root The node to start from.
+ * @param {string|Array<string|int>} pointer The JSON Pointer or an array of
+ *      unescaped segments.
+ * @param {boolean} [options.nullable=true] Determines if a `null` value is
+ *      returned as `null` instead of causing an error.
+ * @returns {Object|null} The object or `null` if the value is `null` or there
+ *      is no match.
+ * @throws {UnexpectedType} When the value isn't an object or `null`.
+ */
+export function getOptionalObject(root, pointer, { nullable = true } = {}) {
+    return getValue(root, pointer, "Object", null, nullable);
+}
@@ -3,9 +3,78 @@
  * @author Nicholas C. Zakas
  */
 
-export { tokenize } from "./tokens.js";
-export { parse } from "./parse.js";
+export { tokenize, tokenizeColumnar, tokenFilters, tokenTextEquals, matchesKeyword, getPunctuatorKind, getTokenSegments, getCommentText, verifyTokenCoverage } from "./tokens.js";
+export { parse, parseAuto, parseBytes, parsePrefix, parseSequence, parseTolerant, createParser, getParseInfo } from "./parse.js";
+export { decodeUtf8 } from "./utf8.js";
+export { parseEmbedded } from "./embedded.js";
+export { validateStringLiteral, canonicalizeStringLiteral, isEquivalentStringLiteral } from "./strings.js";
+export { formatNumber, parseNumberLiteral } from "./numbers.js";
 export { types } from "./types.js";
-export { traverse, iterator } from "./traversal.js";
+export { traverse, iterator, replace } from "./traversal.js";
 export { evaluate } from "./evaluate.js";
-export { print } from "./print.js";
+export { print, preview, getRawText, sliceText } from "./print.js";
+export { toDot } from "./dot.js";
+export { fromValue } from "./from-value.js";
+export { detectMode, inferStyle } from "./detect.js";
+export {
+    applyEdits,
+    normalizeEscapes,
+    normalizeQuotes,
+    renameKey,
+    setValue,
+    insertMember,
+    insertElement,
+    remove
+} from "./transforms.js";
+export { toSarif } from "./sarif.js";
+export { applySuppressions } from "./suppressions.js";
+export { sortDiagnostics, groupDiagnosticsByLine, getMaxSeverity } from "./diagnostics.js";
+export { createMalformedCases } from "./mutations.js";
+export {
+    createLineIndex,
+    translateLocation,
+    rebaseLocations,
+    createSyntheticLocation,
+    isSyntheticLocation
+} from "./locations.js";
+export { trackProvenance, getProvenance, copyProvenance } from "./provenance.js";
+export { createValidator } from "./validator.js";
+export { ErrorWithLocation, formatErrorMessage } from "./errors.js";
+export { ObjectView } from "./object-view.js";
+export {
+    parsePointer,
+    formatPointer,
+    resolvePointer,
+    resolvePointers,
+    createPointerIndex,
+    iterateWithPointers,
+    iterateStrings,
+    resolveReferences
+} from "./pointer.js";
+export {
+    getString,
+    getNumber,
+    getBoolean,
+    getArray,
+    getObject,
+    getOptionalString,
+    getOptionalNumber,
+    getOptionalBoolean,
+    getOptionalArray,
+    getOptionalObject
+} from "./getters.js";
+export { isEquivalent, compareNodes } from "./compare.js";
+export {
+    findDuplicateElements,
+    checkAllowedKeys,
+    findHexCaseMismatches,
+    findWhitespaceProblems,
+    findWindowsPaths
+} from "./analysis.js";
+export {
+    getDocumentSymbols,
+    getFoldingRanges,
+    getSelectionRange,
+    getHoverInfo,
+    getMatchingBracket
+} from "./editor.js";
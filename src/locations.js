@@ -0,0 +1,169 @@
+/**
+ * @fileoverview Utilities for moving locations between documents.
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+import { traverse } from "./traversal.js";
+
+//-----------------------------------------------------------------------------
+// Exports
+//-----------------------------------------------------------------------------
+
+/**
+ * Creates a location for a node that doesn't come from source text, such as
+ * one created by `fromValue()`. Every `line`, `column`, and `offset` is 0.
+ * Lines and columns in source text always start at 1, so a synthetic
+ * location can never be mistaken for a real one.
+ * @returns {Object} A location object with zeroed `start` and `end`.
+ */
+export function createSyntheticLocation() {
+    return {
+        start: { line: 0, column: 0, offset: 0 },
+        end: { line: 0, column: 0, offset: 0 }
+    };
+}
+
+/**
+ * Determines if a location is synthetic, meaning the node or token it
+ * belongs to doesn't come from source text. A missing location is also
+ * treated as synthetic.
+ * @param {Object} [loc] The location to check, with `start` and `end`.
+ * @returns {boolean} True if the location is synthetic or missing.
+ */
+export function isSyntheticLocation(loc) {
+    return !loc || loc.start.line === 0;
+}
+
+/**
+ * Creates an index of the offsets where each line of a text starts, using
+ * the same line break rules as the tokenizer, so the line and column of
+ * any offset can be found without scanning the text again. This allows
+ * lines and columns to be calculated only when they're needed, such as for
+ * nodes parsed with the `offsetsOnly` option.
+ * @param {string} text The text to index.
+ * @returns {Object} An object with a `getLocation(offset)` method that
+ *      returns a location with `line`, `column`, and `offset` properties
+ *      and a `getOffset(line, column)` method that returns the offset of a
+ *      line and column.
+ */
+export function createLineIndex(text) {
+
+    const lineStarts = [0];
+
+    for (let i = 0; i < text.length; i++) {
+        const c = text.charAt(i);
+
+        if (c === "\r" && text.charAt(i + 1) === "\n") {
+            lineStarts.push(i + 2);
+            i++;
+        } else if (c === "\r" || c === "\n") {
+            lineStarts.push(i + 1);
+        }
+    }
+
+    return {
+        getLocation(offset) {
+            let low = 0;
+            let high = lineStarts.length - 1;
+
+            // find the last line that starts at or before the offset
+            while (low < high) {
+                const middle = Math.ceil((low + high) / 2);
+
+                if (lineStarts[middle] > offset) {
+                    high = middle - 1;
+                } else {
+                    low = middle;
+                }
+            }
+
+            return {
+                line: low + 1,
+                column: offset - lineStarts[low] + 1,
+                offset
+            };
+        },
+
+        getOffset(line, column) {
+            return lineStarts[line - 1] + column - 1;
+        }
+    };
+}
+
+/**
+ * Translates a location in a fragment of text to the matching location in a
+ * larger text that contains the fragment. Locations on the first line of
+ * the fragment are shifted right by the column where the fragment starts;
+ * locations on later lines keep their columns.
+ * @param {Object} location The location in the fragment, with `line`,
+ *      `column`, and `offset` properties.
+ * @param {Object} start The location in the larger text where the fragment
+ *      starts, with `line`, `column`, and `offset` properties.
+ * @returns {Object} The location in the larger text.
+ */
+export function translateLocation(location, start) {
+    return {
+        line: location.line + start.line - 1,
+        column: location.line === 1 ? location.column + start.column - 1 : location.column,
+        offset: location.offset + start.offset
+    };
+}
+
+/**
+ * Updates the locations in an AST parsed from a fragment of text so they
+ * refer to a larger text that contains the fragment, such as when a file is
+ * generated from snippets. The AST is changed in place, including ranges,
+ * and when `root` is a `Document` node, its tokens and comments. Nodes with
+ * synthetic locations are left alone.
+ * @param {Node} root The root of the AST to update.
+ * @param {Object} start The location in the larger text where the fragment
+ *      starts, with `line`, `column`, and `offset` properties.
+ * @returns {Node} The same `root` node.
+ */
+export function rebaseLocations(root, start) {
+
+    const updated = new Set();
+
+    function update(item) {
+
+        // comments can be shared with tokens, so only update them once
+        if (updated.has(item)) {
+            return;
+        }
+
+        updated.add(item);
+
+        // synthetic locations don't refer to either text
+        if (isSyntheticLocation(item.loc)) {
+            return;
+        }
+
+        item.loc = {
+            start: translateLocation(item.loc.start, start),
+            end: translateLocation(item.loc.end, start)
+        };
+
+        if (Array.isArray(item.range)) {
+            item.range = [item.loc.start.offset, item.loc.end.offset];
+        } else if (item.range) {
+
+            // the document range was created as an object, so rebuild it the same way
+            item.range = { range: [item.loc.start.offset, item.loc.end.offset] };
+        }
+    }
+
+    traverse(root, {
+        enter: update
+    });
+
+    if (root.type === "Document") {
+        (root.tokens || []).forEach(update);
+        (root.comments || []).forEach(update);
+    }
+
+    return root;
+}
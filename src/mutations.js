@@ -0,0 +1,121 @@
+/**
+ * @fileoverview Malformed document generator for testing parsers.
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+import { tokenize, isComment } from "./tokens.js";
+
+//-----------------------------------------------------------------------------
+// Helpers
+//-----------------------------------------------------------------------------
+
+const swappedBrackets = new Map([
+    ["]", "}"],
+    ["}", "]"]
+]);
+
+/**
+ * Determines if removing the text between two offsets would join the
+ * characters on either side into a single token, such as `1,2` becoming
+ * `12`.
+ * @param {string} text The text to check.
+ * @param {int} start The offset of the first removed character.
+ * @param {int} end The offset after the last removed character.
+ * @returns {boolean} True if the characters would be joined.
+ */
+function wouldJoin(text, start, end) {
+    return /[\w.+-]/.test(text[start - 1] || "") && /[\w.+-]/.test(text[end] || "");
+}
+
+/**
+ * Creates a case that removes a token.
+ * @param {string} kind The kind of mutation.
+ * @param {string} text The original text.
+ * @param {Token} token The token to remove.
+ * @param {Token} next The token after the removed token.
+ * @returns {Object} The case.
+ */
+function removeToken(kind, text, token, next) {
+    const { offset: start } = token.loc.start;
+    const { offset: end } = token.loc.end;
+    const replacement = wouldJoin(text, start, end) ? " " : "";
+    const shift = end - start - replacement.length;
+
+    return {
+        kind,
+        text: text.slice(0, start) + replacement + text.slice(end),
+        code: "UnexpectedToken",
+        index: next.loc.start.offset - shift
+    };
+}
+
+//-----------------------------------------------------------------------------
+// Exports
+//-----------------------------------------------------------------------------
+
+/**
+ * Creates malformed versions of a valid document by making one change at a
+ * time, each paired with the error that parsing it should produce. This is
+ * useful for checking that a parser, or a tool built on one, reports the
+ * right error at the right location. The kinds of changes are:
+ *
+ * - `"delete-comma"` removes a comma between members or elements, which
+ *   causes an `UnexpectedToken` error at the following value.
+ * - `"delete-colon"` removes the colon after a member name, which causes
+ *   an `UnexpectedToken` error at the member's value.
+ * - `"swap-bracket"` replaces a closing `]` with `}` or the reverse, which
+ *   causes a `MismatchedBracket` error at the bracket.
+ * - `"truncate-string"` removes everything from the closing quote of a
+ *   string onward, which causes an `UnexpectedEOF` error at the end of the
+ *   text.
+ * @param {string} text The valid JSON text to change.
+ * @param {boolean} [options.comments=false] Determines if the text contains
+ *      comments.
+ * @returns {Array<Object>} The cases in document order, grouped by kind,
+ *      each with `kind`, `text`, `code`, and `index` properties, where `code`
+ *      is the expected error's `code` and `index` is its expected offset.
+ * @throws {Error} When the text can't be tokenized.
+ */
+export function createMalformedCases(text, { comments = false } = {}) {
+
+    const tokens = tokenize(text, { comments }).filter(token => !isComment(token));
+    const cases = {
+        "delete-comma": [],
+        "delete-colon": [],
+        "swap-bracket": [],
+        "truncate-string": []
+    };
+
+    tokens.forEach((token, i) => {
+        const next = tokens[i + 1];
+        const { offset } = token.loc.start;
+
+        if (token.value === "," && next) {
+            cases["delete-comma"].push(removeToken("delete-comma", text, token, next));
+        } else if (token.value === ":" && next) {
+            cases["delete-colon"].push(removeToken("delete-colon", text, token, next));
+        } else if (token.type === "Punctuator" && swappedBrackets.has(token.value)) {
+            cases["swap-bracket"].push({
+                kind: "swap-bracket",
+                text: text.slice(0, offset) + swappedBrackets.get(token.value) + text.slice(offset + 1),
+                code: "MismatchedBracket",
+                index: offset
+            });
+        } else if (token.type === "String") {
+            const truncated = text.slice(0, token.loc.end.offset - 1);
+
+            cases["truncate-string"].push({
+                kind: "truncate-string",
+                text: truncated,
+                code: "UnexpectedEOF",
+                index: truncated.length
+            });
+        }
+    });
+
+    return [].concat(...Object.values(cases));
+}
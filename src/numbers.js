@@ -0,0 +1,136 @@
+/**
+ * @fileoverview Helpers for JSON number literals.
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+import { UnexpectedChar, UnexpectedEOF } from "./errors.js";
+import { isDigit, locateInLiteral } from "./syntax.js";
+
+//-----------------------------------------------------------------------------
+// Helpers
+//-----------------------------------------------------------------------------
+
+/**
+ * Writes a number without an exponent.
+ * @param {number} value The number to format.
+ * @returns {string} The formatted number.
+ */
+function formatWithoutExponent(value) {
+
+    // toExponential() without arguments uses the fewest digits that round-trip
+    const [mantissa, exponentText] = Math.abs(value).toExponential().split("e");
+    const digits = mantissa.replace(".", "");
+    const pointIndex = Number(exponentText) + 1;
+    const sign = value < 0 ? "-" : "";
+
+    if (pointIndex <= 0) {
+        return `${ sign }0.${ "0".repeat(-pointIndex) }${ digits }`;
+    }
+
+    if (pointIndex >= digits.length) {
+        return sign + digits + "0".repeat(pointIndex - digits.length);
+    }
+
+    return `${ sign }${ digits.slice(0, pointIndex) }.${ digits.slice(pointIndex) }`;
+}
+
+//-----------------------------------------------------------------------------
+// Exports
+//-----------------------------------------------------------------------------
+
+/**
+ * Converts a number into a JSON number literal using the fewest digits that
+ * still round-trip to the same number.
+ * @param {number} value The number to format.
+ * @param {string} [options.exponent="auto"] When to use an exponent:
+ *      `"auto"` to match `JSON.stringify()` and `print()`, `"never"` to
+ *      always write every digit, or `"always"` to always use scientific
+ *      notation.
+ * @returns {string} The JSON number literal.
+ * @throws {TypeError} When the number isn't finite.
+ */
+export function formatNumber(value, { exponent = "auto" } = {}) {
+
+    if (!Number.isFinite(value)) {
+        throw new TypeError(`Cannot represent ${ value } in JSON.`);
+    }
+
+    // JSON has no way to distinguish -0 from 0
+    if (value === 0) {
+        return exponent === "always" ? "0e+0" : "0";
+    }
+
+    switch (exponent) {
+    case "never":
+        return formatWithoutExponent(value);
+
+    case "always":
+        return value.toExponential();
+
+    default:
+        return String(value);
+    }
+}
+
+/**
+ * Validates a JSON number literal and returns the number it represents.
+ * Errors are located relative to the start of the literal.
+ * @param {string} text The number literal.
+ * @returns {number} The number.
+ * @throws {UnexpectedChar} When the literal contains an invalid character.
+ * @throws {UnexpectedEOF} When the literal ends too soon.
+ */
+export function parseNumberLiteral(text) {
+
+    let i = 0;
+
+    function expectDigits() {
+        if (i >= text.length) {
+            throw new UnexpectedEOF(locateInLiteral(i));
+        }
+
+        if (!isDigit(text.charAt(i))) {
+            throw new UnexpectedChar(text.charAt(i), locateInLiteral(i));
+        }
+
+        while (isDigit(text.charAt(i))) {
+            i++;
+        }
+    }
+
+    if (text.charAt(i) === "-") {
+        i++;
+    }
+
+    // leading zeros aren't allowed
+    if (text.charAt(i) === "0") {
+        i++;
+    } else {
+        expectDigits();
+    }
+
+    if (text.charAt(i) === ".") {
+        i++;
+        expectDigits();
+    }
+
+    if (text.charAt(i) === "e" || text.charAt(i) === "E") {
+        i++;
+
+        if (text.charAt(i) === "+" || text.charAt(i) === "-") {
+            i++;
+        }
+
+        expectDigits();
+    }
+
+    if (i < text.length) {
+        throw new UnexpectedChar(text.charAt(i), locateInLiteral(i));
+    }
+
+    return Number(text);
+}
@@ -0,0 +1,198 @@
+/**
+ * @fileoverview A map-like view of an Object node.
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Exports
+//-----------------------------------------------------------------------------
+
+/**
+ * Provides map-like access to the members of an `Object` node. The members
+ * are indexed once when the view is created, so repeated lookups don't need
+ * to scan the members. Keys are returned in the order they first appear in
+ * the object.
+ */
+export class ObjectView {
+
+    /**
+     * Creates a new instance.
+     * @param {Node} node The `Object` node to create a view of.
+     * @param {string} [options.duplicates="last"] Which member to use when
+     *      a key appears more than once: `"first"` or `"last"`. The default
+     *      matches the behavior of `JSON.parse()` and `evaluate()`.
+     * @throws {TypeError} When `node` is not an `Object` node.
+     */
+    constructor(node, { duplicates = "last" } = {}) {
+
+        if (!node || node.type !== "Object") {
+            throw new TypeError("An ObjectView can only be created from an Object node.");
+        }
+
+        /**
+         * The `Object` node this view is for.
+         * @type Node
+         * @property node
+         */
+        this.node = node;
+
+        /**
+         * The members of the object keyed by their names.
+         * @type Map<string,Node>
+         * @private
+         */
+        this._members = new Map();
+
+        for (const member of node.members) {
+            if (duplicates !== "first" || !this._members.has(member.name.value)) {
+                this._members.set(member.name.value, member);
+            }
+        }
+    }
+
+    /**
+     * The number of unique keys in the object.
+     * @type int
+     */
+    get size() {
+        return this._members.size;
+    }
+
+    /**
+     * Determines if the object has a member with the given name.
+     * @param {string} key The member name to look for.
+     * @returns {boolean} True if the member exists, false if not.
+     */
+    has(key) {
+        return this._members.has(key);
+    }
+
+    /**
+     * Gets the value node of the member with the given name.
+     * @param {string} key The member name to look for.
+     * @returns {Node|undefined} The value node or `undefined` if there is no
+     *      member with that name.
+     */
+    get(key) {
+        const member = this._members.get(key);
+        return member ? member.value : undefined;
+    }
+
+    /**
+     * Gets the `Member` node with the given name.
+     * @param {string} key The member name to look for.
+     * @returns {Node|undefined} The `Member` node or `undefined` if there is
+     *      no member with that name.
+     */
+    getMember(key) {
+        return this._members.get(key);
+    }
+
+    /**
+     * Looks up a member, optionally allowing the name to differ in case or
+     * Unicode normalization. An exact match is always preferred, followed by
+     * a match after NFC normalization, followed by a case-insensitive match.
+     * @param {string} key The member name to look for.
+     * @param {boolean} [options.ignoreCase=false] Determines if a member
+     *      whose name differs only in case can match.
+     * @param {boolean} [options.normalize=false] Determines if names are
+     *      compared after Unicode NFC normalization.
+     * @returns {Object|undefined} An object with `member` (the `Member`
+     *      node), `value` (the value node), and `match` (`"exact"`,
+     *      `"normalized"`, or `"ignoreCase"`) properties, or `undefined`
+     *      if no member matches.
+     */
+    find(key, { ignoreCase = false, normalize = false } = {}) {
+
+        const createResult = (member, match) => ({
+            member,
+            value: member.value,
+            match
+        });
+
+        if (this._members.has(key)) {
+            return createResult(this._members.get(key), "exact");
+        }
+
+        const prepare = name => (normalize ? name.normalize("NFC") : name);
+        const normalizedKey = prepare(key);
+
+        if (normalize) {
+            for (const [name, member] of this._members) {
+                if (prepare(name) === normalizedKey) {
+                    return createResult(member, "normalized");
+                }
+            }
+        }
+
+        if (ignoreCase) {
+            const lowerKey = normalizedKey.toLowerCase();
+
+            for (const [name, member] of this._members) {
+                if (prepare(name).toLowerCase() === lowerKey) {
+                    return createResult(member, "ignoreCase");
+                }
+            }
+        }
+
+        return undefined;
+    }
+
+    /**
+     * Creates an iterator over the member names.
+     * @returns {Iterator<string>} An iterator over the member names.
+     */
+    keys() {
+        return this._members.keys();
+    }
+
+    /**
+     * Creates an iterator over the value nodes.
+     * @returns {Iterator<Node>} An iterator over the value nodes.
+     */
+    *values() {
+        for (const member of this._members.values()) {
+            yield member.value;
+        }
+    }
+
+    /**
+     * Creates an iterator over the member names and value nodes.
+     * @returns {Iterator<Array>} An iterator over `[name, value]` pairs.
+     */
+    *entries() {
+        for (const [key, member] of this._members) {
+            yield [key, member.value];
+        }
+    }
+
+    /**
+     * Creates an iterator over the members whose names match a predicate,
+     * such as the `x-` extension fields in an OpenAPI document. Every member
+     * of the object is checked in order, including each member with a
+     * duplicated name, regardless of the `duplicates` option.
+     * @param {Function} predicate A function that is called with each
+     *      member name and `Member` node and returns `true` to include the
+     *      member.
+     * @returns {Iterator<Array>} An iterator over `[name, member]` pairs,
+     *      where `member` is the `Member` node, so its location is
+     *      available.
+     */
+    *membersMatching(predicate) {
+        for (const member of this.node.members) {
+            const key = member.name.value;
+
+            if (predicate(key, member)) {
+                yield [key, member];
+            }
+        }
+    }
+
+    /**
+     * Creates an iterator over the member names and value nodes.
+     * @returns {Iterator<Array>} An iterator over `[name, value]` pairs.
+     */
+    [Symbol.iterator]() {
+        return this.entries();
+    }
+}
@@ -8,10 +8,13 @@
 // Imports
 //-----------------------------------------------------------------------------
 
-import { tokenize } from "./tokens.js";
+import { tokenizePartial, isComment } from "./tokens.js";
 import { types as t } from "./types.js";
 import { escapeToChar } from "./syntax.js";
-import { UnexpectedToken, ErrorWithLocation } from "./errors.js";
+import { UnexpectedToken, UnexpectedEOF, ErrorWithLocation, EmptyDocument, ParseCancelled, MismatchedBracket, UnexpectedColon, UnexpectedRootType, NonIntegerNumber, InvalidString, ObjectKeyMustBeString, LimitExceeded, setErrorMessage } from "./errors.js";
+import { getSuggestedFix } from "./fixes.js";
+import { decodeUtf8, getUtf8Length } from "./utf8.js";
+import { createLineIndex } from "./locations.js";
 
 //-----------------------------------------------------------------------------
 // Helpers
@@ -20,9 +23,40 @@ import { UnexpectedToken, ErrorWithLocation } from "./errors.js";
 const DEFAULT_OPTIONS = {
     tokens: false,
     comments: false,
-    ranges: false
+    ranges: false,
+    allowEmpty: false,
+    eof: false,
+    integersOnly: false,
+    rootTypes: undefined,
+    bom: false,
+    shebang: false,
+    strictWhitespace: false,
+    tolerant: false,
+    offsetsOnly: false,
+    validateString: undefined,
+    maxExponentDigits: Infinity,
+    maxInputBytes: Infinity,
+    maxLineLength: Infinity,
+    maxStringLength: Infinity,
+    graphemeColumns: false,
+    onProgress: undefined,
+    shouldCancel: undefined,
+    callbackInterval: 1000,
+    sourceName: undefined,
+    trailingContent: "error"
+};
+
+const DEFAULT_THRESHOLDS = {
+    tokens: 1000000,
+    ranges: 1000000
 };
 
+/**
+ * The mode and options used to parse each document. Documents are the keys
+ * so the information doesn't appear when a document is serialized.
+ */
+const parseInfo = new WeakMap();
+
 /**
  * Converts a JSON-encoded string into a JavaScript string, interpreting each
  * escape sequence.
@@ -86,6 +120,18 @@ function getStringValue(token) {
     return result;
 }
 
+/**
+ * Determines if a string token contains a `\/` escape, which decodes to the
+ * same value as a plain `/` and so can't be detected from the value alone.
+ * @param {Token} token The string token to check.
+ * @returns {boolean} `true` if the token contains a `\/` escape.
+ */
+function hasEscapedSolidus(token) {
+
+    // an even number of backslashes before the escape are escapes themselves
+    return /(?<!\\)(?:\\\\)*\\\//.test(token.value);
+}
+
 /**
  * Gets the JavaScript value represented by a JSON token.
  * @param {Token} token The JSON token to get a value for.
@@ -107,61 +153,486 @@ function getLiteralValue(token) {
     }
 }
 
+/**
+ * Adds a sentence about where in the document an error occurred to the
+ * error's message, before the location at the end, and attaches the path
+ * that was being parsed as the `context` property.
+ * @param {ErrorWithLocation} error The error to update.
+ * @param {Array<Object>} contextStack The members and elements being parsed
+ *      when the error occurred, from outermost to innermost.
+ * @returns {void}
+ */
+export function addContext(error, contextStack) {
+
+    error.context = contextStack.map(frame => ({ ...frame }));
+
+    if (!contextStack.length) {
+        return;
+    }
+
+    const frame = contextStack[contextStack.length - 1];
+    const description = frame.type === "Member"
+        ? `value of ${ JSON.stringify(frame.name) }`
+        : `element ${ frame.index } of array`;
+    const location = error.sourceName
+        ? ` (${ error.sourceName }:${ error.line }:${ error.column })`
+        : ` (${ error.line }:${ error.column })`;
+    const suffix = error.message.endsWith(location) ? location : "";
+    const text = error.message.slice(0, error.message.length - suffix.length);
+
+    // a sentence of its own so messages with more than one sentence still read correctly
+    const sentenceEnd = text.endsWith(".") ? "" : ".";
+
+    setErrorMessage(error, `${ text }${ sentenceEnd } Occurred while parsing ${ description } at line ${ frame.loc.start.line }.${ suffix }`);
+}
+
+/**
+ * Counts the characters in a string as a user perceives them. When
+ * `Intl.Segmenter` isn't available, code points are counted instead.
+ * @param {string} text The text to count.
+ * @returns {int} The number of characters.
+ */
+function countGraphemes(text) {
+    if (typeof Intl === "object" && typeof Intl.Segmenter === "function") {
+        return [...new Intl.Segmenter().segment(text)].length;
+    }
+
+    return Array.from(text).length;
+}
+
+/**
+ * Changes an error's column to count extended grapheme clusters instead of
+ * UTF-16 code units. The error's message must still end with its location,
+ * so this must be called before `addSourceName()`.
+ * @param {ErrorWithLocation} error The error to update.
+ * @param {string} text The text being parsed.
+ * @returns {void}
+ */
+function useGraphemeColumn(error, text) {
+
+    const location = `(${ error.line }:${ error.column })`;
+    const lineStart = error.index - createLineIndex(text).getLocation(error.index).column + 1;
+
+    error.column = countGraphemes(text.slice(lineStart, error.index)) + 1;
+
+    if (error.message.endsWith(location)) {
+        setErrorMessage(error, error.message.slice(0, -location.length) +
+            `(${ error.line }:${ error.column })`);
+    }
+}
+
+/**
+ * Adds the name of the source to an error's message and attaches it as the
+ * `sourceName` property. The error's message must still end with its
+ * location.
+ * @param {ErrorWithLocation} error The error to update.
+ * @param {string} sourceName The name of the source.
+ * @returns {void}
+ */
+function addSourceName(error, sourceName) {
+
+    const location = `(${ error.line }:${ error.column })`;
+
+    error.sourceName = sourceName;
+
+    if (error.message.endsWith(location)) {
+        setErrorMessage(error, error.message.slice(0, -location.length) +
+            `(${ sourceName }:${ error.line }:${ error.column })`);
+    }
+}
+
+/**
+ * Creates a copy of an error so details can be added to the copy without
+ * changing the original, which may be thrown again later.
+ * @param {Error} error The error to copy.
+ * @returns {Error} The copy.
+ */
+function copyError(error) {
+    return Object.assign(Object.create(Object.getPrototypeOf(error)), error, {
+        message: error.message,
+        stack: error.stack
+    });
+}
+
+/**
+ * Throws an error if the input is larger than the `maxInputBytes` option.
+ * This is checked before tokenizing so oversized input is rejected without
+ * doing any other work.
+ * @param {int} byteLength The number of UTF-8 bytes in the input.
+ * @param {Object} options The parser options.
+ * @returns {void}
+ * @throws {LimitExceeded} When the input is too large.
+ */
+function checkInputSize(byteLength, options) {
+
+    if (byteLength > options.maxInputBytes) {
+        const ex = new LimitExceeded("maxInputBytes", options.maxInputBytes, { line: 1, column: 1, offset: 0 });
+
+        if (options.sourceName) {
+            addSourceName(ex, options.sourceName);
+        }
+
+        throw ex;
+    }
+}
+
+/**
+ * Throws an error if text is larger than the `maxInputBytes` option when
+ * encoded as UTF-8. The text is only measured when it could be too large.
+ * @param {string} text The text to check.
+ * @param {Object} options The parser options.
+ * @returns {void}
+ * @throws {LimitExceeded} When the text is too large.
+ */
+function checkTextSize(text, options) {
+
+    // each UTF-16 code unit is at most three UTF-8 bytes
+    if (options.maxInputBytes !== undefined && text.length * 3 > options.maxInputBytes) {
+        checkInputSize(getUtf8Length(text), options);
+    }
+}
+
 //-----------------------------------------------------------------------------
 // Main Function
 //-----------------------------------------------------------------------------
 
 /**
- * 
+ * Parses a document, optionally stopping after the first value.
  * @param {string} text The text to parse.
- * @param {boolean} [options.tokens=false] Determines if tokens are returned in
- *      the AST. 
- * @param {boolean} [options.comments=false] Determines if comments are allowed
- *      in the JSON.
- * @param {boolean} [options.ranges=false] Determines if ranges will be returned
- *      in addition to `loc` properties.
- * @returns {Object} The AST representing the parsed JSON.
+ * @param {Object} options The options passed to `parse()`.
+ * @param {boolean} prefix Determines if only the first value is parsed,
+ *      ignoring any text after it.
+ * @param {Object} [record] A record in a JSON text sequence to parse instead
+ *      of the whole text, with `tokens` containing its tokens, `error`
+ *      containing the error that stopped tokenizing inside of it or `null`,
+ *      and `start` containing the location where it starts. To parse just
+ *      one value from tokens that were already read, the record also has
+ *      a `tokenIndex` property containing the index of the value's first
+ *      token.
+ * @returns {Object} The AST representing the parsed JSON or, when the record
+ *      has a `tokenIndex`, an object with a `node` property containing the
+ *      value and a `tokenIndex` property containing the index of the token
+ *      after it.
  * @throws {Error} When there is a parsing error. 
  */
-export function parse(text, options) {
+function parseDocument(text, options, prefix, record) {
 
     options = Object.freeze({
         ...DEFAULT_OPTIONS,
         ...options
     });
 
-    const tokens = tokenize(text, {
+    // the size of a sequence is checked once before it's tokenized
+    if (!record) {
+        checkTextSize(text, options);
+    }
+
+    /*
+     * Tracks the members and elements currently being parsed so errors can
+     * report where in the document they occurred. Frames are intentionally
+     * not removed when an error is thrown so the stack is still intact
+     * when the error is caught.
+     */
+    const contextStack = [];
+
+    // only created when an error needs a line and column
+    let lineIndex = null;
+
+    /**
+     * Adds the line and column to a location for an error when the
+     * `offsetsOnly` option means it only has an offset.
+     * @param {Object} loc The location.
+     * @returns {Object} The location with `line`, `column`, and `offset`.
+     */
+    function locateError(loc) {
+
+        if (!options.offsetsOnly) {
+            return loc;
+        }
+
+        if (!lineIndex) {
+            lineIndex = createLineIndex(text);
+        }
+
+        return lineIndex.getLocation(loc.offset);
+    }
+
+    /**
+     * Creates a copy of a token, node, or context frame whose location has
+     * lines and columns so it can be used to create an error.
+     * @param {Object} item The token, node, or frame.
+     * @returns {Object} The item or a copy of it.
+     */
+    function withErrorLocation(item) {
+        return options.offsetsOnly ? {
+            ...item,
+            loc: {
+                start: locateError(item.loc.start),
+                end: locateError(item.loc.end)
+            }
+        } : item;
+    }
+
+    /**
+     * Adds the source name and parsing context to an error.
+     * @param {Error} ex The error to update.
+     * @param {Array<Object>} [frames] The members and elements being
+     *      parsed when the error occurred. Defaults to the ones being parsed
+     *      now.
+     * @returns {Error} The same error.
+     */
+    function addErrorDetails(ex, frames = contextStack) {
+        if (ex instanceof ErrorWithLocation) {
+            const suggestedFix = getSuggestedFix(ex, text, tokens);
+
+            if (suggestedFix) {
+                ex.suggestedFix = suggestedFix;
+            }
+
+            if (options.graphemeColumns) {
+                useGraphemeColumn(ex, text);
+            }
+
+            if (options.sourceName) {
+                addSourceName(ex, options.sourceName);
+            }
+
+            addContext(ex, options.offsetsOnly ? frames.map(withErrorLocation) : frames);
+        }
+
+        return ex;
+    }
+
+    let tokens;
+    let tokenizerError = null;
+    let textEnd = null;
+    const recoveredErrors = [];
+
+    const tokenizerOptions = {
         comments: !!options.comments,
-        ranges: !!options.ranges
-    });
-    let tokenIndex = 0;
+        ranges: !!options.ranges,
+        bom: !!options.bom,
+        shebang: !!options.shebang,
+        strictWhitespace: !!options.strictWhitespace,
+        offsetsOnly: !!options.offsetsOnly,
+        eof: !!options.eof && !prefix && !record,
+        maxLineLength: options.maxLineLength,
+        maxExponentDigits: options.maxExponentDigits
+    };
+
+    try {
+        if (record) {
+            ({ tokens, error: tokenizerError, end: textEnd = null } = record);
+        } else {
+
+            /*
+             * A tokenizer error isn't thrown until the parser has read the
+             * tokens before it so the error knows which member or element
+             * it was found in.
+             */
+            ({ tokens, error: tokenizerError, end: textEnd } = tokenizePartial(text, tokenizerOptions));
+        }
+    } catch (ex) {
+        throw addErrorDetails(ex);
+    }
+
+    // the parser itself never needs to see the EOF token
+    const eofToken = tokenizerOptions.eof && !tokenizerError ? tokens.pop() : null;
+
+    const valueOnly = !!record && record.tokenIndex !== undefined;
+
+    // a byte order mark and a shebang can only start the text and are never part of the value
+    let tokenIndex = valueOnly ? record.tokenIndex : 0;
+
+    while (tokenIndex < tokens.length && (tokens[tokenIndex].type === "BOM" || tokens[tokenIndex].type === "Shebang")) {
+        tokenIndex++;
+    }
+
+    // what was being parsed when the tokens ran out, for a tokenizer error found there
+    let contextAtEnd = null;
 
     function nextNoComments() {
-        return tokens[tokenIndex++];
+        const nextToken = tokens[tokenIndex++];
+
+        if (!nextToken && !contextAtEnd) {
+            contextAtEnd = contextStack.slice();
+        }
+
+        return nextToken;
     }
     
     function nextSkipComments() {
-        const nextToken = tokens[tokenIndex++];
-        if (nextToken && nextToken.type.endsWith("Comment")) {
-            return nextSkipComments();
+        let nextToken = tokens[tokenIndex++];
+
+        // a loop so any number of comments in a row can't overflow the call stack
+        while (nextToken && isComment(nextToken)) {
+            nextToken = tokens[tokenIndex++];
+        }
+
+        if (!nextToken && !contextAtEnd) {
+            contextAtEnd = contextStack.slice();
         }
 
         return nextToken;
+    }
+
+    let docStart = { offset: 0 };
 
+    if (record) {
+        docStart = record.start;
+    } else if (!options.offsetsOnly) {
+        docStart = { line: 1, column: 1, offset: 0 };
     }
 
     // determine correct way to evaluate tokens based on presence of comments
-    const next = options.comments ? nextSkipComments : nextNoComments;
+    const readToken = options.comments ? nextSkipComments : nextNoComments;
+    let tokensRead = 0;
+
+    function nextWithCallbacks() {
+        const nextToken = readToken();
+
+        if (nextToken && ++tokensRead % options.callbackInterval === 0) {
+            if (options.onProgress) {
+                options.onProgress({
+                    offset: nextToken.loc.start.offset,
+                    length: text.length
+                });
+            }
+
+            if (options.shouldCancel && options.shouldCancel()) {
+                throw new ParseCancelled(locateError(nextToken.loc.start));
+            }
+        }
+
+        return nextToken;
+    }
+
+    const next = options.onProgress || options.shouldCancel
+        ? nextWithCallbacks
+        : readToken;
+
+    /**
+     * Creates an error for an unexpected token or, if there is no token,
+     * for the unexpected end of input.
+     * @param {Token} [token] The unexpected token.
+     * @returns {UnexpectedToken|UnexpectedEOF} The error.
+     */
+    function createUnexpectedError(token) {
+        if (token) {
+            return new UnexpectedToken(withErrorLocation(token));
+        }
+
+        const lastToken = tokens[tokens.length - 1];
+        return new UnexpectedEOF(locateError(lastToken ? { ...lastToken.loc.end } : { ...docStart }));
+    }
+
+    /**
+     * Throws an error for an unexpected token or, if there is no token,
+     * for the unexpected end of input.
+     * @param {Token} [token] The unexpected token.
+     * @returns {void}
+     * @throws {UnexpectedToken} When a token is passed.
+     * @throws {UnexpectedEOF} When no token is passed.
+     */
+    function unexpected(token) {
+        throw createUnexpectedError(token);
+    }
+
+    /**
+     * Records the error that would have been thrown for an unexpected token
+     * or the unexpected end of input when parsing is tolerant and a missing
+     * punctuator is assumed instead.
+     * @param {Token} [token] The unexpected token.
+     * @returns {void}
+     */
+    function recover(token) {
+        recoveredErrors.push(addErrorDetails(createUnexpectedError(token)));
+    }
+
+    /**
+     * Inserts a punctuator that is missing from the text, such as an
+     * implied comma, into the tokens when parsing is tolerant. The token
+     * has an empty location right after the last token before `index`
+     * that isn't a comment.
+     * @param {string} value The punctuator.
+     * @param {int} index The index in the tokens to insert the token at.
+     * @returns {int} The index the token was inserted at, which is before
+     *      `index` when comments come before it.
+     */
+    function insertSyntheticToken(value, index) {
+
+        while (isComment(tokens[index - 1])) {
+            index--;
+        }
+
+        const { end } = tokens[index - 1].loc;
+        const range = options.ranges ? {
+            range: [end.offset, end.offset]
+        } : undefined;
+
+        const token = {
+            type: "Punctuator",
+            value,
+            loc: {
+                start: { ...end },
+                end: { ...end }
+            },
+            ...range,
+            synthetic: true
+        };
+
+        tokens.splice(index, 0, token);
+        tokenIndex++;
+
+        return index;
+    }
+
+    /**
+     * Determines if a missing comma should be assumed before a token.
+     * @param {Token} [token] The token after a member or element.
+     * @param {boolean} isArray Determines if the token is in an array.
+     * @returns {boolean} `true` if parsing is tolerant and the token starts
+     *      the next member or element.
+     */
+    function isMissingComma(token, isArray) {
+        return options.tolerant && !!token && (isArray
+            ? token.type !== "Punctuator" || token.value === "{" || token.value === "["
+            : token.type === "String");
+    }
 
     function assertTokenValue(token, value) {
         if (!token || token.value !== value) {
-            throw new UnexpectedToken(token);
+            unexpected(token);
         }
     }
 
+    /**
+     * Asserts that a token closes the array or object that was opened by
+     * another token.
+     * @param {Token} token The token to check.
+     * @param {Token} openToken The token that opened the array or object.
+     * @returns {void}
+     * @throws {MismatchedBracket} When the token closes the wrong kind of
+     *      container.
+     * @throws {UnexpectedColon} When the token is a colon inside an array.
+     */
+    function assertClosingBracket(token, openToken) {
+        const isArray = openToken.value === "[";
+
+        if (token && token.value === (isArray ? "}" : "]")) {
+            throw new MismatchedBracket(withErrorLocation(token), withErrorLocation(openToken));
+        }
+
+        if (token && isArray && token.value === ":") {
+            throw new UnexpectedColon(withErrorLocation(token), withErrorLocation(openToken));
+        }
+
+        assertTokenValue(token, isArray ? "]" : "}");
+    }
+
     function assertTokenType(token, type) {
         if (!token || token.type !== type) {
-            throw new UnexpectedToken(token);
+            unexpected(token);
         }
     }
 
@@ -174,9 +645,32 @@ export function parse(text, options) {
     function createLiteralNode(token) {
         const range = createRange(token.loc.start, token.loc.end);
 
-        return {
+        /*
+         * An escape sequence decodes to at least one character for every six
+         * in the text, so a string that's too long even if every character
+         * were escaped fails before it's decoded.
+         */
+        if (token.type === "String" && (token.value.length - 2) / 6 > options.maxStringLength) {
+            throw new LimitExceeded("maxStringLength", options.maxStringLength, locateError(token.loc.start));
+        }
+
+        const value = getLiteralValue(token);
+
+        if (token.type === "String" && value.length > options.maxStringLength) {
+            throw new LimitExceeded("maxStringLength", options.maxStringLength, locateError(token.loc.start));
+        }
+
+        if (token.type === "String" && options.validateString) {
+            const message = options.validateString(value, token.loc);
+
+            if (message) {
+                throw new InvalidString(message, locateError(token.loc.start));
+            }
+        }
+
+        const node = {
             type: token.type,
-            value: getLiteralValue(token),
+            value,
             loc: {
                 start: {
                     ...token.loc.start
@@ -187,19 +681,99 @@ export function parse(text, options) {
             },
             ...range
         };
+
+        // only added when true so most string nodes don't change shape
+        if (token.type === "String" && hasEscapedSolidus(token)) {
+            node.escapedSolidus = true;
+        }
+
+        return node;
     }
 
+    /**
+     * Parses a value that isn't an array or object.
+     * @param {Token} token The token for the value.
+     * @returns {Node} The node for the value.
+     * @throws {UnexpectedToken} When the token isn't a value.
+     */
+    function parseLiteral(token) {
+
+        switch (token.type) {
+        case "Number":
+            if (options.integersOnly && /[.eE]/.test(token.value)) {
+                throw new NonIntegerNumber(withErrorLocation(token));
+            }
+            return createLiteralNode(token);
+
+        case "String":
+        case "Boolean":
+        case "Null":
+            return createLiteralNode(token);
+
+        default:
+            throw new UnexpectedToken(withErrorLocation(token));
+        }
+    }
+
+    /**
+     * Starts the next member or element of an array or object. For an
+     * object, this reads the member name and colon.
+     * @param {Object} container The array or object being parsed.
+     * @param {Token} token The first token of the member or element.
+     * @returns {Token} The first token of the value.
+     */
+    function startChild(container, token) {
+
+        if (container.isArray) {
+            contextStack.push({
+                type: "Element",
+                index: container.children.length,
+                loc: token.loc
+            });
+
+            return token;
+        }
+
+        // give a clearer error when a value other than a string is the name
+        if (token.type !== "String" &&
+            (token.type !== "Punctuator" || token.value === "{" || token.value === "[")) {
+            throw new ObjectKeyMustBeString(withErrorLocation(token));
+        }
 
-    function parseProperty(token) {
         assertTokenType(token, "String");
         const name = createLiteralNode(token);
 
-        token = next();
-        assertTokenValue(token, ":");
-        const value = parseValue();
+        assertTokenValue(next(), ":");
+
+        container.name = name;
+        contextStack.push({
+            type: "Member",
+            name: name.value,
+            loc: name.loc
+        });
+
+        return next();
+    }
+
+    /**
+     * Adds a value to the array or object being parsed.
+     * @param {Object} container The array or object being parsed.
+     * @param {Node} value The value to add.
+     * @returns {void}
+     */
+    function addChild(container, value) {
+
+        contextStack.pop();
+
+        if (container.isArray) {
+            container.children.push(value);
+            return;
+        }
+
+        const { name } = container;
         const range = createRange(name.loc.start, value.loc.end);
 
-        return t.member(name, value, {
+        container.children.push(t.member(name, value, {
             loc: {
                 start: {
                     ...name.loc.start
@@ -209,149 +783,697 @@ export function parse(text, options) {
                 }
             },
             ...range
-        });
+        }));
     }
 
-    function parseObject(firstToken) {
-
-        // The first token must be a { or else it's an error
-        assertTokenValue(firstToken, "{");
+    /**
+     * Finishes parsing an array or object.
+     * @param {Object} container The array or object being parsed.
+     * @param {Token} [token] The token that should close it.
+     * @returns {Node} The `Array` or `Object` node.
+     */
+    function closeContainer(container, token) {
 
-        const members = [];
-        let token = next();
+        let closeTokenIndex = tokenIndex - 1;
 
-        if (token && token.value !== "}") {
-            do {
-    
-                // add the value into the array
-                members.push(parseProperty(token));
-    
-                token = next();
-    
-                if (token.value === ",") {
-                    token = next();
-                } else {
-                    break;
-                }
-            } while (token);
+        // only the end of the text can close containers, not a tokenizer error
+        if (!token && options.tolerant && !tokenizerError) {
+            recover(token);
+            closeTokenIndex = insertSyntheticToken(container.isArray ? "]" : "}", tokens.length);
+            token = tokens[closeTokenIndex];
         }
 
-        assertTokenValue(token, "}");
-        const range = createRange(firstToken.loc.start, token.loc.end);
+        const { openToken, openTokenIndex, children } = container;
 
-        return t.object(members, {
-            loc: {
-                start: {
-                    ...firstToken.loc.start
-                },
-                end: {
-                    ...token.loc.end
-                }
+        assertClosingBracket(token, openToken);
+        const range = createRange(openToken.loc.start, token.loc.end);
+        const tokenIndices = options.tokens ? {
+            openTokenIndex,
+            closeTokenIndex
+        } : undefined;
+        const loc = {
+            start: {
+                ...openToken.loc.start
             },
-            ...range
-        });
+            end: {
+                ...token.loc.end
+            }
+        };
 
+        if (container.isArray) {
+            return t.array(children, {
+                type: "Array",
+                elements: children,
+                loc,
+                ...range,
+                ...tokenIndices
+            });
+        }
+
+        return t.object(children, {
+            loc,
+            ...range,
+            ...tokenIndices
+        });
     }
 
-    function parseArray(firstToken) {
+    /**
+     * Parses a value, including any arrays and objects it contains. Arrays
+     * and objects that are still open are kept in a stack instead of being
+     * parsed recursively, so deeply nested input can't overflow the call
+     * stack and the time taken only depends on the number of tokens.
+     * @param {Token} [token] The first token of the value. If missing, the
+     *      next token is read.
+     * @returns {Node} The node for the value.
+     */
+    function parseValue(token) {
 
-        // The first token must be a [ or else it's an error
-        assertTokenValue(firstToken, "[");
+        /*
+         * The arrays and objects being parsed, innermost last. Each has the
+         * token that opened it, its members or elements so far, and, for an
+         * object, the name of the member whose value is being parsed.
+         */
+        const containers = [];
 
-        const elements = [];
-        let token = next();
-        
-        while (token && token.value !== "]") {
+        token = token || next();
 
-            // add the value into the array
-            elements.push(parseValue(token));
+        for (;;) {
 
-            token = next();
-            
-            if (token.value === ",") {
+            if (!token) {
+                unexpected(token);
+            }
+
+            let value;
+
+            if (token.type === "Punctuator" && (token.value === "{" || token.value === "[")) {
+                const container = {
+                    openToken: token,
+                    openTokenIndex: tokenIndex - 1,
+                    isArray: token.value === "[",
+                    children: [],
+                    name: null
+                };
+
+                containers.push(container);
                 token = next();
+
+                if (token && token.value !== "}" && token.value !== "]") {
+                    token = startChild(container, token);
+                    continue;
+                }
+
+                containers.pop();
+                value = closeContainer(container, token);
             } else {
-                break;
+                value = parseLiteral(token);
             }
-        }
 
-        assertTokenValue(token, "]");
-        const range = createRange(firstToken.loc.start, token.loc.end);
+            // a finished value may be the last one in its container, which then finishes too
+            while (containers.length) {
+                const container = containers[containers.length - 1];
+                let hasNext = false;
 
-        return t.array(elements, {
-            type: "Array",
-            elements,
-            loc: {
-                start: {
-                    ...firstToken.loc.start
-                },
-                end: {
-                    ...token.loc.end
+                // the token is read first so a tokenizer error after the value is still inside it
+                token = next();
+                addChild(container, value);
+
+                if (token && token.value === ",") {
+                    token = next();
+
+                    // a comma before the closing bracket is allowed in arrays
+                    hasNext = container.isArray
+                        ? !!token && token.value !== "]" && token.value !== "}"
+                        : !!token;
+                } else if (isMissingComma(token, container.isArray)) {
+                    recover(token);
+                    insertSyntheticToken(",", tokenIndex - 1);
+                    hasNext = true;
                 }
-            },
-            ...range
-        });
 
+                if (hasNext) {
+                    token = startChild(container, token);
+                    break;
+                }
+
+                containers.pop();
+                value = closeContainer(container, token);
+            }
+
+            if (!containers.length) {
+                return value;
+            }
+        }
     }
 
+    let docBody = null;
+    let trailingOffset = null;
 
+    try {
+        const firstToken = next();
 
-    function parseValue(token) {
+        // a value read after earlier values ends the input rather than leaving it empty
+        if (!firstToken && valueOnly) {
+            throw new UnexpectedEOF(createLineIndex(text).getLocation(text.length));
+        }
 
-        token = token || next();
-        
-        switch (token.type) {
-        case "String":
-        case "Boolean":
-        case "Number":
-        case "Null":
-            return createLiteralNode(token);
+        if (!firstToken && !options.allowEmpty) {
+            throw new EmptyDocument(locateError(docStart));
+        }
+
+        docBody = firstToken ? parseValue(firstToken) : null;
+
+        if (docBody && options.rootTypes && !options.rootTypes.includes(docBody.type)) {
+            throw new UnexpectedRootType(options.rootTypes, withErrorLocation(docBody));
+        }
 
-        case "Punctuator":
-            if (token.value === "{") {
-                return parseObject(token);
-            } else if (token.value === "[") {
-                return parseArray(token);
+        if (valueOnly) {
+            return { node: docBody, tokenIndex };
+        }
+    
+        if (prefix) {
+
+            // text that can't be tokenized is also trailing content
+            const trailingToken = next();
+            trailingOffset = trailingToken ? trailingToken.loc.start.offset
+                : tokenizerError ? tokenizerError.index
+                    : null;
+        } else {
+            const unexpectedToken = next();
+            if (unexpectedToken) {
+                throw new UnexpectedToken(withErrorLocation(unexpectedToken));
             }
-            /*falls through*/
+        }
 
-        default:
-            throw new UnexpectedToken(token);
+        /*
+         * A record ends at the next separator and a document at the end of
+         * the text, so text that can't be tokenized is in them.
+         */
+        if (!prefix && tokenizerError) {
+            throw tokenizerError;
+        }
+    } catch (ex) {
+
+        // the tokens ran out because the tokenizer stopped at an error
+        if (tokenizerError && (ex instanceof UnexpectedEOF || ex instanceof EmptyDocument)) {
+            throw addErrorDetails(tokenizerError, contextAtEnd || contextStack);
+        }
+
+        /*
+         * Errors in the text are reported before errors in the order of the
+         * tokens, like they would be if the document were tokenized first.
+         * The context is only known when the parser reached the error.
+         */
+        if (tokenizerError && !prefix && !record) {
+            throw addErrorDetails(tokenizerError, contextAtEnd || []);
         }
 
+        throw addErrorDetails(ex);
     }
 
-    
-    const docBody = parseValue();
-    
-    const unexpectedToken = next();
-    if (unexpectedToken) {
-        throw new UnexpectedToken(unexpectedToken);
+    // tokens after the value aren't part of the document
+    if (prefix) {
+        const end = docBody ? docBody.loc.end.offset : 0;
+        tokens = tokens.filter(token => token.loc.end.offset <= end);
     }
-    
-    
+
+    let docEnd = docBody ? docBody.loc.end : docStart;
+
+    // a document without a value still covers the whitespace and comments in the text
+    if (!docBody && !prefix && !record) {
+        docEnd = textEnd || (options.offsetsOnly ? { offset: text.length } : createLineIndex(text).getLocation(text.length));
+    }
+
     const docParts = {
         loc: {
             start: {
-                line: 1,
-                column: 1,
-                offset: 0
+                ...docStart
             },
             end: {
-                ...docBody.loc.end
+                ...docEnd
             }
         }
     };
-    
+
+    if (options.comments) {
+        docParts.comments = tokens.filter(isComment);
+    }
 
     if (options.tokens) {
-        docParts.tokens = tokens;
+        docParts.tokens = eofToken ? [...tokens, eofToken] : tokens;
     }
 
     if (options.ranges) {
         docParts.range = createRange(docParts.loc.start, docParts.loc.end);
     }
 
-    return t.document(docBody, docParts);
+    if (options.sourceName) {
+        docParts.sourceName = options.sourceName;
+    }
+
+    if (options.trailingContent === "return") {
+        docParts.trailingOffset = trailingOffset;
+    }
+
+    if (options.onProgress) {
+        options.onProgress({
+            offset: text.length,
+            length: text.length
+        });
+    }
+
+    const document = t.document(docBody, docParts);
+
+    parseInfo.set(document, {
+        mode: options.comments ? "jsonc" : "json",
+        options,
+        errors: recoveredErrors,
+
+        // lines are only counted again when the tokenizer didn't count them
+        get endLocation() {
+            if (!textEnd || textEnd.line === undefined) {
+                textEnd = createLineIndex(text).getLocation(textEnd ? textEnd.offset : text.length);
+            }
+
+            return { ...textEnd };
+        },
+
+        // a record only counts the lines from its separator to its end
+        get lineCount() {
+            const startLine = !record ? 1
+                : docStart.line === undefined ? createLineIndex(text).getLocation(docStart.offset).line
+                    : docStart.line;
+
+            return this.endLocation.line - startLine + 1;
+        }
+    });
 
+    return document;
+
+}
+
+/**
+ * 
+ * @param {string} text The text to parse.
+ * @param {boolean} [options.tokens=false] Determines if tokens are returned in
+ *      the AST. 
+ * @param {boolean} [options.comments=false] Determines if comments are allowed
+ *      in the JSON. When `true`, the document has a `comments` property
+ *      containing the comment tokens.
+ * @param {boolean} [options.ranges=false] Determines if ranges will be returned
+ *      in addition to `loc` properties.
+ * @param {boolean} [options.allowEmpty=false] Determines if an empty or
+ *      whitespace-only document is allowed. When `true`, such a document
+ *      produces a `Document` node whose `body` is `null`.
+ * @param {boolean} [options.bom=false] Determines if a byte order mark at
+ *      the start of the text is a `BOM` token in the returned tokens.
+ * @param {boolean} [options.eof=false] Determines if the returned tokens
+ *      end with an `EOF` token. Only used when `tokens` is `true`.
+ * @param {boolean} [options.integersOnly=false] Determines if numbers with
+ *      a fraction or exponent cause a `NonIntegerNumber` error.
+ * @param {Array<string>} [options.rootTypes] The node types allowed as the
+ *      root value, such as `["Object"]`. Any other root value causes an
+ *      `UnexpectedRootType` error.
+ * @param {boolean} [options.shebang=false] Determines if a first line that
+ *      starts with `#!` is allowed, even after a byte order mark. When
+ *      `true`, the line is skipped and is a `Shebang` token in the returned
+ *      tokens.
+ * @param {boolean} [options.strictWhitespace=false] Determines if
+ *      whitespace other than spaces, tabs, carriage returns, and line feeds
+ *      causes an `IrregularWhitespace` error instead of being skipped.
+ * @param {boolean} [options.tolerant=false] Determines if a missing comma
+ *      between members or elements and missing closing brackets and braces
+ *      at the end of the text are assumed instead of causing an error. Each
+ *      assumed punctuator is added to the tokens with `synthetic: true`.
+ * @param {Function} [options.validateString] A function that is called with
+ *      the decoded value and location of every string, including member
+ *      names. If it returns a message, parsing stops and an `InvalidString`
+ *      error with that message is thrown at the string's location.
+ * @param {int} [options.maxExponentDigits=Infinity] The maximum number of
+ *      digits allowed in the exponent of a number. A longer exponent causes
+ *      a `LimitExceeded` error as soon as the extra digit is read.
+ * @param {int} [options.maxInputBytes=Infinity] The maximum size of the
+ *      input in UTF-8 bytes. Larger input causes a `LimitExceeded` error
+ *      before it's tokenized.
+ * @param {int} [options.maxLineLength=Infinity] The maximum number of
+ *      characters allowed on a single line, not counting line breaks.
+ * @param {int} [options.maxStringLength=Infinity] The maximum number of
+ *      characters allowed in a string after escapes are decoded.
+ * @param {boolean} [options.graphemeColumns=false] Determines if error
+ *      columns count extended grapheme clusters instead of UTF-16 code units
+ *      so they match the characters a user sees. Node locations aren't
+ *      affected.
+ * @param {boolean} [options.offsetsOnly=false] Determines if locations
+ *      only have an `offset`, skipping the calculation of lines and columns.
+ *      Errors still have full locations.
+ * @param {Function} [options.onProgress] A function that is called with an
+ *      object containing `offset` and `length` properties as parsing
+ *      progresses and once more when parsing is complete.
+ * @param {Function} [options.shouldCancel] A function that is called as
+ *      parsing progresses. If it returns `true`, parsing stops and a
+ *      `ParseCancelled` error is thrown.
+ * @param {int} [options.callbackInterval=1000] The number of tokens to read
+ *      between calls to `onProgress` and `shouldCancel`.
+ * @param {string} [options.sourceName] The name of the file or URI being
+ *      parsed. When present, it's included in error messages, attached to
+ *      errors as `sourceName`, and attached to the returned document.
+ * @param {string} [options.trailingContent="error"] What to do with text
+ *      after the value: `"error"` to throw an error, `"ignore"` to ignore
+ *      it as `parsePrefix()` does, or `"return"` to also add its offset to
+ *      the document as `trailingOffset`, which is `null` when there's
+ *      nothing after the value but whitespace and comments.
+ * @returns {Object} The AST representing the parsed JSON.
+ * @throws {Error} When there is a parsing error. 
+ */
+export function parse(text, options) {
+    const trailingContent = options && options.trailingContent;
+    return parseDocument(text, options, trailingContent === "ignore" || trailingContent === "return");
+}
+
+/**
+ * Parses JSON text with the `tolerant` option and returns the errors that
+ * were recovered from along with the AST, so editors can show problems in
+ * text that's still being edited while working with the rest of it.
+ * @param {string} text The text to parse.
+ * @param {Object} [options] The same options as `parse()`. The `tolerant`
+ *      option is always `true`.
+ * @returns {Object} An object with an `ast` property containing the AST
+ *      and an `errors` property containing an array of the errors that
+ *      would have been thrown without the `tolerant` option, one for each
+ *      assumed punctuator, in the order they were found.
+ * @throws {Error} When there is a parsing error that can't be recovered
+ *      from.
+ */
+export function parseTolerant(text, options) {
+    const ast = parse(text, { ...options, tolerant: true });
+    return { ast, errors: getParseInfo(ast).errors.slice() };
+}
+
+/**
+ * Parses JSON text, turning off options that are expensive for large input
+ * so callers get reasonable performance without tuning each option. Input
+ * shorter than a threshold is parsed with the options as given.
+ * @param {string} text The text to parse.
+ * @param {Object} [options] The same options as `parse()`, plus:
+ * @param {Object} [options.thresholds] The input lengths, in characters, at
+ *      which options are turned off.
+ * @param {int} [options.thresholds.tokens=1000000] The length at which
+ *      `tokens` is turned off so the tokens aren't kept after parsing.
+ * @param {int} [options.thresholds.ranges=1000000] The length at which
+ *      `ranges` is turned off so nodes don't get `range` properties.
+ * @returns {Object} The AST representing the parsed JSON. Pass it to
+ *      `getParseInfo()` to find out which options were used.
+ * @throws {Error} When there is a parsing error.
+ */
+export function parseAuto(text, options = {}) {
+    const { thresholds, ...parseOptions } = options;
+    const { tokens, ranges } = { ...DEFAULT_THRESHOLDS, ...thresholds };
+
+    if (text.length >= tokens) {
+        parseOptions.tokens = false;
+    }
+
+    if (text.length >= ranges) {
+        parseOptions.ranges = false;
+    }
+
+    return parse(text, parseOptions);
+}
+
+/**
+ * Parses UTF-8 bytes, such as the contents of a file read without an
+ * encoding. Invalid bytes cause an `InvalidUtf8` error with the location
+ * where they would be in the text instead of being replaced with U+FFFD.
+ * @param {Uint8Array} bytes The bytes to parse.
+ * @param {Object} [options] The same options as `parse()`.
+ * @returns {Object} The AST representing the parsed JSON.
+ * @throws {Error} When the bytes aren't valid UTF-8 or there is a parsing
+ *      error.
+ */
+export function parseBytes(bytes, options = {}) {
+
+    checkInputSize(bytes.length, { ...DEFAULT_OPTIONS, ...options });
+
+    let text;
+
+    try {
+        text = decodeUtf8(bytes);
+    } catch (ex) {
+        if (options.sourceName) {
+            addSourceName(ex, options.sourceName);
+        }
+
+        throw ex;
+    }
+
+    return parse(text, options);
+}
+
+/**
+ * Parses a JSON text sequence (RFC 7464), as used by `application/json-seq`
+ * streams, where each JSON text starts with a record separator character
+ * (U+001E). Locations are relative to the whole text.
+ * @param {string} text The text to parse.
+ * @param {Object} [options] The same options as `parse()`, except `eof` and
+ *      `shebang` are ignored and `maxInputBytes` applies to the whole text.
+ *      The `tokens` and `comments` of each document only include the ones
+ *      in its record.
+ * @returns {Iterator<Object>} An iterator over the `Document` node for each
+ *      record. Consecutive record separators are ignored.
+ * @throws {Error} When a record can't be parsed or the text doesn't start
+ *      with a record separator. Records before the error are still
+ *      returned by the iterator.
+ */
+export function *parseSequence(text, options = {}) {
+
+    checkTextSize(text, { ...DEFAULT_OPTIONS, ...options });
+
+    const { tokens, error, end: textEnd } = tokenizePartial(text, {
+        comments: !!options.comments,
+        ranges: !!options.ranges,
+        recordSeparators: true,
+        strictWhitespace: !!options.strictWhitespace,
+        offsetsOnly: !!options.offsetsOnly,
+        maxLineLength: options.maxLineLength,
+        maxExponentDigits: options.maxExponentDigits
+    });
+
+    const firstToken = tokens[0];
+
+    if (firstToken && firstToken.type !== "RecordSeparator" || !firstToken && error) {
+        let ex = error;
+
+        if (firstToken) {
+            const start = options.offsetsOnly
+                ? createLineIndex(text).getLocation(firstToken.loc.start.offset)
+                : firstToken.loc.start;
+
+            ex = new UnexpectedToken({ ...firstToken, loc: { start, end: start } });
+        }
+
+        if (options.sourceName) {
+            addSourceName(ex, options.sourceName);
+        }
+
+        throw ex;
+    }
+
+    let start = 0;
+
+    while (start < tokens.length) {
+        let end = start + 1;
+
+        while (end < tokens.length && tokens[end].type !== "RecordSeparator") {
+            end++;
+        }
+
+        const isLast = end === tokens.length;
+
+        // consecutive separators don't create empty records
+        if (end > start + 1 || isLast && error) {
+            yield parseDocument(text, options, false, {
+                tokens: tokens.slice(start + 1, end),
+                error: isLast ? error : null,
+                start: { ...tokens[start].loc.end },
+
+                // a record ends where the next separator starts
+                end: isLast ? textEnd : { ...tokens[end].loc.start }
+            });
+        }
+
+        start = end;
+    }
+}
+
+/**
+ * Parses the first value in the text and stops, so the value can be followed
+ * by text that isn't JSON, such as a config block at the top of a file.
+ * @param {string} text The text to parse.
+ * @param {Object} [options] The same options as `parse()`, except `eof`
+ *      is ignored.
+ * @returns {Object} An object with an `ast` property containing the
+ *      document for the first value and an `offset` property containing
+ *      the offset of the first character after the value.
+ * @throws {Error} When there is a parsing error before the end of the value.
+ */
+export function parsePrefix(text, options) {
+    const ast = parseDocument(text, options, true);
+
+    return {
+        ast,
+        offset: ast.loc.end.offset
+    };
+}
+
+/**
+ * Creates a parser that reads one token or value at a time and can return
+ * to an earlier position, so tools can try more than one interpretation of
+ * the text, such as when deciding whether a file uses a relaxed syntax.
+ * Text that can't be tokenized only causes an error when the parser reaches
+ * it, so everything before it can still be read.
+ * @param {string} text The text to parse.
+ * @param {Object} [options] The same options as `parse()`, except `eof`
+ *      and `tokens` are ignored. When `comments` is `true`, `peek()` and
+ *      `next()` return comment tokens.
+ * @returns {Object} The parser, with these methods:
+ *
+ *      - `peek()` returns the next token without reading it, or `null` at
+ *        the end of the text.
+ *      - `next()` reads and returns the next token, or `null` at the end of
+ *        the text.
+ *      - `parseValue()` reads a whole value starting at the next token and
+ *        returns its node.
+ *      - `checkpoint()` returns an object representing the current position.
+ *      - `rewind(checkpoint)` returns to the position of a checkpoint.
+ *
+ *      The parser's `offset` property is the offset where the next token
+ *      starts, or the length of the text at the end. `peek()`, `next()`, and
+ *      `parseValue()` throw the same errors as `parse()`; after an error,
+ *      the position doesn't change.
+ * @throws {TypeError} When the `tolerant` option is used, because assumed
+ *      punctuators would be added to the tokens the parser's position and
+ *      checkpoints refer to.
+ */
+export function createParser(text, options = {}) {
+
+    if (options.tolerant) {
+        throw new TypeError("The tolerant option can't be used with createParser().");
+    }
+
+    checkTextSize(text, options);
+
+    const { tokens, error } = tokenizePartial(text, {
+        comments: !!options.comments,
+        ranges: !!options.ranges,
+        bom: !!options.bom,
+        shebang: !!options.shebang,
+        strictWhitespace: !!options.strictWhitespace,
+        offsetsOnly: !!options.offsetsOnly,
+        maxLineLength: options.maxLineLength,
+        maxExponentDigits: options.maxExponentDigits
+    });
+
+    if (error && options.sourceName) {
+        addSourceName(error, options.sourceName);
+    }
+
+    const checkpoints = new WeakSet();
+    let tokenIndex = 0;
+
+    /**
+     * Gets the token at the current position.
+     * @returns {Token|null} The token or `null` at the end of the text.
+     * @throws {Error} When the text at the current position can't be
+     *      tokenized.
+     */
+    function current() {
+
+        if (tokenIndex < tokens.length) {
+            return tokens[tokenIndex];
+        }
+
+        if (error) {
+            throw error;
+        }
+
+        return null;
+    }
+
+    return {
+
+        get offset() {
+            const token = tokens[tokenIndex];
+
+            if (token) {
+                return token.loc.start.offset;
+            }
+
+            return error ? error.index : text.length;
+        },
+
+        peek() {
+            return current();
+        },
+
+        next() {
+            const token = current();
+
+            if (token) {
+                tokenIndex++;
+            }
+
+            return token;
+        },
+
+        parseValue() {
+
+            // the value is parsed from the tokens already read, so each call only reads the value's tokens
+            const result = parseDocument(text, {
+                ...options,
+                allowEmpty: false,
+                tokens: false
+            }, false, {
+                tokens,
+                error: error && copyError(error),
+                start: options.offsetsOnly ? { offset: 0 } : { line: 1, column: 1, offset: 0 },
+                tokenIndex
+            });
+
+            tokenIndex = result.tokenIndex;
+
+            return result.node;
+        },
+
+        checkpoint() {
+            const checkpoint = Object.freeze({ tokenIndex });
+
+            checkpoints.add(checkpoint);
+            return checkpoint;
+        },
+
+        rewind(checkpoint) {
+
+            if (!checkpoints.has(checkpoint)) {
+                throw new TypeError("The checkpoint wasn't created by this parser.");
+            }
+
+            tokenIndex = checkpoint.tokenIndex;
+        }
+    };
+}
+
+/**
+ * Gets the mode and options that were used to parse a document, so code
+ * that receives the document, such as a printer or a cache, can make the
+ * same decisions as the parser without the options being passed along
+ * separately.
+ * @param {Node} document The `Document` node.
+ * @returns {Object|null} An object with a `mode` property of `"json"` or
+ *      `"jsonc"` (when comments were allowed), an `options` property
+ *      containing the frozen options, including defaults, and an `errors`
+ *      property containing the errors recovered from when the `tolerant`
+ *      option was used, or `null` if the document wasn't created by
+ *      parsing, such as one created by `fromValue()`.
+ */
+export function getParseInfo(document) {
+    return parseInfo.get(document) || null;
 }
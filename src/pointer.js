@@ -0,0 +1,423 @@
+/**
+ * @fileoverview JSON Pointer (RFC 6901) helpers for Momoa AST.
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Helpers
+//-----------------------------------------------------------------------------
+
+const ARRAY_INDEX = /^(?:0|[1-9]\d*)$/;
+
+/**
+ * Escapes a single segment of a JSON Pointer.
+ * @param {string|int} segment The segment to escape.
+ * @returns {string} The escaped segment.
+ */
+function escapeSegment(segment) {
+    return String(segment).replace(/~/g, "~0").replace(/\//g, "~1");
+}
+
+/**
+ * Unescapes a single segment of a JSON Pointer.
+ * @param {string} segment The segment to unescape.
+ * @param {string} pointer The full pointer, for error messages.
+ * @returns {string} The unescaped segment.
+ * @throws {SyntaxError} When the segment contains an invalid escape.
+ */
+function unescapeSegment(segment, pointer) {
+    if (/~(?![01])/.test(segment)) {
+        throw new SyntaxError(`Invalid escape in JSON Pointer "${ pointer }".`);
+    }
+
+    // ~1 must be replaced first so ~01 becomes ~1 and not /
+    return segment.replace(/~1/g, "/").replace(/~0/g, "~");
+}
+
+/**
+ * Gets the JSON Pointer in a string that refers to another part of the same
+ * document, either as a pointer, such as `"/definitions/a"`, or as a URI
+ * fragment, such as `"#/definitions/a"`, which may be percent-encoded.
+ * @param {string} value The string to check.
+ * @returns {string|null} The JSON Pointer or `null` if the string isn't a
+ *      reference to the same document.
+ * @throws {SyntaxError} When the string is a reference but the pointer is
+ *      invalid.
+ */
+function getReferencePointer(value) {
+
+    if (value.charAt(0) === "/") {
+        parsePointer(value);
+        return value;
+    }
+
+    if (value.charAt(0) !== "#") {
+        return null;
+    }
+
+    let pointer;
+
+    try {
+        pointer = decodeURIComponent(value.slice(1));
+    } catch (ex) {
+        throw new SyntaxError(`Invalid percent-encoding in JSON Pointer "${ value }".`);
+    }
+
+    parsePointer(pointer);
+    return pointer;
+}
+
+//-----------------------------------------------------------------------------
+// Exports
+//-----------------------------------------------------------------------------
+
+/**
+ * Converts a JSON Pointer into an array of unescaped segments.
+ * @param {string} pointer The JSON Pointer to convert, such as `"/a/0"`.
+ * @returns {Array<string>} The segments of the pointer. The pointer `""`
+ *      returns an empty array and the pointer `"/"` returns `[""]`.
+ * @throws {SyntaxError} When the pointer is invalid.
+ */
+export function parsePointer(pointer) {
+
+    if (pointer === "") {
+        return [];
+    }
+
+    if (pointer.charAt(0) !== "/") {
+        throw new SyntaxError(`JSON Pointer "${ pointer }" must be empty or start with "/".`);
+    }
+
+    return pointer.slice(1).split("/").map(segment => unescapeSegment(segment, pointer));
+}
+
+/**
+ * Converts an array of segments into a JSON Pointer, escaping `~` and `/`.
+ * @param {Array<string|int>} path The segments of the pointer. Member names
+ *      are strings and array indices are numbers.
+ * @returns {string} The JSON Pointer.
+ */
+export function formatPointer(path) {
+    return path.map(segment => "/" + escapeSegment(segment)).join("");
+}
+
+/**
+ * Finds the node that a JSON Pointer refers to. When an object has more
+ * than one member with the same name, the last one is used, which matches
+ * `JSON.parse()` and `evaluate()`.
+ * @param {Node} root The node to start from. If this is a `Document` node,
+ *      the pointer is resolved against its body.
+ * @param {string|Array<string|int>} pointer The JSON Pointer or an array of
+ *      unescaped segments.
+ * @returns {Node|undefined} The value node the pointer refers to or
+ *      `undefined` if there is no such node.
+ * @throws {SyntaxError} When the pointer is invalid.
+ */
+export function resolvePointer(root, pointer) {
+
+    const path = typeof pointer === "string" ? parsePointer(pointer) : pointer;
+    let node = root.type === "Document" ? root.body : root;
+
+    for (const segment of path) {
+
+        if (!node) {
+            return undefined;
+        }
+
+        const key = String(segment);
+
+        if (node.type === "Object") {
+            let found;
+
+            for (const member of node.members) {
+                if (member.name.value === key) {
+                    found = member.value;
+                }
+            }
+
+            node = found;
+        } else if (node.type === "Array" && ARRAY_INDEX.test(key)) {
+            node = node.elements[Number(key)];
+        } else {
+            return undefined;
+        }
+    }
+
+    return node || undefined;
+}
+
+/**
+ * Finds the nodes that many JSON Pointers refer to. This is faster than
+ * calling `resolvePointer()` for each pointer because pointers that share a
+ * prefix are resolved together and each object's members are only indexed
+ * once.
+ * @param {Node} root The node to start from. If this is a `Document` node,
+ *      the pointers are resolved against its body.
+ * @param {Array<string|Array<string|int>>} pointers The JSON Pointers or
+ *      arrays of unescaped segments.
+ * @returns {Array<Node|undefined>} The value node each pointer refers to, in
+ *      the same order as `pointers`, or `undefined` where there is no match.
+ * @throws {SyntaxError} When a pointer is invalid.
+ */
+export function resolvePointers(root, pointers) {
+
+    const results = new Array(pointers.length).fill(undefined);
+
+    // build a tree of segments so shared prefixes are only resolved once
+    const tree = { indices: [], children: new Map() };
+
+    pointers.forEach((pointer, i) => {
+        const path = typeof pointer === "string" ? parsePointer(pointer) : pointer;
+        let branch = tree;
+
+        for (const segment of path) {
+            const key = String(segment);
+
+            if (!branch.children.has(key)) {
+                branch.children.set(key, { indices: [], children: new Map() });
+            }
+
+            branch = branch.children.get(key);
+        }
+
+        branch.indices.push(i);
+    });
+
+    const pending = [[root.type === "Document" ? root.body : root, tree]];
+
+    while (pending.length) {
+        const [node, branch] = pending.pop();
+
+        // an empty document has no body to resolve against
+        if (!node) {
+            continue;
+        }
+
+        for (const i of branch.indices) {
+            results[i] = node;
+        }
+
+        if (!branch.children.size) {
+            continue;
+        }
+
+        if (node.type === "Object") {
+            const values = new Map(node.members.map(member => [member.name.value, member.value]));
+
+            for (const [key, child] of branch.children) {
+                if (values.has(key)) {
+                    pending.push([values.get(key), child]);
+                }
+            }
+        } else if (node.type === "Array") {
+            for (const [key, child] of branch.children) {
+                if (ARRAY_INDEX.test(key) && Number(key) < node.elements.length) {
+                    pending.push([node.elements[Number(key)], child]);
+                }
+            }
+        }
+    }
+
+    return results;
+}
+
+/**
+ * Creates an index of the JSON Pointer for every node in a tree in a single
+ * pass, so analyses that need the pointers for many nodes don't each need
+ * to track paths during traversal. Value nodes map to their own pointer,
+ * while `Member` nodes and their names map to the pointer of the member's
+ * value. When an object has more than one member with the same name, each
+ * member still gets an entry even though only the last one can be resolved.
+ * @param {Node} root The node to start from. If this is a `Document` node,
+ *      it and its body map to the pointer `""`.
+ * @returns {Map<Node,Object>} A map of each node to an object with
+ *      `pointer`, `path`, and `depth` properties, where `path` is the array
+ *      of unescaped segments and `depth` is the number of objects and
+ *      arrays that contain the node.
+ */
+export function createPointerIndex(root) {
+
+    const index = new Map();
+    const pending = [[root, []]];
+
+    while (pending.length) {
+        const [node, path] = pending.pop();
+        const entry = { pointer: formatPointer(path), path, depth: path.length };
+
+        index.set(node, entry);
+
+        switch (node.type) {
+        case "Document":
+            if (node.body) {
+                pending.push([node.body, path]);
+            }
+            break;
+
+        case "Object":
+            for (const member of node.members) {
+                const memberPath = [...path, member.name.value];
+                const memberEntry = { pointer: formatPointer(memberPath), path: memberPath, depth: memberPath.length };
+
+                index.set(member, memberEntry);
+                index.set(member.name, memberEntry);
+                pending.push([member.value, memberPath]);
+            }
+            break;
+
+        case "Array":
+            node.elements.forEach((element, i) => {
+                pending.push([element, [...path, i]]);
+            });
+            break;
+
+        // no default
+        }
+    }
+
+    return index;
+}
+
+/**
+ * Creates an iterator over every value in a tree along with its JSON
+ * Pointer, in document order. `Member` nodes and member names aren't
+ * included since they share the pointer of the member's value. This makes
+ * it possible to flatten a document into pointer-value pairs in one pass.
+ * @param {Node} root The node to start from. If this is a `Document` node,
+ *      iteration starts at its body.
+ * @returns {Iterator<Object>} An iterator over objects with `node`,
+ *      `pointer`, `path`, and `depth` properties, where `path` is the array
+ *      of unescaped segments and `depth` is the number of objects and
+ *      arrays that contain the node.
+ */
+export function *iterateWithPointers(root) {
+
+    const start = root.type === "Document" ? root.body : root;
+
+    // an empty document has no values
+    if (!start) {
+        return;
+    }
+
+    const pending = [[start, []]];
+
+    while (pending.length) {
+        const [node, path] = pending.pop();
+
+        yield { node, pointer: formatPointer(path), path, depth: path.length };
+
+        // children are pushed in reverse so they're visited in order
+        if (node.type === "Object") {
+            for (let i = node.members.length - 1; i >= 0; i--) {
+                const member = node.members[i];
+                pending.push([member.value, [...path, member.name.value]]);
+            }
+        } else if (node.type === "Array") {
+            for (let i = node.elements.length - 1; i >= 0; i--) {
+                pending.push([node.elements[i], [...path, i]]);
+            }
+        }
+    }
+}
+
+/**
+ * Creates an iterator over every string value in a tree along with its
+ * location and JSON Pointer, in document order, for tools such as secret
+ * scanners that only care about strings.
+ * @param {Node} root The node to start from. If this is a `Document` node,
+ *      iteration starts at its body.
+ * @param {Object} [options] Options for the iteration.
+ * @param {boolean} [options.names=false] Determines if member names are
+ *      included. A member name has the same pointer as the member's value.
+ * @returns {Iterator<Object>} An iterator over objects with `value`, `loc`,
+ *      `pointer`, `node`, and `isName` properties, where `value` is the
+ *      decoded string and `isName` is `true` for member names.
+ */
+export function *iterateStrings(root, { names = false } = {}) {
+
+    const start = root.type === "Document" ? root.body : root;
+
+    // an empty document has no strings
+    if (!start) {
+        return;
+    }
+
+    const pending = [[start, [], false]];
+
+    while (pending.length) {
+        const [node, path, isName] = pending.pop();
+
+        if (node.type === "String") {
+            yield { value: node.value, loc: node.loc, pointer: formatPointer(path), node, isName };
+        } else if (node.type === "Object") {
+
+            // children are pushed in reverse so they're visited in order
+            for (let i = node.members.length - 1; i >= 0; i--) {
+                const member = node.members[i];
+                const memberPath = [...path, member.name.value];
+
+                pending.push([member.value, memberPath, false]);
+
+                if (names) {
+                    pending.push([member.name, memberPath, true]);
+                }
+            }
+        } else if (node.type === "Array") {
+            for (let i = node.elements.length - 1; i >= 0; i--) {
+                pending.push([node.elements[i], [...path, i], false]);
+            }
+        }
+    }
+}
+
+/**
+ * Finds the strings in a document that are JSON Pointers to other parts of
+ * the same document, such as the values of `$ref` members, and resolves
+ * them. A reference can be a pointer, such as `"/definitions/a"`, or a URI
+ * fragment, such as `"#/definitions/a"`. Other strings, such as references
+ * to other documents, are skipped. References are resolved once, so a
+ * reference to another reference isn't followed.
+ * @param {Node} root The node to search and resolve pointers against. If
+ *      this is a `Document` node, its body is used.
+ * @param {Object} [options] Options for finding references.
+ * @param {Array<string>} [options.keys=["$ref"]] The member names whose
+ *      string values are references.
+ * @returns {Array<Object>} One object for each reference, in document order,
+ *      with a `node` property containing the `String` node, a `pointer`
+ *      property containing the JSON Pointer, a `target` property containing
+ *      the node the pointer refers to, and an `error` property. When the
+ *      pointer is invalid, `pointer` and `target` are `null` and `error`
+ *      is a `SyntaxError`. When nothing matches, `target` is `null`.
+ */
+export function resolveReferences(root, { keys = ["$ref"] } = {}) {
+
+    const references = [];
+
+    for (const { node, path } of iterateWithPointers(root)) {
+        const key = path[path.length - 1];
+
+        // array indices are numbers, so only member values are checked
+        if (node.type !== "String" || typeof key !== "string" || !keys.includes(key)) {
+            continue;
+        }
+
+        let pointer;
+
+        try {
+            pointer = getReferencePointer(node.value);
+        } catch (ex) {
+            references.push({ node, pointer: null, target: null, error: ex });
+            continue;
+        }
+
+        if (pointer !== null) {
+            references.push({
+                node,
+                pointer,
+                target: resolvePointer(root, pointer) || null,
+                error: null
+            });
+        }
+    }
+
+    return references;
+}
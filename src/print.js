@@ -8,19 +8,344 @@
 //-----------------------------------------------------------------------------
 
 import { evaluate } from "./evaluate";
+import { formatNumber } from "./numbers.js";
+import { isSyntheticLocation } from "./locations.js";
+
+//-----------------------------------------------------------------------------
+// Helpers
+//-----------------------------------------------------------------------------
+
+function isHighSurrogate(code) {
+    return code >= 0xD800 && code <= 0xDBFF;
+}
+
+function isLowSurrogate(code) {
+    return code >= 0xDC00 && code <= 0xDFFF;
+}
+
+/**
+ * Determines if a node has a location in the source text. Nodes created by
+ * `fromValue()` and the `types` builders have synthetic locations instead.
+ * @param {Node} node The node to check.
+ * @returns {boolean} True if the node came from the source text.
+ */
+function hasSourceLocation(node) {
+    return !isSyntheticLocation(node.loc) && node.loc.end.offset > node.loc.start.offset;
+}
+
+/**
+ * Finds the nodes that must be printed rather than copied from the source
+ * text: modified nodes, nodes without a source location, and every node
+ * that contains one of those.
+ * @param {Node} root The node to start from.
+ * @param {Set<Node>} modified The nodes that were modified.
+ * @returns {Set<Node>} The nodes that must be printed.
+ */
+function findDirtyNodes(root, modified) {
+
+    const dirty = new Set();
+
+    function visit(node) {
+        let isDirty = modified.has(node) || !hasSourceLocation(node);
+
+        switch (node.type) {
+        case "Document":
+            isDirty = (node.body && visit(node.body)) || isDirty;
+            break;
+
+        case "Object":
+            for (const member of node.members) {
+                isDirty = visit(member) || isDirty;
+            }
+            break;
+
+        case "Member":
+            isDirty = visit(node.name) || isDirty;
+            isDirty = visit(node.value) || isDirty;
+            break;
+
+        case "Array":
+            for (const element of node.elements) {
+                isDirty = visit(element) || isDirty;
+            }
+            break;
+
+        // no default
+        }
+
+        if (isDirty) {
+            dirty.add(node);
+        }
+
+        return isDirty;
+    }
+
+    visit(root);
+
+    return dirty;
+}
+
+/**
+ * Prints a node, copying the source text of nodes that weren't modified.
+ * Nodes that are printed, including objects and arrays that contain a
+ * modified node, lose any comments between their children because the AST
+ * doesn't include comments.
+ * @param {Node} root The node to print.
+ * @param {string} source The text the node was parsed from.
+ * @param {Set<Node>} modified The nodes that were modified.
+ * @param {int} indent The number of spaces to indent each line.
+ * @returns {string} The JSON text.
+ */
+function printWithSource(root, source, modified, indent) {
+
+    const dirty = findDirtyNodes(root, modified);
+    const space = " ".repeat(indent);
+
+    function printNode(node, depth) {
+
+        if (!dirty.has(node)) {
+            return node.type === "Document" ? source : getRawText(node, source);
+        }
+
+        const lineBreak = indent ? "\n" + space.repeat(depth + 1) : "";
+        const closingBreak = indent ? "\n" + space.repeat(depth) : "";
+
+        switch (node.type) {
+        case "Document": {
+
+            // a document can only start with a shebang when it was allowed while parsing
+            const [start] = /^\uFEFF?(?:#![^\r\n]*(?:\r\n|\r|\n)?)?/.exec(source);
+
+            return start + (node.body ? printNode(node.body, depth) : "");
+        }
+
+        case "Object":
+            if (!node.members.length) {
+                return "{}";
+            }
+
+            return "{" + node.members.map(member => lineBreak + printNode(member, depth + 1)).join(",") + closingBreak + "}";
+
+        case "Member":
+            return printNode(node.name, depth) + (indent ? ": " : ":") + printNode(node.value, depth);
+
+        case "Array":
+            if (!node.elements.length) {
+                return "[]";
+            }
+
+            return "[" + node.elements.map(element => lineBreak + printNode(element, depth + 1)).join(",") + closingBreak + "]";
+
+        case "Number":
+            return formatNumber(node.value);
+
+        case "Null":
+            return "null";
+
+        case "String": {
+            const text = JSON.stringify(node.value);
+            return node.escapedSolidus ? text.replace(/\//g, "\\/") : text;
+        }
+
+        default:
+            return JSON.stringify(node.value);
+        }
+    }
+
+    return printNode(root, 0);
+}
+
+/**
+ * Creates the pieces of a one-line rendering of a node, in order. Each piece
+ * has a `text` property and may have an `open` property containing the text
+ * that closes it, a `close` property when it closes the last open piece, and
+ * a `cut` property when the rendering may be cut off after it.
+ * @param {Node} node The node to render.
+ * @returns {Iterator<Object>} The pieces.
+ */
+function *getPreviewPieces(node) {
+
+    switch (node.type) {
+    case "Document":
+        if (node.body) {
+            yield* getPreviewPieces(node.body);
+        }
+        break;
+
+    case "Object":
+        yield { text: "{", open: "}", cut: true };
+
+        for (let i = 0; i < node.members.length; i++) {
+            if (i > 0) {
+                yield { text: ", ", cut: true };
+            }
+
+            yield* getPreviewPieces(node.members[i]);
+        }
+
+        yield { text: "}", close: true };
+        break;
+
+    case "Member":
+        yield* getPreviewPieces(node.name);
+        yield { text: ": ", cut: true };
+        yield* getPreviewPieces(node.value);
+        break;
+
+    case "Array":
+        yield { text: "[", open: "]", cut: true };
+
+        for (let i = 0; i < node.elements.length; i++) {
+            if (i > 0) {
+                yield { text: ", ", cut: true };
+            }
+
+            yield* getPreviewPieces(node.elements[i]);
+        }
+
+        yield { text: "]", close: true };
+        break;
+
+    case "String":
+        yield { text: "\"", open: "\"", cut: true };
+
+        // each character is one piece so escapes and surrogate pairs stay whole
+        for (const c of node.value) {
+            yield { text: JSON.stringify(c).slice(1, -1), cut: true };
+        }
+
+        yield { text: "\"", close: true };
+        break;
+
+    case "Number":
+        yield { text: formatNumber(node.value) };
+        break;
+
+    case "Null":
+        yield { text: "null" };
+        break;
+
+    default:
+        yield { text: String(node.value) };
+    }
+}
 
 //-----------------------------------------------------------------------------
 // Exports
 //-----------------------------------------------------------------------------
 
+/**
+ * Gets the text in a range, checking that the range actually fits the text.
+ * Unlike `String#slice()`, which quietly clamps bad offsets, this catches
+ * ranges that don't belong to the text, such as those from an AST that was
+ * parsed from a different version of the text.
+ * @param {string} text The text to slice.
+ * @param {Array<int>} range The start and end offsets.
+ * @returns {string} The text in the range.
+ * @throws {RangeError} When the offsets aren't integers, the start is after
+ *      the end, the end is past the end of the text, or either offset is
+ *      between the two halves of a surrogate pair.
+ */
+export function sliceText(text, range) {
+
+    const [start, end] = range;
+
+    if (!Number.isInteger(start) || !Number.isInteger(end) || start < 0 || start > end) {
+        throw new RangeError(`Range [${ start }, ${ end }] is invalid.`);
+    }
+
+    if (end > text.length) {
+        throw new RangeError(`Range [${ start }, ${ end }] is outside of the text (length ${ text.length }).`);
+    }
+
+    for (const offset of [start, end]) {
+        if (isLowSurrogate(text.charCodeAt(offset)) && isHighSurrogate(text.charCodeAt(offset - 1))) {
+            throw new RangeError(`Offset ${ offset } is in the middle of a surrogate pair.`);
+        }
+    }
+
+    return text.slice(start, end);
+}
+
+/**
+ * Gets the source text of a node.
+ * @param {Node} node The node to get the text for.
+ * @param {string} source The text the node was parsed from.
+ * @returns {string} The text of the node.
+ * @throws {RangeError} When the node's location doesn't fit the source.
+ */
+export function getRawText(node, source) {
+    return sliceText(source, [node.loc.start.offset, node.loc.end.offset]);
+}
+
 /**
  * Converts a Momoa AST back into a JSON string.
  * @param {Node} node The node to print.
  * @param {int} [options.indent=0] The number of spaces to indent each line. If
- *      greater than 0, then newlines and indents will be added to output. 
+ *      greater than 0, then newlines and indents will be added to output.
+ * @param {string} [options.source] The text the AST was parsed from. When
+ *      present, the text of nodes that weren't modified is copied from the
+ *      source instead of being printed, and every member is printed even
+ *      when names are duplicated. Comments inside of a printed object or
+ *      array, such as one containing a modified node, aren't kept.
+ * @param {Iterable<Node>} [options.modified] The nodes that were modified
+ *      after parsing. Only used with `source`. Nodes without a source
+ *      location, such as those created by `fromValue()`, are always
+ *      treated as modified.
  * @returns {string} The JSON representation of the AST.
  */
-export function print(node, { indent = 0 } = {}) {
+export function print(node, { indent = 0, source, modified = [] } = {}) {
+
+    if (typeof source === "string") {
+        return printWithSource(node, source, new Set(modified), indent);
+    }
+
     const value = evaluate(node);
     return JSON.stringify(value, null, indent);
 }
+
+/**
+ * Renders a node on a single line, cutting it off with `…` when it's longer
+ * than a maximum length, for places where printing the whole node is too
+ * much, such as hover tooltips and log messages. Open strings, objects, and
+ * arrays are still closed after the `…`, and escape sequences and surrogate
+ * pairs are never split.
+ * @param {Node} node The node to render.
+ * @param {int} [maxLength=80] The maximum length of the result.
+ * @returns {string} The rendering, such as `{"a": 1, …}`.
+ * @throws {RangeError} When `maxLength` isn't a positive integer.
+ */
+export function preview(node, maxLength = 80) {
+
+    if (!Number.isInteger(maxLength) || maxLength < 1) {
+        throw new RangeError("The maximum length must be a positive integer.");
+    }
+
+    const closers = [];
+    let text = "";
+    let truncated = "\u2026";
+
+    for (const piece of getPreviewPieces(node)) {
+        text += piece.text;
+
+        if (text.length > maxLength) {
+            return truncated;
+        }
+
+        if (piece.open) {
+            closers.push(piece.open);
+        } else if (piece.close) {
+            closers.pop();
+        }
+
+        if (piece.cut) {
+            const candidate = text + "\u2026" + closers.slice().reverse().join("");
+
+            if (candidate.length <= maxLength) {
+                truncated = candidate;
+            }
+        }
+    }
+
+    return text;
+}
@@ -0,0 +1,92 @@
+/**
+ * @fileoverview Tracking where nodes came from across transforms.
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+import { traverse } from "./traversal.js";
+import { isSyntheticLocation } from "./locations.js";
+
+//-----------------------------------------------------------------------------
+// Helpers
+//-----------------------------------------------------------------------------
+
+/**
+ * The origin of each tracked node. Nodes are the keys so the origin follows
+ * a node into any tree it's moved to without changing the node itself.
+ */
+const origins = new WeakMap();
+
+//-----------------------------------------------------------------------------
+// Exports
+//-----------------------------------------------------------------------------
+
+/**
+ * Records the origin of every node in a document so it can be looked up
+ * with `getProvenance()` after the nodes are moved into other trees, such
+ * as when layered configuration files are merged. Nodes with synthetic
+ * locations, such as those added by `fromValue()`, aren't tracked.
+ * @param {Node} document The document to track.
+ * @param {string} [source] An identifier for the document. Defaults to the
+ *      document's `sourceName`.
+ * @returns {Node} The same document.
+ * @throws {TypeError} When there is no `source` and the document has no
+ *      `sourceName`.
+ */
+export function trackProvenance(document, source = document.sourceName) {
+
+    if (source === undefined) {
+        throw new TypeError("A source is required when the document has no sourceName.");
+    }
+
+    traverse(document, {
+        enter(node) {
+
+            // nodes added to the document without a location didn't come from it
+            if (isSyntheticLocation(node.loc)) {
+                return;
+            }
+
+            origins.set(node, {
+                source,
+                range: [node.loc.start.offset, node.loc.end.offset]
+            });
+        }
+    });
+
+    return document;
+}
+
+/**
+ * Gets the origin of a node recorded by `trackProvenance()`.
+ * @param {Node} node The node to look up.
+ * @returns {Object|null} An object with a `source` property containing the
+ *      identifier of the document the node came from and a `range` property
+ *      containing the node's offsets in that document, or `null` if the node
+ *      wasn't in a tracked document, such as a node created by `fromValue()`.
+ */
+export function getProvenance(node) {
+    return origins.get(node) || null;
+}
+
+/**
+ * Gives a node the same origin as another node. Use this when a transform
+ * replaces a node with a copy so the copy can still be traced back to the
+ * original text.
+ * @param {Node} from The node to copy the origin from.
+ * @param {Node} to The node to copy the origin to.
+ * @returns {Node} The `to` node.
+ */
+export function copyProvenance(from, to) {
+
+    if (origins.has(from)) {
+        origins.set(to, origins.get(from));
+    } else {
+        origins.delete(to);
+    }
+
+    return to;
+}
@@ -0,0 +1,132 @@
+/**
+ * @fileoverview Converts errors into the SARIF format.
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Helpers
+//-----------------------------------------------------------------------------
+
+const SARIF_SCHEMA = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION = "2.1.0";
+const INFORMATION_URI = "https://github.com/humanwhocodes/momoa";
+
+// SARIF calls the least severe level "note" instead of "info"
+const LEVELS = new Map([
+    ["error", "error"],
+    ["warning", "warning"],
+    ["info", "note"]
+]);
+
+/**
+ * Gets the text of an error's message without the location at the end,
+ * which is reported in the result's region instead.
+ * @param {Object} diagnostic The serialized error.
+ * @returns {string} The message text.
+ */
+function getMessageText(diagnostic) {
+
+    const { message, range, sourceName } = diagnostic;
+
+    if (!range) {
+        return message;
+    }
+
+    const { line, column } = range.start;
+    const location = sourceName
+        ? ` (${ sourceName }:${ line }:${ column })`
+        : ` (${ line }:${ column })`;
+
+    return message.endsWith(location) ? message.slice(0, -location.length) : message;
+}
+
+/**
+ * Creates a SARIF location for an error.
+ * @param {Object} diagnostic The serialized error.
+ * @param {string} [sourceName] The source name to use if the error doesn't
+ *      have one.
+ * @returns {Array<Object>} An array containing the location, or an empty
+ *      array if there is no source name to use. The location has no region
+ *      when the error has no range.
+ */
+function createLocations(diagnostic, sourceName) {
+
+    const uri = diagnostic.sourceName || sourceName;
+
+    if (!uri) {
+        return [];
+    }
+
+    const physicalLocation = {
+        artifactLocation: { uri }
+    };
+
+    // errors at synthetic locations only identify the file
+    if (diagnostic.range) {
+        const { start, end } = diagnostic.range;
+
+        physicalLocation.region = {
+            startLine: start.line,
+            startColumn: start.column,
+            endLine: end.line,
+            endColumn: end.column,
+            charOffset: start.offset,
+            charLength: end.offset - start.offset
+        };
+    }
+
+    return [{ physicalLocation }];
+}
+
+//-----------------------------------------------------------------------------
+// Exports
+//-----------------------------------------------------------------------------
+
+/**
+ * Converts errors thrown by Momoa into a SARIF 2.1.0 log.
+ * @param {Iterable<Error|Object>} errors The errors to convert. Each may be
+ *      an error thrown by Momoa or the result of calling its `toJSON()`
+ *      method.
+ * @param {string} [options.sourceName] The file or URI to use for errors
+ *      that don't have a `sourceName`. Errors without a source name are
+ *      reported without a location.
+ * @param {string} [options.toolVersion] The version of the tool to report.
+ * @returns {Object} The SARIF log. Use `JSON.stringify()` to convert it into
+ *      text.
+ */
+export function toSarif(errors, { sourceName, toolVersion } = {}) {
+
+    const diagnostics = [...errors].map(error => (
+        typeof error.toJSON === "function" ? error.toJSON() : error
+    ));
+    const ruleIds = [...new Set(diagnostics.map(diagnostic => diagnostic.code))];
+
+    const driver = {
+        name: "momoa",
+        informationUri: INFORMATION_URI,
+        rules: ruleIds.map(id => ({ id }))
+    };
+
+    if (toolVersion) {
+        driver.version = toolVersion;
+    }
+
+    return {
+        $schema: SARIF_SCHEMA,
+        version: SARIF_VERSION,
+        runs: [
+            {
+                tool: { driver },
+                results: diagnostics.map(diagnostic => ({
+                    ruleId: diagnostic.code,
+                    ruleIndex: ruleIds.indexOf(diagnostic.code),
+                    level: LEVELS.get(diagnostic.severity) || "error",
+                    message: {
+                        text: getMessageText(diagnostic)
+                    },
+                    locations: createLocations(diagnostic, sourceName)
+                }))
+            }
+        ]
+    };
+}
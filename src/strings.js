@@ -0,0 +1,123 @@
+/**
+ * @fileoverview Helpers for JSON string literals.
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+import { escapeToChar, locateInLiteral } from "./syntax.js";
+import { UnexpectedChar, UnexpectedEOF } from "./errors.js";
+
+//-----------------------------------------------------------------------------
+// Helpers
+//-----------------------------------------------------------------------------
+
+const QUOTE = "\"";
+
+//-----------------------------------------------------------------------------
+// Exports
+//-----------------------------------------------------------------------------
+
+/**
+ * Validates a JSON string literal without tokenizing a whole document and
+ * returns the string it represents. Errors are located relative to the
+ * start of the literal.
+ * @param {string} text The string literal, including quotation marks.
+ * @returns {string} The decoded string.
+ * @throws {UnexpectedChar} When the literal contains an invalid character or
+ *      escape sequence, or anything after the closing quotation mark.
+ * @throws {UnexpectedEOF} When the literal isn't closed.
+ */
+export function validateStringLiteral(text) {
+
+    if (text.charAt(0) !== QUOTE) {
+        if (!text) {
+            throw new UnexpectedEOF(locateInLiteral(0));
+        }
+
+        throw new UnexpectedChar(text.charAt(0), locateInLiteral(0));
+    }
+
+    let value = "";
+    let i = 1;
+
+    while (i < text.length) {
+        const c = text.charAt(i);
+
+        if (c === QUOTE) {
+            if (i < text.length - 1) {
+                throw new UnexpectedChar(text.charAt(i + 1), locateInLiteral(i + 1));
+            }
+
+            return value;
+        }
+
+        // control characters must be escaped
+        if (c < " ") {
+            throw new UnexpectedChar(c, locateInLiteral(i));
+        }
+
+        if (c !== "\\") {
+            value += c;
+            i++;
+            continue;
+        }
+
+        const escapeChar = text.charAt(i + 1);
+
+        if (escapeToChar.has(escapeChar)) {
+            value += escapeToChar.get(escapeChar);
+            i += 2;
+        } else if (escapeChar === "u") {
+            for (let j = i + 2; j < i + 6; j++) {
+                if (j >= text.length) {
+                    throw new UnexpectedEOF(locateInLiteral(j));
+                }
+
+                if (!/[0-9a-f]/i.test(text.charAt(j))) {
+                    throw new UnexpectedChar(text.charAt(j), locateInLiteral(j));
+                }
+            }
+
+            value += String.fromCharCode(parseInt(text.slice(i + 2, i + 6), 16));
+            i += 6;
+        } else if (escapeChar) {
+            throw new UnexpectedChar(escapeChar, locateInLiteral(i + 1));
+        } else {
+            break;
+        }
+    }
+
+    throw new UnexpectedEOF(locateInLiteral(text.length));
+}
+
+/**
+ * Converts a JSON string literal into its canonical form, so literals that
+ * represent the same string are written the same way. In the canonical
+ * form, only quotation marks, backslashes, and control characters are
+ * escaped, and `\u` escapes use lowercase hex digits.
+ * @param {string} text The string literal, including quotation marks.
+ * @returns {string} The canonical string literal.
+ * @throws {UnexpectedChar} When the literal contains an invalid character or
+ *      escape sequence, or anything after the closing quotation mark.
+ * @throws {UnexpectedEOF} When the literal isn't closed.
+ */
+export function canonicalizeStringLiteral(text) {
+    return JSON.stringify(validateStringLiteral(text));
+}
+
+/**
+ * Determines if two JSON string literals represent the same string, such
+ * as `"\u00FF"` and `"\u00ff"` or `"\u0041"` and `"A"`.
+ * @param {string} a The first string literal, including quotation marks.
+ * @param {string} b The second string literal, including quotation marks.
+ * @returns {boolean} True if the literals represent the same string.
+ * @throws {UnexpectedChar} When a literal contains an invalid character or
+ *      escape sequence, or anything after the closing quotation mark.
+ * @throws {UnexpectedEOF} When a literal isn't closed.
+ */
+export function isEquivalentStringLiteral(a, b) {
+    return validateStringLiteral(a) === validateStringLiteral(b);
+}
@@ -0,0 +1,79 @@
+/**
+ * @fileoverview Suppression comments for errors.
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Helpers
+//-----------------------------------------------------------------------------
+
+const DEFAULT_DIRECTIVE = "momoa-expect-error";
+
+/**
+ * Gets the line on which an error starts.
+ * @param {Error|Object} error An error thrown by Momoa or the result of
+ *      calling its `toJSON()` method.
+ * @returns {int} The one-based line number.
+ */
+function getLine(error) {
+    return error.range ? error.range.start.line : error.line;
+}
+
+//-----------------------------------------------------------------------------
+// Exports
+//-----------------------------------------------------------------------------
+
+/**
+ * Separates errors that are suppressed by a comment from those that aren't.
+ * An error is suppressed when the line before the one it starts on contains
+ * only a line comment that begins with the directive, such as
+ * `// momoa-expect-error`. Because the text may not be valid JSON, comments
+ * are found line by line rather than by tokenizing.
+ * @param {Iterable<Error|Object>} errors The errors to check. Each may be an
+ *      error thrown by Momoa or the result of calling its `toJSON()` method.
+ * @param {string} text The text the errors came from.
+ * @param {string} [options.directive="momoa-expect-error"] The text that
+ *      must begin a suppression comment.
+ * @returns {Object} An object with an `errors` property containing the
+ *      errors that aren't suppressed, a `suppressed` property containing the
+ *      errors that are, and an `unused` property containing the one-based
+ *      line numbers of suppression comments that didn't suppress anything.
+ */
+export function applySuppressions(errors, text, { directive = DEFAULT_DIRECTIVE } = {}) {
+
+    const suppressedLines = new Set();
+
+    text.split(/\r\n|\r|\n/).forEach((line, i) => {
+        const match = /^\s*\/\/\s*(\S+)/.exec(line);
+
+        if (match && match[1] === directive) {
+            suppressedLines.add(i + 2);
+        }
+    });
+
+    const result = {
+        errors: [],
+        suppressed: [],
+        unused: []
+    };
+    const usedLines = new Set();
+
+    for (const error of errors) {
+        const line = getLine(error);
+
+        if (suppressedLines.has(line)) {
+            result.suppressed.push(error);
+            usedLines.add(line);
+        } else {
+            result.errors.push(error);
+        }
+    }
+
+    for (const line of suppressedLines) {
+        if (!usedLines.has(line)) {
+            result.unused.push(line - 1);
+        }
+    }
+
+    return result;
+}
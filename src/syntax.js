@@ -69,3 +69,114 @@ export const knownTokenTypes = new Map([
     [FALSE, "Boolean"],
     [NULL, "Null"]
 ]);
+
+/*
+ * Whitespace characters that JSON doesn't allow, which are often pasted
+ * from rich text, along with their Unicode names.
+ */
+export const irregularWhitespaceNames = new Map([
+    ["\u000B", "LINE TABULATION"],
+    ["\u000C", "FORM FEED"],
+    ["\u0085", "NEXT LINE"],
+    ["\u00A0", "NO-BREAK SPACE"],
+    ["\u1680", "OGHAM SPACE MARK"],
+    ["\u180E", "MONGOLIAN VOWEL SEPARATOR"],
+    ["\u2000", "EN QUAD"],
+    ["\u2001", "EM QUAD"],
+    ["\u2002", "EN SPACE"],
+    ["\u2003", "EM SPACE"],
+    ["\u2004", "THREE-PER-EM SPACE"],
+    ["\u2005", "FOUR-PER-EM SPACE"],
+    ["\u2006", "SIX-PER-EM SPACE"],
+    ["\u2007", "FIGURE SPACE"],
+    ["\u2008", "PUNCTUATION SPACE"],
+    ["\u2009", "THIN SPACE"],
+    ["\u200A", "HAIR SPACE"],
+    ["\u200B", "ZERO WIDTH SPACE"],
+    ["\u2028", "LINE SEPARATOR"],
+    ["\u2029", "PARAGRAPH SEPARATOR"],
+    ["\u202F", "NARROW NO-BREAK SPACE"],
+    ["\u205F", "MEDIUM MATHEMATICAL SPACE"],
+    ["\u3000", "IDEOGRAPHIC SPACE"],
+    ["\uFEFF", "ZERO WIDTH NO-BREAK SPACE"]
+]);
+
+/*
+ * Matches any one of the irregular whitespace characters, built from the
+ * names above so the two can't get out of sync.
+ */
+export const irregularWhitespacePattern = new RegExp("[" + [...irregularWhitespaceNames.keys()]
+    .map(c => "\\u" + c.charCodeAt(0).toString(16).padStart(4, "0"))
+    .join("") + "]");
+
+export const punctuatorKinds = new Map([
+    [LBRACKET, "LBracket"],
+    [RBRACKET, "RBracket"],
+    [LBRACE, "LBrace"],
+    [RBRACE, "RBrace"],
+    [COLON, "Colon"],
+    [COMMA, "Comma"]
+]);
+
+//-----------------------------------------------------------------------------
+// Character Checks
+//-----------------------------------------------------------------------------
+
+/**
+ * Determines if a character is whitespace that JSON allows: a space, tab,
+ * line feed, or carriage return.
+ * @param {string} c The character to check.
+ * @returns {boolean} True if the character is JSON whitespace.
+ */
+export function isWhitespace(c) {
+    return c === " " || c === "\t" || c === "\n" || c === "\r";
+}
+
+/**
+ * Determines if a character is whitespace in JavaScript but not in JSON,
+ * such as a no-break space. The tokenizer skips these unless the
+ * `strictWhitespace` option is used. Other irregular whitespace, such as
+ * U+200B, is never skipped.
+ * @param {string} c The character to check.
+ * @returns {boolean} True if the character is irregular whitespace that
+ *      the tokenizer skips.
+ */
+export function isIrregularWhitespace(c) {
+    return c === "\v" || c === "\f" || c > "\x7f" && /\s/.test(c);
+}
+
+/**
+ * Determines if a character is a decimal digit.
+ * @param {string} c The character to check.
+ * @returns {boolean} True if the character is a digit.
+ */
+export function isDigit(c) {
+    return c >= "0" && c <= "9";
+}
+
+/**
+ * Determines if a character is a hexadecimal digit.
+ * @param {string} c The character to check.
+ * @returns {boolean} True if the character is a hexadecimal digit.
+ */
+export function isHexDigit(c) {
+    return isDigit(c) || /[a-f]/i.test(c);
+}
+
+//-----------------------------------------------------------------------------
+// Locations
+//-----------------------------------------------------------------------------
+
+/**
+ * Creates a location for an offset inside of a single-line literal, such as
+ * the text passed to `validateStringLiteral()`.
+ * @param {int} offset The offset in the literal.
+ * @returns {Object} The location.
+ */
+export function locateInLiteral(offset) {
+    return {
+        line: 1,
+        column: offset + 1,
+        offset
+    };
+}
@@ -7,8 +7,9 @@
 // Imports
 //-----------------------------------------------------------------------------
 
-import { escapeToChar, expectedKeywords, knownTokenTypes } from "./syntax.js";
-import { UnexpectedChar, UnexpectedEOF } from "./errors.js";
+import { escapeToChar, expectedKeywords, irregularWhitespaceNames, knownTokenTypes, punctuatorKinds, isWhitespace, isIrregularWhitespace } from "./syntax.js";
+import { UnexpectedChar, UnexpectedEOF, LineTooLong, CommentsNotAllowed, LimitExceeded, IrregularWhitespace } from "./errors.js";
+import { createLineIndex } from "./locations.js";
 
 //-----------------------------------------------------------------------------
 // Helpers
@@ -17,34 +18,156 @@ import { UnexpectedChar, UnexpectedEOF } from "./errors.js";
 const QUOTE = "\"";
 const SLASH = "/";
 const STAR = "*";
+const RECORD_SEPARATOR = "\u001e";
+const BYTE_ORDER_MARK = "\uFEFF";
 
 const DEFAULT_OPTIONS = {
     comments: false,
-    ranges: false
+    ranges: false,
+    eof: false,
+    recordSeparators: false,
+    bom: false,
+    shebang: false,
+    strictWhitespace: false,
+    offsetsOnly: false,
+    recover: false,
+    maxLineLength: Infinity,
+    maxExponentDigits: Infinity
 };
 
-function isWhitespace(c) {
-    return /[\s\n]/.test(c);
+/*
+ * Classes of ASCII characters, looked up by character code so that checking
+ * a character doesn't need a regular expression or a chain of comparisons.
+ */
+const DIGIT = 1;
+const HEX_DIGIT = 2;
+const KEYWORD_START = 4;
+const NUMBER_START = 8;
+
+const asciiClasses = new Uint8Array(128);
+
+"0123456789".split("").forEach(c => {
+    asciiClasses[c.charCodeAt(0)] |= DIGIT | HEX_DIGIT | NUMBER_START;
+});
+
+"abcdefABCDEF".split("").forEach(c => {
+    asciiClasses[c.charCodeAt(0)] |= HEX_DIGIT;
+});
+
+"tfn".split("").forEach(c => {
+    asciiClasses[c.charCodeAt(0)] |= KEYWORD_START;
+});
+
+".-".split("").forEach(c => {
+    asciiClasses[c.charCodeAt(0)] |= NUMBER_START;
+});
+
+/**
+ * Gets the classes of a character.
+ * @param {string} c The character, or an empty string at the end of input.
+ * @returns {int} The classes, or 0 for the end of input and characters
+ *      outside of ASCII.
+ */
+function getCharClasses(c) {
+    const code = c.charCodeAt(0);
+    return code < 128 ? asciiClasses[code] : 0;
 }
 
 function isDigit(c) {
-    return c >= "0" && c <= "9";
+    return (getCharClasses(c) & DIGIT) !== 0;
 }
 
 function isHexDigit(c) {
-    return isDigit(c) || /[a-f]/i.test(c);
+    return (getCharClasses(c) & HEX_DIGIT) !== 0;
 }
 
 function isPositiveDigit(c) {
-    return c >= "1" && c <= "9";
+    return c !== "0" && isDigit(c);
 }
 
 function isKeywordStart(c) {
-    return /[tfn]/.test(c);
+    return (getCharClasses(c) & KEYWORD_START) !== 0;
 }
 
 function isNumberStart(c) {
-    return isDigit(c) || c === "." || c === "-";
+    return (getCharClasses(c) & NUMBER_START) !== 0;
+}
+
+/*
+ * The token types in the order of their numbers in `tokenizeColumnar()`.
+ */
+const columnarTypes = [
+    "Punctuator",
+    "Boolean",
+    "Null",
+    "Number",
+    "String",
+    "LineComment",
+    "BlockComment",
+    "Shebang",
+    "RecordSeparator",
+    "EOF",
+    "Invalid",
+    "BOM"
+];
+
+const columnarTypeNumbers = new Map(columnarTypes.map((type, i) => [type, i]));
+
+/**
+ * Creates the parallel arrays that `tokenizeColumnar()` fills in. The arrays
+ * start small and double in size as tokens are added.
+ * @returns {Object} An object with an `add()` method to add a token and a
+ *      `finish()` method that returns the arrays trimmed to the number of
+ *      tokens.
+ */
+function createTokenColumns() {
+
+    let length = 0;
+    let capacity = 1024;
+
+    const columns = {
+        types: new Uint8Array(capacity),
+        startOffsets: new Uint32Array(capacity),
+        startLines: new Uint32Array(capacity),
+        startColumns: new Uint32Array(capacity),
+        endOffsets: new Uint32Array(capacity),
+        endLines: new Uint32Array(capacity),
+        endColumns: new Uint32Array(capacity)
+    };
+
+    return {
+        add(type, start, end) {
+
+            if (length === capacity) {
+                capacity *= 2;
+
+                for (const key of Object.keys(columns)) {
+                    const grown = new columns[key].constructor(capacity);
+                    grown.set(columns[key]);
+                    columns[key] = grown;
+                }
+            }
+
+            columns.types[length] = columnarTypeNumbers.get(type);
+            columns.startOffsets[length] = start.offset;
+            columns.startLines[length] = start.line;
+            columns.startColumns[length] = start.column;
+            columns.endOffsets[length] = end.offset;
+            columns.endLines[length] = end.line;
+            columns.endColumns[length] = end.column;
+            length++;
+        },
+
+        finish() {
+            const result = { length, typeNames: columnarTypes };
+
+            for (const key of Object.keys(columns)) {
+                result[key] = columns[key].slice(0, length);
+            }
+
+            return result;
+        }
+    };
 }
 
 //-----------------------------------------------------------------------------
@@ -52,11 +175,20 @@ function isNumberStart(c) {
 //-----------------------------------------------------------------------------
 
 /**
- * Creates an iterator over the tokens representing the source text.
+ * Reads the tokens from the source text.
  * @param {string} text The source text to tokenize.
- * @returns {Iterator} An iterator over the tokens. 
+ * @param {Object} options The options passed to `tokenize()`.
+ * @param {boolean} partial Determines if an error stops tokenizing instead
+ *      of being thrown.
+ * @param {Object} [columns] The columns from `createTokenColumns()` to add
+ *      tokens to instead of creating token objects.
+ * @returns {Object} An object with a `tokens` property containing the tokens
+ *      read, an `error` property containing the error that stopped
+ *      tokenizing, or `null` if there wasn't one, and an `end` property
+ *      containing the location of the end of the text, or `null` if
+ *      tokenizing stopped early or lines weren't counted.
  */
-export function tokenize(text, options) {
+function readTokens(text, options, partial, columns) {
 
     options = Object.freeze({
         ...DEFAULT_OPTIONS,
@@ -68,6 +200,12 @@ export function tokenize(text, options) {
     let column = 0;
     let newLine = false;
 
+    // a \r\n is read as one character located at the \r
+    let skipLineFeed = false;
+
+    // lines are still counted when they're needed to check their length
+    const trackLines = !options.offsetsOnly || options.maxLineLength !== Infinity;
+
     const tokens = [];
 
 
@@ -83,18 +221,45 @@ export function tokenize(text, options) {
             value,
             loc: {
                 start: startLoc,
-                end: endLoc || {
+                end: endLoc || (options.offsetsOnly ? { offset: endOffset } : {
                     line: startLoc.line,
                     column: startLoc.column + value.length,
                     offset: endOffset
-                }
+                })
             },
             ...range
         };
     }
 
+    function addToken(tokenType, value, startLoc, endLoc) {
+
+        if (!columns) {
+            tokens.push(createToken(tokenType, value, startLoc, endLoc));
+            return;
+        }
+
+        columns.add(tokenType, startLoc, endLoc || {
+            line: startLoc.line,
+            column: startLoc.column + value.length,
+            offset: startLoc.offset + value.length
+        });
+    }
+
     function next() {
+        if (skipLineFeed) {
+            offset++;
+            skipLineFeed = false;
+        }
+
         let c = text.charAt(++offset);
+
+        if (c === "\r" && text.charAt(offset + 1) === "\n") {
+            skipLineFeed = true;
+        }
+
+        if (!trackLines) {
+            return c;
+        }
     
         if (newLine) {
             line++;
@@ -104,28 +269,33 @@ export function tokenize(text, options) {
             column++;
         }
 
-        if (c === "\r") {
-            newLine = true;
-
-            // if we already see a \r, just ignore upcoming \n
-            if (text.charAt(offset + 1) === "\n") {
-                offset++;
-            }
-        } else if (c === "\n") {
+        if (c === "\r" || c === "\n") {
             newLine = true;
+        } else if (column > options.maxLineLength && c) {
+            throw new LineTooLong(options.maxLineLength, { line, column, offset });
         }
 
         return c;
     }
 
     function locate() {
-        return {
+        return options.offsetsOnly ? { offset } : {
             line,
             column,
             offset
         };
     }
 
+    /**
+     * Adds the line and column to a location for an error when the
+     * `offsetsOnly` option means it only has an offset.
+     * @param {Object} loc The location.
+     * @returns {Object} The location with `line`, `column`, and `offset`.
+     */
+    function locateError(loc) {
+        return options.offsetsOnly ? createLineIndex(text).getLocation(loc.offset) : loc;
+    }
+
     function readKeyword(c) {
 
         // get the expected keyword
@@ -133,8 +303,10 @@ export function tokenize(text, options) {
 
         // check to see if it actually exists
         if (text.slice(offset, offset + value.length) === value) {
-            offset += value.length - 1;
-            column += value.length - 1;
+            for (let j = 1; j < value.length; j++) {
+                next();
+            }
+
             return { value, c: next() };
         }
 
@@ -148,33 +320,25 @@ export function tokenize(text, options) {
     }
 
     function readString(c) {
-        let value = c;
+        const start = offset;
         c = next();
 
         while (c && c !== QUOTE) {
 
             // escapes
             if (c === "\\") {
-                value += c;
                 c = next();
 
-                if (escapeToChar.has(c)) {
-                    value += c;
-                } else if (c === "u") {
-                    value += c;
+                if (c === "u") {
                     for (let i = 0; i < 4; i++) {
                         c = next();
-                        if (isHexDigit(c)) {
-                            value += c;
-                        } else {
+                        if (!isHexDigit(c)) {
                             unexpected(c);
                         }
                     }
-                } else {
+                } else if (!escapeToChar.has(c)) {
                     unexpected(c);
                 }
-            } else {
-                value += c;
             }
 
             c = next();
@@ -183,22 +347,52 @@ export function tokenize(text, options) {
         if (!c) {
             unexpectedEOF();
         }
-        
-        value += c;
+
+        // the characters read don't include the \n of a \r\n
+        const value = text.slice(start, offset + 1);
 
         return { value, c: next() };
     }
 
+    /**
+     * Reads a run of digits starting with the current character. Digits
+     * can't end a line, so the whole run is skipped at once instead of
+     * reading each digit.
+     * @returns {string} The character after the digits.
+     */
+    function readDigits() {
+        let last = offset;
+        let code = text.charCodeAt(last + 1);
 
-    function readNumber(c) {
+        while (code >= 48 && code <= 57) {
+            code = text.charCodeAt(++last + 1);
+        }
 
-        let value = "";
+        if (trackLines) {
 
-        // Number may start with a minus but not a plus
-        if (c === "-") {
+            // read one digit at a time so the error is at the first one past the limit
+            if (column + last - offset > options.maxLineLength) {
+                let c;
+
+                do {
+                    c = next();
+                } while (isDigit(c));
 
-            value += c;
+                return c;
+            }
+
+            column += last - offset;
+        }
 
+        offset = last;
+        return next();
+    }
+
+    function readNumber(c) {
+        const start = offset;
+
+        // Number may start with a minus but not a plus
+        if (c === "-") {
             c = next();
 
             // Next digit cannot be zero
@@ -210,10 +404,8 @@ export function tokenize(text, options) {
 
         // Zero must be followed by a decimal point or nothing
         if (c === "0") {
-
-            value += c;
-
             c = next();
+
             if (isDigit(c)) {
                 unexpected(c);
             }
@@ -223,40 +415,41 @@ export function tokenize(text, options) {
                 unexpected(c);
             }
 
-            do {
-                value += c;
-                c = next();
-            } while (isDigit(c));
+            c = readDigits();
         }
 
         // Decimal point may be followed by any number of digits
         if (c === ".") {
-
-            do {
-                value += c;
-                c = next();
-            } while (isDigit(c));
+            c = next();
+            requireDigit(c);
+            c = readDigits();
         }
 
         // Exponent is always last
         if (c === "e" || c === "E") {
-
-            value += c;
             c = next();
 
             if (c === "+" || c === "-") {
-                value += c;
                 c = next();
             }
 
+            requireDigit(c);
+
+            let exponentDigits = 0;
+
             while (isDigit(c)) {
-                value += c;
+
+                // stop at the first digit past the limit instead of reading the rest
+                if (++exponentDigits > options.maxExponentDigits) {
+                    throw new LimitExceeded("maxExponentDigits", options.maxExponentDigits, locateError(locate()));
+                }
+
                 c = next();
             }
         }
 
-
-        return { value, c };
+        // a number can't contain a line break, so the reader is just past its end
+        return { value: text.slice(start, offset), c };
     }
 
     /**
@@ -269,6 +462,7 @@ export function tokenize(text, options) {
      */
     function readComment(c) {
 
+        const start = offset;
         let value = c;
 
         // next character determines single- or multi-line
@@ -299,7 +493,9 @@ export function tokenize(text, options) {
                     
                     //end of comment
                     if (c === SLASH) {
-                        value += c;
+
+                        // the characters read don't include the \n of a \r\n
+                        value = text.slice(start, offset + 1);
 
                         /*
                          * The single-line comment functionality cues up the
@@ -328,7 +524,51 @@ export function tokenize(text, options) {
      * @throws {UnexpectedChar} always.
      */
     function unexpected(c) {
-        throw new UnexpectedChar(c, locate());
+        throw new UnexpectedChar(c, locateError(locate()));
+    }
+
+    /**
+     * Checks that a character is whitespace JSON allows when
+     * `strictWhitespace` is enabled. A byte order mark is still allowed at
+     * the start of the text.
+     * @param {string} c The character to check.
+     * @returns {void}
+     * @throws {IrregularWhitespace} When the character is irregular
+     *      whitespace.
+     */
+    function checkWhitespace(c) {
+        if (options.strictWhitespace && irregularWhitespaceNames.has(c) && !(c === BYTE_ORDER_MARK && offset === 0)) {
+            throw new IrregularWhitespace(c, irregularWhitespaceNames.get(c), locateError(locate()));
+        }
+    }
+
+    /**
+     * Determines if an offset is the start of the first line, which is
+     * either the start of the text or right after a byte order mark.
+     * @param {int} at The offset to check.
+     * @returns {boolean} True if the offset starts the first line.
+     */
+    function isFirstLineStart(at) {
+        return at === 0 || at === 1 && text.charAt(0) === BYTE_ORDER_MARK;
+    }
+
+    /**
+     * Checks that a character is a digit, as required after the decimal point
+     * or the exponent of a number.
+     * @param {string} c The character to check.
+     * @returns {void}
+     * @throws {UnexpectedChar} When the character isn't a digit.
+     * @throws {UnexpectedEOF} When the end of the text was reached.
+     */
+    function requireDigit(c) {
+
+        if (!c) {
+            unexpectedEOF();
+        }
+
+        if (!isDigit(c)) {
+            unexpected(c);
+        }
     }
 
     /**
@@ -337,52 +577,627 @@ export function tokenize(text, options) {
      * @throws {UnexpectedEOF} always.
      */
     function unexpectedEOF() {
-        throw new UnexpectedEOF(locate());
+        throw new UnexpectedEOF(locateError(locate()));
     }
 
     let c = next();
 
-    while (offset < text.length) {
+    /**
+     * Skips the rest of the input that couldn't be tokenized up to the next
+     * `,`, `:`, bracket, brace, or line break, which is where tokenizing
+     * resumes, and adds an `Invalid` token for the skipped text.
+     * @param {ErrorWithLocation} error The error that stopped tokenizing.
+     * @param {Object} start The location where the token being read
+     *      started.
+     * @returns {void}
+     */
+    function recoverToNextStructural(error, start) {
+
+        /*
+         * A string that isn't closed is read to the end of the input, so
+         * skipping starts again right after its opening quote.
+         */
+        if (text.charAt(start.offset) === QUOTE) {
+            offset = start.offset;
+            line = startLine;
+            column = startColumn;
+            newLine = false;
+            skipLineFeed = false;
+        }
+
+        // the character that caused the error hasn't been read yet
+        c = text.charAt(offset);
 
-        while (isWhitespace(c)) {
+        // always skip something so tokenizing can't get stuck
+        if (offset === start.offset) {
             c = next();
         }
 
-        if (!c) {
+        while (c && knownTokenTypes.get(c) !== "Punctuator" && c !== "\r" && c !== "\n") {
+            c = next();
+        }
+
+        if (columns) {
+            addToken("Invalid", "", start, locate());
+            return;
+        }
+
+        const token = createToken("Invalid", text.slice(start.offset, offset), start, locate());
+        token.error = error;
+        tokens.push(token);
+    }
+
+    // a byte order mark can only be the first character
+    if (c === BYTE_ORDER_MARK && options.bom) {
+        addToken("BOM", c, locate());
+        c = next();
+    }
+
+    let start = null;
+    let startLine = line;
+    let startColumn = column;
+
+    for (;;) {
+        try {
+            while (offset < text.length) {
+
+                start = null;
+
+                while (isWhitespace(c) || isIrregularWhitespace(c)) {
+                    checkWhitespace(c);
+                    c = next();
+                }
+
+                if (!c) {
+                    break;
+                }
+
+                start = locate();
+                startLine = line;
+                startColumn = column;
+
+                // check for easy case
+                if (knownTokenTypes.has(c)) {
+                    addToken(knownTokenTypes.get(c), c, start);
+                    c = next();
+                } else if (isKeywordStart(c)) {
+                    const result = readKeyword(c);
+                    let value = result.value;
+                    c = result.c;
+                    addToken(knownTokenTypes.get(value), value, start);
+                } else if (isNumberStart(c)) {
+                    const result = readNumber(c);
+                    let value = result.value;
+                    c = result.c;
+                    addToken("Number", value, start);
+                } else if (c === QUOTE) {
+                    const result = readString(c);
+                    let value = result.value;
+                    c = result.c;
+                    addToken("String", value, start, locate());
+                } else if (c === "#" && options.shebang && isFirstLineStart(start.offset) && text.charAt(offset + 1) === "!") {
+                    let value = "";
+
+                    do {
+                        value += c;
+                        c = next();
+                    } while (c && c !== "\r" && c !== "\n");
+
+                    addToken("Shebang", value, start, locate());
+                } else if (c === RECORD_SEPARATOR && options.recordSeparators) {
+                    addToken("RecordSeparator", c, start);
+                    c = next();
+                } else if (c === SLASH && options.comments) {
+                    const result = readComment(c);
+                    let value = result.value;
+                    c = result.c;
+                    addToken(value.startsWith("//") ? "LineComment" : "BlockComment", value, start, locate());
+                } else if (c === SLASH && (text.charAt(offset + 1) === SLASH || text.charAt(offset + 1) === STAR)) {
+                    throw new CommentsNotAllowed(locateError(start));
+                } else {
+
+                    // some irregular whitespace, such as U+200B, isn't whitespace at all
+                    checkWhitespace(c);
+                    unexpected(c);
+                }
+            }
+
             break;
+        } catch (ex) {
+
+            // a line that's too long is a limit on the input, not a token problem
+            if (options.recover && !(ex instanceof LineTooLong)) {
+                recoverToNextStructural(ex, start || locate());
+                continue;
+            }
+
+            if (!partial) {
+                throw ex;
+            }
+
+            return { tokens, error: ex, end: null };
         }
+    }
 
-        const start = locate();
+    if (options.eof) {
+        addToken("EOF", "", locate());
+    }
 
-        // check for easy case
-        if (knownTokenTypes.has(c)) {
-            tokens.push(createToken(knownTokenTypes.get(c), c, start));
-            c = next();
-        } else if (isKeywordStart(c)) {
-            const result = readKeyword(c);
-            let value = result.value;
-            c = result.c;
-            tokens.push(createToken(knownTokenTypes.get(value), value, start));
-        } else if (isNumberStart(c)) {
-            const result = readNumber(c);
-            let value = result.value;
-            c = result.c;
-            tokens.push(createToken("Number", value, start));
-        } else if (c === QUOTE) {
-            const result = readString(c);
-            let value = result.value;
-            c = result.c;
-            tokens.push(createToken("String", value, start));
-        } else if (c === SLASH && options.comments) {
-            const result = readComment(c);
-            let value = result.value;
-            c = result.c;
-            tokens.push(createToken(value.startsWith("//") ? "LineComment" : "BlockComment", value, start, locate()));
-        } else {
-            unexpected(c);
+    // the reader is now at the end, so its line and column are free
+    const end = trackLines ? { line, column, offset } : null;
+
+    return { tokens, error: null, end };
+
+}
+
+/**
+ * Creates an iterator over the tokens representing the source text.
+ * @param {string} text The source text to tokenize.
+ * @param {boolean} [options.comments=false] Determines if comments are allowed
+ *      in the JSON.
+ * @param {boolean} [options.ranges=false] Determines if tokens have a `range`
+ *      property in addition to `loc`.
+ * @param {boolean} [options.eof=false] Determines if a final `EOF` token with
+ *      an empty value is added at the end of the input.
+ * @param {boolean} [options.recover=false] Determines if an error adds an
+ *      `Invalid` token for the text up to the next `,`, `:`, bracket, brace,
+ *      or line break and tokenizing resumes there instead of the error
+ *      being thrown.
+ * @param {boolean} [options.recordSeparators=false] Determines if the
+ *      record separator character (U+001E) used by JSON text sequences
+ *      produces a `RecordSeparator` token instead of an error.
+ * @param {boolean} [options.bom=false] Determines if a byte order mark at
+ *      the start of the text produces a `BOM` token instead of being
+ *      skipped as whitespace.
+ * @param {boolean} [options.shebang=false] Determines if a first line that
+ *      starts with `#!`, such as `#!/usr/bin/env node`, produces a
+ *      `Shebang` token instead of an error.
+ *      The shebang may follow a byte order mark.
+ * @param {boolean} [options.strictWhitespace=false] Determines if
+ *      whitespace other than spaces, tabs, carriage returns, and line feeds,
+ *      such as a no-break space, causes an `IrregularWhitespace` error
+ *      naming the character instead of being skipped.
+ * @param {boolean} [options.offsetsOnly=false] Determines if token
+ *      locations only have an `offset`. Errors still have full locations.
+ * @param {int} [options.maxLineLength=Infinity] The maximum number of
+ *      characters allowed on a single line, not counting line breaks.
+ * @param {int} [options.maxExponentDigits=Infinity] The maximum number of
+ *      digits allowed in the exponent of a number.
+ * @returns {Iterator} An iterator over the tokens. 
+ * @throws {LineTooLong} When a line is longer than `maxLineLength`.
+ * @throws {LimitExceeded} When an exponent is longer than
+ *      `maxExponentDigits`.
+ * @throws {CommentsNotAllowed} When there is a comment and `comments` is
+ *      `false`.
+ * @throws {IrregularWhitespace} When there is irregular whitespace and
+ *      `strictWhitespace` is `true`.
+ */
+export function tokenize(text, options) {
+    return readTokens(text, options, false).tokens;
+}
+
+/**
+ * Tokenizes the source text up to the first error instead of throwing it.
+ * Used to parse a value that is followed by text that isn't JSON.
+ * @param {string} text The source text to tokenize.
+ * @param {Object} options The options for `tokenize()`.
+ * @returns {Object} An object with a `tokens` property containing the tokens
+ *      before the error, an `error` property containing the error, or
+ *      `null` if there wasn't one, and an `end` property containing the
+ *      location of the end of the text, which is `null` when there was an
+ *      error or when `offsetsOnly` means lines weren't counted.
+ */
+export function tokenizePartial(text, options) {
+    return readTokens(text, options, true);
+}
+
+/**
+ * Tokenizes the source text into parallel arrays, one for each token
+ * property, instead of an array of token objects. This uses much less
+ * memory for large inputs and can be loaded directly into tools that work
+ * with columns of numbers.
+ * @param {string} text The source text to tokenize.
+ * @param {Object} [options] The same options as `tokenize()`, except
+ *      `ranges` is ignored.
+ * @returns {Object} An object with a `length` property containing the
+ *      number of tokens, a `typeNames` array of token types, and these
+ *      arrays, with one item per token:
+ *
+ *      - `types`, a `Uint8Array` of indices in `typeNames`.
+ *      - `startOffsets`, `startLines`, and `startColumns`, `Uint32Array`s
+ *        with the start location of each token.
+ *      - `endOffsets`, `endLines`, and `endColumns`, `Uint32Array`s with
+ *        the end location of each token.
+ *
+ *      The text of a token can be found by slicing the source text from
+ *      its start offset to its end offset.
+ * @throws {Error} The same errors as `tokenize()`.
+ * @throws {TypeError} When the `offsetsOnly` option is used, because the
+ *      line and column arrays can't be filled in without counting lines.
+ */
+export function tokenizeColumnar(text, options) {
+
+    if (options && options.offsetsOnly) {
+        throw new TypeError("The offsetsOnly option can't be used with tokenizeColumnar().");
+    }
+
+    const columns = createTokenColumns();
+
+    readTokens(text, options, false, columns);
+    return columns.finish();
+}
+
+//-----------------------------------------------------------------------------
+// Filters
+//-----------------------------------------------------------------------------
+
+/**
+ * Determines if a token is a comment, either a `LineComment` or a
+ * `BlockComment`. Shared by the modules that filter or skip comment tokens.
+ * @param {Token} token The token to check.
+ * @returns {boolean} True if the token is a comment, false if not.
+ */
+export function isComment(token) {
+    return token.type === "LineComment" || token.type === "BlockComment";
+}
+
+/**
+ * Adapters that filter a sequence of tokens (such as the result of
+ * `tokenize()` or the `tokens` property of a document) without requiring
+ * a filter function for common cases.
+ */
+export const tokenFilters = {
+
+    /**
+     * Creates an iterator over the tokens that aren't comments.
+     * @param {Iterable<Token>} tokens The tokens to filter.
+     * @returns {Iterator<Token>} An iterator over the tokens.
+     */
+    *significant(tokens) {
+        for (const token of tokens) {
+            if (!isComment(token)) {
+                yield token;
+            }
+        }
+    },
+
+    /**
+     * Creates an iterator over the comment tokens.
+     * @param {Iterable<Token>} tokens The tokens to filter.
+     * @returns {Iterator<Token>} An iterator over the tokens.
+     */
+    *commentsOnly(tokens) {
+        for (const token of tokens) {
+            if (isComment(token)) {
+                yield token;
+            }
+        }
+    },
+
+    /**
+     * Creates an iterator over the punctuator tokens.
+     * @param {Iterable<Token>} tokens The tokens to filter.
+     * @returns {Iterator<Token>} An iterator over the tokens.
+     */
+    *punctuationOnly(tokens) {
+        for (const token of tokens) {
+            if (token.type === "Punctuator") {
+                yield token;
+            }
         }
     }
+};
+
+//-----------------------------------------------------------------------------
+// Comparisons
+//-----------------------------------------------------------------------------
+
+/**
+ * Determines if the source text of a token is exactly the given text. The
+ * comparison reads the source text in place, so no substrings are created.
+ * @param {Token} token The token to check.
+ * @param {string} text The source text the token came from.
+ * @param {string} needle The text to compare with.
+ * @returns {boolean} True if the token's source text is `needle`.
+ */
+export function tokenTextEquals(token, text, needle) {
+    const { start, end } = token.loc;
+
+    return end.offset - start.offset === needle.length &&
+        text.startsWith(needle, start.offset);
+}
+
+/**
+ * Determines if a token is the given keyword: `true`, `false`, or `null`.
+ * @param {Token} token The token to check.
+ * @param {string} keyword The keyword to compare with.
+ * @returns {boolean} True if the token is the keyword.
+ */
+export function matchesKeyword(token, keyword) {
+    return (token.type === "Boolean" || token.type === "Null") &&
+        token.value === keyword;
+}
+
+/**
+ * Gets the kind of a punctuator token so code can check for a specific
+ * punctuator by name instead of by value. Punctuator tokens keep the type
+ * `"Punctuator"`, so code that checks token types doesn't need to change.
+ * @param {Token} token The token to check.
+ * @returns {string|undefined} One of `"LBrace"`, `"RBrace"`, `"LBracket"`,
+ *      `"RBracket"`, `"Comma"`, or `"Colon"`, or `undefined` if the token
+ *      isn't a punctuator.
+ */
+export function getPunctuatorKind(token) {
+    return token.type === "Punctuator" ? punctuatorKinds.get(token.value) : undefined;
+}
+
+//-----------------------------------------------------------------------------
+// Verification
+//-----------------------------------------------------------------------------
+
+/**
+ * Formats a location as `line:column` for messages.
+ * @param {Object} location The location to format.
+ * @returns {string} The formatted location.
+ */
+function formatLineColumn(location) {
+    return `${ location.line }:${ location.column }`;
+}
+
+/**
+ * Verifies that tokens and the whitespace between them exactly cover the
+ * source text, so that tokenizer changes that cause locations to drift can
+ * be caught by running this against a collection of files. Each token must
+ * start where the previous token ends or after only whitespace, its value
+ * must match its source text, and its line, column, and `range`, when
+ * present, must match its offsets. Synthetic tokens inserted by the
+ * `tolerant` option aren't in the text and are skipped.
+ * @param {string} text The source text the tokens came from.
+ * @param {Array<Token>} tokens The tokens to verify, in order.
+ * @returns {Object|null} The first inconsistency, or `null` if there aren't
+ *      any. The inconsistency has a `kind` property of `"gap"`, `"overlap"`,
+ *      `"out-of-range"`, `"text-mismatch"`, or `"location-mismatch"`, a
+ *      `message` describing it, the `offset` where it was found, and the
+ *      `index` and `token` of the token involved, or `-1` and `null` for
+ *      text after the last token.
+ */
+export function verifyTokenCoverage(text, tokens) {
+
+    let lineIndex = null;
+    let offset = 0;
+
+    function report(kind, message, problemOffset, index) {
+        return {
+            kind,
+            message,
+            offset: problemOffset,
+            index,
+            token: index === -1 ? null : tokens[index]
+        };
+    }
+
+    function checkGap(end, index) {
+        for (let i = offset; i < end; i++) {
+            if (!isWhitespace(text[i]) && !isIrregularWhitespace(text[i])) {
+                return report("gap", `Text at offset ${ i } isn't covered by a token.`, i, index);
+            }
+        }
+
+        return null;
+    }
+
+    for (let index = 0; index < tokens.length; index++) {
+        const token = tokens[index];
+
+        if (token.synthetic) {
+            continue;
+        }
+
+        const start = token.loc.start;
+        const end = token.loc.end;
+
+        if (end.offset < start.offset || end.offset > text.length) {
+            return report("out-of-range", `Token ${ index } has offsets ${ start.offset } to ${ end.offset }, which aren't in the text.`, start.offset, index);
+        }
+
+        if (start.offset < offset) {
+            return report("overlap", `Token ${ index } starts at offset ${ start.offset } before the previous token ends at offset ${ offset }.`, start.offset, index);
+        }
+
+        const gap = checkGap(start.offset, index);
+
+        if (gap) {
+            return gap;
+        }
+
+        const source = text.slice(start.offset, end.offset);
+
+        if (token.value !== source) {
+            return report("text-mismatch", `Token ${ index } has the value ${ JSON.stringify(token.value) } but its source text is ${ JSON.stringify(source) }.`, start.offset, index);
+        }
+
+        if (token.range && (token.range[0] !== start.offset || token.range[1] !== end.offset)) {
+            return report("location-mismatch", `Token ${ index } has the range [${ token.range }] but offsets ${ start.offset } to ${ end.offset }.`, start.offset, index);
+        }
+
+        for (const location of [start, end]) {
+            if (location.line === undefined) {
+                continue;
+            }
+
+            if (!lineIndex) {
+                lineIndex = createLineIndex(text);
+            }
+
+            const expected = lineIndex.getLocation(location.offset);
+
+            if (location.line !== expected.line || location.column !== expected.column) {
+                return report("location-mismatch", `Token ${ index } has the location ${ formatLineColumn(location) } at offset ${ location.offset }, which is at ${ formatLineColumn(expected) }.`, location.offset, index);
+            }
+        }
+
+        offset = end.offset;
+    }
+
+    return checkGap(text.length, -1);
+}
+
+//-----------------------------------------------------------------------------
+// Comments
+//-----------------------------------------------------------------------------
+
+/**
+ * Gets the text inside of a comment, without the comment markers, for
+ * tools such as documentation extractors. The token itself isn't changed,
+ * so its location still describes the raw comment. For a block comment:
+ *
+ * - When `gutter` is `true` and every line after the first starts with a
+ *   `*`, as in a JSDoc-style comment, the `*` and one space after it are
+ *   removed from each line, along with any extra `*` at the start.
+ * - When `dedent` is `true`, the indentation that the lines after the first
+ *   have in common, after removing gutters, is removed, and the first line
+ *   is trimmed.
+ *
+ * Blank lines at the start and end and whitespace at the end of each line
+ * are always removed, and lines are joined with `\n`.
+ * @param {Token} token The `LineComment` or `BlockComment` token.
+ * @param {boolean} [options.gutter=true] Determines if `*` gutters are
+ *      removed.
+ * @param {boolean} [options.dedent=true] Determines if common indentation
+ *      is removed.
+ * @returns {string} The text of the comment.
+ * @throws {TypeError} When the token isn't a comment.
+ */
+export function getCommentText(token, { gutter = true, dedent = true } = {}) {
+
+    if (!isComment(token)) {
+        throw new TypeError(`Expected a comment token but found ${ token.type }.`);
+    }
+
+    if (token.type === "LineComment") {
+        const body = token.value.slice(2).trimEnd();
+        return dedent ? body.trimStart() : body;
+    }
+
+    let lines = token.value.slice(2, -2).split(/\r\n|\r|\n/);
+    const rest = lines.slice(1).filter(line => line.trim());
+
+    if (gutter && rest.length && rest.every(line => /^\s*\*/.test(line))) {
+        lines = lines.map((line, i) => {
+            if (i === 0) {
+                return line.replace(/^\*+/, "");
+            }
+
+            return line.replace(/^\s*\* ?/, "");
+        });
+    }
+
+    if (dedent && rest.length) {
+        const indent = Math.min(...lines.slice(1).filter(line => line.trim()).map(line => line.match(/^\s*/)[0].length));
+
+        lines = lines.map((line, i) => (i === 0 ? line : line.slice(indent)));
+    }
+
+    if (dedent) {
+        lines[0] = lines[0].trimStart();
+    }
+
+    lines = lines.map(line => line.trimEnd());
+
+    while (lines.length && !lines[0]) {
+        lines.shift();
+    }
+
+    while (lines.length && !lines[lines.length - 1]) {
+        lines.pop();
+    }
+
+    return lines.join("\n");
+}
+
+//-----------------------------------------------------------------------------
+// Segments
+//-----------------------------------------------------------------------------
+
+/**
+ * Gets the length of the character or escape sequence at an offset.
+ * @param {Token} token The token containing the offset.
+ * @param {string} text The source text the token came from.
+ * @param {int} offset The offset to check.
+ * @returns {int} The number of code units to keep together.
+ */
+function getUnitLength(token, text, offset) {
+
+    if (text[offset] === "\\" && token.type === "String") {
+        return text[offset + 1] === "u" ? 6 : 2;
+    }
+
+    const code = text.charCodeAt(offset);
+
+    if (code >= 0xD800 && code <= 0xDBFF && offset + 1 < token.loc.end.offset) {
+        return 2;
+    }
+
+    return 1;
+}
+
+/**
+ * Splits the source text of a token into segments no wider than a maximum
+ * width so that a long token can be wrapped for display. Segments end at
+ * line breaks, such as those in block comments, and never split an escape
+ * sequence or a surrogate pair, so a segment may only be wider than the
+ * maximum when a single escape sequence is.
+ * @param {Token} token The token to split.
+ * @param {string} text The source text the token came from.
+ * @param {int} maxWidth The maximum number of code units in a segment.
+ * @returns {Array<Object>} The segments as objects with `start` and `end`
+ *      locations, in order. An empty token has no segments.
+ * @throws {RangeError} When `maxWidth` isn't a positive integer.
+ */
+export function getTokenSegments(token, text, maxWidth) {
+
+    if (!Number.isInteger(maxWidth) || maxWidth < 1) {
+        throw new RangeError(`Maximum width must be a positive integer but was ${ maxWidth }.`);
+    }
+
+    const segments = [];
+    const endOffset = token.loc.end.offset;
+    let { line, column, offset } = token.loc.start;
+    let start = null;
+
+    function endSegment() {
+        if (start) {
+            segments.push({ start, end: { line, column, offset } });
+            start = null;
+        }
+    }
+
+    while (offset < endOffset) {
+        const c = text[offset];
+
+        if (c === "\r" || c === "\n") {
+            endSegment();
+            offset += c === "\r" && text[offset + 1] === "\n" ? 2 : 1;
+            line++;
+            column = 1;
+            continue;
+        }
+
+        const length = getUnitLength(token, text, offset);
+
+        if (start && offset + length - start.offset > maxWidth) {
+            endSegment();
+        }
+
+        if (!start) {
+            start = { line, column, offset };
+        }
+
+        offset += length;
+        column += length;
+    }
 
-    return tokens;
+    endSegment();
 
+    return segments;
 }
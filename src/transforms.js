@@ -0,0 +1,719 @@
+/**
+ * @fileoverview Source transformations that produce text edits.
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+import { tokenize, isComment } from "./tokens.js";
+import { parse } from "./parse.js";
+import { parsePointer, formatPointer, resolvePointer } from "./pointer.js";
+import { createLineIndex } from "./locations.js";
+import { inferStyle } from "./detect.js";
+
+//-----------------------------------------------------------------------------
+// Helpers
+//-----------------------------------------------------------------------------
+
+const DEFAULT_ESCAPE_OPTIONS = {
+    solidus: "unescaped",
+    hexCase: "preserve",
+    comments: false
+};
+
+/**
+ * Creates an edit that replaces a token with new text.
+ * @param {Token} token The token to replace.
+ * @param {string} text The replacement text.
+ * @returns {Object} An edit object.
+ */
+function createTokenEdit(token, text) {
+    return {
+        range: [token.loc.start.offset, token.loc.end.offset],
+        text
+    };
+}
+
+/**
+ * Parses text into a document or returns the document that was passed.
+ * @param {string|Node} textOrDocument The JSON text or a `Document` node.
+ * @param {Object} options Options to pass to `parse()`.
+ * @returns {Node} The `Document` node.
+ */
+function toDocument(textOrDocument, options) {
+    return typeof textOrDocument === "string"
+        ? parse(textOrDocument, options)
+        : textOrDocument;
+}
+
+/**
+ * Finds the object member that a JSON Pointer refers to. When an object has
+ * more than one member with the same name, the last one is used.
+ * @param {Node} document The `Document` node.
+ * @param {string|Array<string|int>} pointer The JSON Pointer or an array of
+ *      unescaped segments.
+ * @returns {Node} The `Member` node.
+ * @throws {Error} When there is no such member.
+ */
+function findMember(document, pointer) {
+
+    const path = typeof pointer === "string" ? parsePointer(pointer) : pointer;
+    const parent = path.length ? resolvePointer(document, path.slice(0, -1)) : null;
+    const name = String(path[path.length - 1]);
+    let found;
+
+    if (parent && parent.type === "Object") {
+        for (const member of parent.members) {
+            if (member.name.value === name) {
+                found = member;
+            }
+        }
+    }
+
+    if (!found) {
+        throw new Error(`No member found at "${ formatPointer(path) }".`);
+    }
+
+    return found;
+}
+
+/**
+ * Changes the quotes around the contents of a string literal, escaping the
+ * new quote character and removing escapes from the old one.
+ * @param {string} content The text between the quotes.
+ * @param {string} from The old quote character.
+ * @param {string} to The new quote character.
+ * @returns {string} The string literal with the new quotes.
+ */
+function requote(content, from, to) {
+
+    let value = "";
+
+    for (let i = 0; i < content.length; i++) {
+        const c = content[i];
+
+        if (c === "\\") {
+            const next = content[++i];
+            value += next === from ? next : c + next;
+        } else {
+            value += c === to ? "\\" + c : c;
+        }
+    }
+
+    return to + value + to;
+}
+
+/**
+ * Determines the line ending used in some text.
+ * @param {string} text The text to check.
+ * @returns {string} The first line ending found or `"\n"` if there is none.
+ */
+function detectLineEnding(text) {
+    const match = /\r\n|\r|\n/.exec(text);
+    return match ? match[0] : "\n";
+}
+
+/**
+ * Gets the offset of the start of the line containing an offset.
+ * @param {string} text The text to check.
+ * @param {int} offset The offset in the line.
+ * @returns {int} The offset of the start of the line.
+ */
+function getLineStart(text, offset) {
+    return offset - createLineIndex(text).getLocation(offset).column + 1;
+}
+
+/**
+ * Gets the whitespace at the start of the line containing an offset.
+ * @param {string} text The text to check.
+ * @param {int} offset The offset in the line.
+ * @returns {string} The whitespace at the start of the line.
+ */
+function getLineIndent(text, offset) {
+    return /^[ \t]*/.exec(text.slice(getLineStart(text, offset)))[0];
+}
+
+/**
+ * Converts a value into JSON text that fits in with the text around it.
+ * @param {*} value The value to convert.
+ * @param {string} indent One level of indentation. When empty, the value
+ *      is printed on one line.
+ * @param {string} baseIndent The indentation of the line the value is on.
+ * @param {string} lineEnding The line ending to use.
+ * @returns {string} The JSON text.
+ * @throws {TypeError} When the value can't be represented in JSON.
+ */
+function printValue(value, indent, baseIndent, lineEnding) {
+
+    const json = JSON.stringify(value, null, indent);
+
+    if (json === undefined) {
+        throw new TypeError(`Cannot represent a value of type ${ typeof value } in JSON.`);
+    }
+
+    return json.split("\n").join(lineEnding + baseIndent);
+}
+
+/**
+ * Calculates the edits needed to insert a member or element into an object
+ * or array, following the style of the container's existing children.
+ * @param {string} text The JSON text.
+ * @param {string|Array<string|int>} pointer The JSON Pointer to the
+ *      container or an array of unescaped segments.
+ * @param {string} type The expected type of the container.
+ * @param {Function} createItem A function that receives the indentation
+ *      for the new child, the indentation to use for each level, and the
+ *      children, and returns the text of the new child.
+ * @param {Object} options Options for the insertion.
+ * @returns {Array<Object>} The edits to apply.
+ * @throws {Error} When there is no container of the given type at the
+ *      pointer.
+ * @throws {RangeError} When the position is out of range.
+ */
+function insertChild(text, pointer, type, createItem, { comments = false, indent, position }) {
+
+    const document = parse(text, { comments, tokens: true });
+    const path = typeof pointer === "string" ? parsePointer(pointer) : pointer;
+    const container = resolvePointer(document, path);
+
+    if (!container || container.type !== type) {
+        throw new Error(`No ${ type.toLowerCase() } found at "${ formatPointer(path) }".`);
+    }
+
+    const children = type === "Object" ? container.members : container.elements;
+
+    if (position === undefined) {
+        position = children.length;
+    }
+
+    if (position < 0 || position > children.length) {
+        throw new RangeError(`Position ${ position } is out of range.`);
+    }
+
+    const getTokenAfter = offset => {
+        let i = document.tokens.findIndex(token => token.loc.start.offset >= offset);
+
+        while (i !== -1 && isComment(document.tokens[i])) {
+            i++;
+        }
+
+        return document.tokens[i];
+    };
+
+    const unit = typeof indent === "number" ? " ".repeat(indent) : indent === undefined ? inferStyle(text, document.tokens).indent : indent;
+    const lineEnding = detectLineEnding(text);
+    const openEnd = container.loc.start.offset + 1;
+    const closeStart = container.loc.end.offset - 1;
+
+    // empty containers
+    if (!children.length) {
+        const inner = text.slice(openEnd, closeStart);
+
+        if (container.loc.start.line < container.loc.end.line) {
+            const itemIndent = getLineIndent(text, closeStart) + unit;
+
+            return [{
+                range: [openEnd, openEnd],
+                text: lineEnding + itemIndent + createItem(itemIndent, unit, children)
+            }];
+        }
+
+        const padding = /^[ \t]*$/.test(inner) ? inner : "";
+
+        return [{
+            range: padding ? [openEnd, closeStart] : [openEnd, openEnd],
+            text: padding + createItem("", "", children) + padding
+        }];
+    }
+
+    const first = children[0];
+    const last = children[children.length - 1];
+    const isMultiline = first.loc.start.line > container.loc.start.line;
+    const itemIndent = isMultiline ? getLineIndent(text, first.loc.start.offset) : "";
+    const item = createItem(itemIndent, isMultiline ? unit : "", children);
+    const afterLast = getTokenAfter(last.loc.end.offset);
+    const hasTrailingComma = afterLast.value === ",";
+    let separator;
+
+    if (isMultiline) {
+        separator = "," + lineEnding + itemIndent;
+    } else if (children.length > 1) {
+        const comma = getTokenAfter(first.loc.end.offset);
+        separator = "," + /^[ \t]*/.exec(text.slice(comma.loc.end.offset))[0];
+    } else if (hasTrailingComma) {
+        separator = "," + /^[ \t]*/.exec(text.slice(afterLast.loc.end.offset))[0];
+    } else {
+        separator = "," + (first.loc.start.offset > openEnd ? " " : "");
+    }
+
+    // insert before an existing child
+    if (position < children.length) {
+        const start = children[position].loc.start.offset;
+
+        return [{
+            range: [start, start],
+            text: item + separator
+        }];
+    }
+
+    // insert after the last child, keeping any trailing comma at the end
+    const end = hasTrailingComma ? afterLast.loc.end.offset : last.loc.end.offset;
+
+    if (isMultiline) {
+        const lineBreak = end + text.slice(end).search(/[\r\n]/);
+
+        // comments on the same line stay with the child they follow
+        if (lineBreak >= end && lineBreak < closeStart) {
+            const edits = [{
+                range: [lineBreak, lineBreak],
+                text: separator.slice(1) + item + (hasTrailingComma ? "," : "")
+            }];
+
+            if (!hasTrailingComma) {
+                edits.unshift({ range: [end, end], text: "," });
+            }
+
+            return edits;
+        }
+    }
+
+    return [{
+        range: [end, end],
+        text: hasTrailingComma
+            ? separator.slice(1) + item + ","
+            : separator + item
+    }];
+}
+
+//-----------------------------------------------------------------------------
+// Exports
+//-----------------------------------------------------------------------------
+
+/**
+ * Applies edits to text. Each edit is an object with a `range` property,
+ * which is an array containing the start and end offsets of the text to
+ * replace, and a `text` property containing the replacement text. Edits
+ * must not overlap.
+ * @param {string} text The text to apply the edits to.
+ * @param {Array<Object>} edits The edits to apply.
+ * @returns {string} The text with the edits applied.
+ * @throws {Error} When two edits overlap.
+ */
+export function applyEdits(text, edits) {
+
+    const sortedEdits = [...edits].sort((a, b) => a.range[0] - b.range[0]);
+    let result = "";
+    let lastIndex = 0;
+
+    for (const { range: [start, end], text: replacement } of sortedEdits) {
+        if (start < lastIndex) {
+            throw new Error(`Edit at offset ${ start } overlaps a previous edit.`);
+        }
+
+        result += text.slice(lastIndex, start) + replacement;
+        lastIndex = end;
+    }
+
+    return result + text.slice(lastIndex);
+}
+
+/**
+ * Calculates the edits needed to make escape sequences in every string
+ * consistent.
+ * @param {string} text The JSON text to normalize.
+ * @param {string} [options.solidus="unescaped"] How to handle forward
+ *      slashes: `"unescaped"` to replace `\/` with `/`, `"escaped"` to
+ *      replace `/` with `\/`, or `"preserve"` to leave them alone.
+ * @param {string} [options.hexCase="preserve"] How to handle the hex digits
+ *      in `\u` escapes: `"lower"`, `"upper"`, or `"preserve"`.
+ * @param {boolean} [options.comments=false] Determines if comments are
+ *      allowed in the JSON.
+ * @returns {Array<Object>} The edits to apply, in document order.
+ * @throws {Error} When the text can't be tokenized.
+ */
+export function normalizeEscapes(text, options) {
+
+    options = {
+        ...DEFAULT_ESCAPE_OPTIONS,
+        ...options
+    };
+
+    const edits = [];
+
+    for (const token of tokenize(text, { comments: options.comments })) {
+        if (token.type !== "String") {
+            continue;
+        }
+
+        const value = token.value.replace(/\\u[0-9a-f]{4}|\\.|\//gi, match => {
+
+            if (match === "\\/") {
+                return options.solidus === "unescaped" ? "/" : match;
+            }
+
+            if (match === "/") {
+                return options.solidus === "escaped" ? "\\/" : match;
+            }
+
+            if (match.length === 6) {
+                switch (options.hexCase) {
+                case "lower":
+                    return "\\u" + match.slice(2).toLowerCase();
+                case "upper":
+                    return "\\u" + match.slice(2).toUpperCase();
+
+                // no default
+                }
+            }
+
+            return match;
+        });
+
+        if (value !== token.value) {
+            edits.push(createTokenEdit(token, value));
+        }
+    }
+
+    return edits;
+}
+
+/**
+ * Calculates the edits needed to make every string use the same quotes,
+ * such as when moving a file that uses single-quoted strings to strict
+ * JSON. The text is scanned without being parsed, so it doesn't need to be
+ * valid JSON. Strings that aren't closed on the same line are left alone.
+ * @param {string} text The text to normalize.
+ * @param {string} [options.quote="double"] The quotes to use: `"double"` or
+ *      `"single"`.
+ * @param {boolean} [options.comments=false] Determines if the text contains
+ *      comments, so quotes in comments aren't treated as strings.
+ * @returns {Array<Object>} The edits to apply, in document order.
+ * @throws {TypeError} When `quote` isn't `"double"` or `"single"`.
+ */
+export function normalizeQuotes(text, { quote = "double", comments = false } = {}) {
+
+    if (quote !== "double" && quote !== "single") {
+        throw new TypeError(`Unknown quote style "${ quote }".`);
+    }
+
+    const to = quote === "double" ? "\"" : "'";
+    const edits = [];
+    let i = 0;
+
+    while (i < text.length) {
+        const c = text[i];
+
+        if (comments && c === "/" && text[i + 1] === "/") {
+            while (i < text.length && text[i] !== "\r" && text[i] !== "\n") {
+                i++;
+            }
+        } else if (comments && c === "/" && text[i + 1] === "*") {
+            const end = text.indexOf("*/", i + 2);
+            i = end === -1 ? text.length : end + 2;
+        } else if (c === "\"" || c === "'") {
+            let end = i + 1;
+
+            while (end < text.length && text[end] !== c && text[end] !== "\r" && text[end] !== "\n") {
+                end += text[end] === "\\" ? 2 : 1;
+            }
+
+            if (text[end] === c && c !== to) {
+                edits.push({
+                    range: [i, end + 1],
+                    text: requote(text.slice(i + 1, end), c, to)
+                });
+            }
+
+            i = text[end] === c ? end + 1 : end;
+        } else {
+            i++;
+        }
+    }
+
+    return edits;
+}
+
+/**
+ * Calculates the edit needed to rename an object member. Only the member
+ * name is replaced, so the value, comments, and formatting are untouched.
+ * @param {string|Node} textOrDocument The JSON text or a `Document` node
+ *      parsed from it.
+ * @param {string|Array<string|int>} pointer The JSON Pointer to the member
+ *      or an array of unescaped segments.
+ * @param {string} newName The new name for the member.
+ * @param {boolean} [options.comments=false] Determines if comments are
+ *      allowed in the JSON. Only used when text is passed.
+ * @returns {Array<Object>} The edits to apply.
+ * @throws {Error} When there is no member at the pointer or the object
+ *      already has a member with the new name.
+ */
+export function renameKey(textOrDocument, pointer, newName, { comments = false } = {}) {
+
+    const document = toDocument(textOrDocument, { comments });
+    const member = findMember(document, pointer);
+
+    if (member.name.value === newName) {
+        return [];
+    }
+
+    const path = typeof pointer === "string" ? parsePointer(pointer) : pointer;
+    const parent = resolvePointer(document, path.slice(0, -1));
+
+    if (parent.members.some(sibling => sibling.name.value === newName)) {
+        throw new Error(`Member "${ newName }" already exists.`);
+    }
+
+    return [{
+        range: [member.name.loc.start.offset, member.name.loc.end.offset],
+        text: JSON.stringify(newName)
+    }];
+}
+
+/**
+ * Calculates the edit needed to replace a value. Only the text of the value
+ * is replaced, and objects and arrays are printed using the indentation
+ * and line endings already in the text.
+ * @param {string} text The JSON text.
+ * @param {string|Array<string|int>} pointer The JSON Pointer to the value
+ *      or an array of unescaped segments.
+ * @param {*} value The new value.
+ * @param {boolean} [options.comments=false] Determines if comments are
+ *      allowed in the JSON.
+ * @param {string|int} [options.indent] The indentation to use for objects
+ *      and arrays. Defaults to the indentation inferred by `inferStyle()`,
+ *      or no indentation if no lines are indented.
+ * @returns {Array<Object>} The edits to apply.
+ * @throws {Error} When there is no value at the pointer.
+ * @throws {TypeError} When the value can't be represented in JSON.
+ */
+export function setValue(text, pointer, value, { comments = false, indent } = {}) {
+
+    const document = parse(text, { comments, tokens: true });
+    const node = resolvePointer(document, pointer);
+
+    if (!node) {
+        const path = typeof pointer === "string" ? parsePointer(pointer) : pointer;
+        throw new Error(`No value found at "${ formatPointer(path) }".`);
+    }
+
+    if (typeof indent === "number") {
+        indent = " ".repeat(indent);
+    }
+
+    const start = node.loc.start.offset;
+
+    return [{
+        range: [start, node.loc.end.offset],
+        text: printValue(
+            value,
+            indent === undefined ? inferStyle(text, document.tokens).indent : indent,
+            getLineIndent(text, start),
+            detectLineEnding(text)
+        )
+    }];
+}
+
+/**
+ * Calculates the edits needed to insert a member into an object. The new
+ * member follows the style of the existing members: their indentation,
+ * spacing around colons and commas, and use of a trailing comma.
+ * @param {string} text The JSON text.
+ * @param {string|Array<string|int>} pointer The JSON Pointer to the object
+ *      or an array of unescaped segments.
+ * @param {string} name The name of the new member.
+ * @param {*} value The value of the new member.
+ * @param {boolean} [options.comments=false] Determines if comments are
+ *      allowed in the JSON.
+ * @param {string|int} [options.indent] The indentation to use for objects
+ *      and arrays in the value. Defaults to the indentation inferred by
+ *      `inferStyle()`.
+ * @param {int} [options.position] The index of the new member. Defaults to
+ *      after the last member.
+ * @returns {Array<Object>} The edits to apply.
+ * @throws {Error} When there is no object at the pointer or it already has
+ *      a member with the name.
+ * @throws {RangeError} When the position is out of range.
+ * @throws {TypeError} When the value can't be represented in JSON.
+ */
+export function insertMember(text, pointer, name, value, options = {}) {
+
+    return insertChild(text, pointer, "Object", (itemIndent, unit, members) => {
+
+        if (members.some(member => member.name.value === name)) {
+            throw new Error(`Member "${ name }" already exists.`);
+        }
+
+        let colon = inferStyle(text).indent ? ": " : ":";
+
+        if (members.length) {
+            const between = text.slice(members[0].name.loc.end.offset, members[0].value.loc.start.offset);
+
+            if (/^[ \t]*:[ \t]*$/.test(between)) {
+                colon = between;
+            }
+        }
+
+        return JSON.stringify(name) + colon + printValue(value, unit, itemIndent, detectLineEnding(text));
+    }, options);
+}
+
+/**
+ * Calculates the edits needed to insert an element into an array. The new
+ * element follows the style of the existing elements: their indentation,
+ * spacing after commas, and use of a trailing comma.
+ * @param {string} text The JSON text.
+ * @param {string|Array<string|int>} pointer The JSON Pointer to the array
+ *      or an array of unescaped segments.
+ * @param {*} value The new element.
+ * @param {boolean} [options.comments=false] Determines if comments are
+ *      allowed in the JSON.
+ * @param {string|int} [options.indent] The indentation to use for objects
+ *      and arrays in the value. Defaults to the indentation inferred by
+ *      `inferStyle()`.
+ * @param {int} [options.position] The index of the new element. Defaults
+ *      to after the last element.
+ * @returns {Array<Object>} The edits to apply.
+ * @throws {Error} When there is no array at the pointer.
+ * @throws {RangeError} When the position is out of range.
+ * @throws {TypeError} When the value can't be represented in JSON.
+ */
+export function insertElement(text, pointer, value, options = {}) {
+
+    return insertChild(text, pointer, "Array", (itemIndent, unit) => {
+        return printValue(value, unit, itemIndent, detectLineEnding(text));
+    }, options);
+}
+
+/**
+ * Calculates the edits needed to remove an object member or array element
+ * along with the comma that separates it from its siblings, so no dangling
+ * commas are left behind. When the member or element is on its own line,
+ * the whole line is removed.
+ * @param {string} text The JSON text.
+ * @param {string|Array<string|int>} pointer The JSON Pointer to the member
+ *      or element or an array of unescaped segments.
+ * @param {boolean} [options.comments=false] Determines if comments are
+ *      allowed in the JSON.
+ * @param {boolean} [options.removeComments=false] Determines if comments
+ *      attached to the member or element are also removed. Attached
+ *      comments are those on the lines immediately before it and those
+ *      after it on the same line.
+ * @returns {Array<Object>} The edits to apply.
+ * @throws {Error} When there is no member or element at the pointer.
+ */
+export function remove(text, pointer, { comments = false, removeComments = false } = {}) {
+
+    const document = parse(text, { comments, tokens: true });
+    const path = typeof pointer === "string" ? parsePointer(pointer) : pointer;
+    const parent = path.length ? resolvePointer(document, path.slice(0, -1)) : null;
+    let children, child;
+
+    if (parent && parent.type === "Object") {
+        children = parent.members;
+        child = findMember(document, path);
+    } else if (parent && parent.type === "Array" && /^(?:0|[1-9]\d*)$/.test(String(path[path.length - 1]))) {
+        children = parent.elements;
+        child = children[Number(path[path.length - 1])];
+    }
+
+    if (!child) {
+        throw new Error(`No member or element found at "${ formatPointer(path) }".`);
+    }
+
+    const { tokens } = document;
+    const index = children.indexOf(child);
+    const firstTokenIndex = tokens.findIndex(token => token.loc.start.offset === child.loc.start.offset);
+    let lastTokenIndex = tokens.findIndex(token => token.loc.end.offset === child.loc.end.offset);
+    const isOnlyWhitespace = value => /^[ \t]*$/.test(value);
+    const edits = [];
+
+    // find the comma after the child, if any
+    let nextIndex = lastTokenIndex + 1;
+
+    while (nextIndex < tokens.length && isComment(tokens[nextIndex])) {
+        nextIndex++;
+    }
+
+    const hasCommaAfter = tokens[nextIndex].value === ",";
+
+    if (hasCommaAfter) {
+        lastTokenIndex = nextIndex;
+    }
+
+    let start = child.loc.start.offset;
+    let end = tokens[lastTokenIndex].loc.end.offset;
+
+    // the only child takes everything between the brackets with it
+    if (children.length === 1) {
+        const openEnd = parent.loc.start.offset + 1;
+        const closeStart = parent.loc.end.offset - 1;
+        const hasComments = tokens.some(token => isComment(token) &&
+            token.loc.start.offset > openEnd && token.loc.end.offset < closeStart);
+
+        if (removeComments || !hasComments) {
+            return [{ range: [openEnd, closeStart], text: "" }];
+        }
+    }
+
+    // when the last child is removed, the comma before it must be too
+    if (!hasCommaAfter && index > 0) {
+        let previousIndex = firstTokenIndex - 1;
+
+        while (isComment(tokens[previousIndex])) {
+            previousIndex--;
+        }
+
+        const comma = tokens[previousIndex];
+
+        if (comma.loc.end.line === child.loc.start.line) {
+            start = comma.loc.start.offset;
+        } else {
+            edits.push({ range: [comma.loc.start.offset, comma.loc.end.offset], text: "" });
+        }
+    }
+
+    if (removeComments) {
+
+        // leading comments on the lines right before the child
+        for (let i = firstTokenIndex - 1; i >= 0 && isComment(tokens[i]); i--) {
+            const { start: commentStart, end: commentEnd } = tokens[i].loc;
+
+            if (commentEnd.line < tokens[i + 1].loc.start.line - 1 ||
+                !isOnlyWhitespace(text.slice(getLineStart(text, commentStart.offset), commentStart.offset))) {
+                break;
+            }
+
+            start = Math.min(start, tokens[i].loc.start.offset);
+        }
+
+        // trailing comments on the same line as the end of the child
+        for (let i = lastTokenIndex + 1; i < tokens.length && isComment(tokens[i]); i++) {
+            if (tokens[i].loc.start.line !== tokens[lastTokenIndex].loc.end.line) {
+                break;
+            }
+
+            end = tokens[i].loc.end.offset;
+        }
+    }
+
+    const lineStart = getLineStart(text, start);
+    const lineEnd = end + /[\r\n]|$/.exec(text.slice(end)).index;
+    const startsLine = isOnlyWhitespace(text.slice(lineStart, start));
+
+    if (startsLine && isOnlyWhitespace(text.slice(end, lineEnd))) {
+
+        // remove the whole line, including its line ending
+        start = lineStart;
+        end = text.startsWith("\r\n", lineEnd) ? lineEnd + 2 : Math.min(lineEnd + 1, text.length);
+    } else if (hasCommaAfter || startsLine) {
+
+        // take the space after the child too
+        end += /^[ \t]*/.exec(text.slice(end))[0].length;
+    }
+
+    edits.push({ range: [start, end], text: "" });
+
+    return edits;
+}
@@ -105,3 +105,63 @@ export function iterator(root, filter = () => true) {
 
     return traversal.filter(filter).values();
 }
+
+/**
+ * Traverses an AST from the given node, allowing nodes to be replaced. If
+ * the visitor's `enter` or `exit` method returns a node, that node replaces
+ * the visited node in its parent. A node returned from `enter` is traversed
+ * in place of the original; returning anything else keeps the original node.
+ * @param {Node} root The node to traverse from.
+ * @param {Object} visitor An object with an `enter` and `exit` method.
+ * @returns {Node} The root node, or its replacement if it was replaced.
+ */
+export function replace(root, visitor) {
+
+    /**
+     * Calls a visitor method and returns the replacement node, if any.
+     * @param {Function} method The visitor method to call.
+     * @param {Node} node The node being visited.
+     * @param {Node} parent The parent of the node being visited.
+     * @returns {Node} The replacement node or the original node.
+     */
+    function callVisitor(method, node, parent) {
+        if (typeof method === "function") {
+            const result = method.call(visitor, node, parent);
+
+            if (isNode(result)) {
+                return result;
+            }
+        }
+
+        return node;
+    }
+
+    /**
+     * Recursively visits a node.
+     * @param {Node} node The node to visit.
+     * @param {Node} parent The parent of the node to visit.
+     * @returns {Node} The node to put in place of the visited node.
+     */
+    function visitNode(node, parent) {
+
+        node = callVisitor(visitor.enter, node, parent);
+
+        for (const key of childKeys.get(node.type)) {
+            const value = node[key];
+
+            if (isObject(value)) {
+                if (Array.isArray(value)) {
+                    value.forEach((child, i) => {
+                        value[i] = visitNode(child, node);
+                    });
+                } else if (isNode(value)) {
+                    node[key] = visitNode(value, node);
+                }
+            }
+        }
+
+        return callVisitor(visitor.exit, node, parent);
+    }
+
+    return visitNode(root);
+}
@@ -0,0 +1,170 @@
+/**
+ * @fileoverview UTF-8 decoder that reports where invalid bytes are.
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+import { InvalidUtf8 } from "./errors.js";
+
+//-----------------------------------------------------------------------------
+// Helpers
+//-----------------------------------------------------------------------------
+
+// the number of code points to convert to a string at once
+const CHUNK_SIZE = 4096;
+
+/**
+ * Gets the number of continuation bytes and the smallest valid second byte
+ * and largest valid second byte for a leading byte. The limits on the second
+ * byte rule out overlong encodings, surrogates, and code points above
+ * U+10FFFF.
+ * @param {int} byte The leading byte.
+ * @returns {Array<int>|null} An array of the number of continuation bytes,
+ *      the smallest second byte, and the largest second byte, or `null` if
+ *      the byte can't start a sequence.
+ */
+function getSequenceInfo(byte) {
+
+    if (byte >= 0xc2 && byte <= 0xdf) {
+        return [1, 0x80, 0xbf];
+    }
+
+    if (byte === 0xe0) {
+        return [2, 0xa0, 0xbf];
+    }
+
+    if (byte === 0xed) {
+        return [2, 0x80, 0x9f];
+    }
+
+    if (byte >= 0xe1 && byte <= 0xef) {
+        return [2, 0x80, 0xbf];
+    }
+
+    if (byte === 0xf0) {
+        return [3, 0x90, 0xbf];
+    }
+
+    if (byte === 0xf4) {
+        return [3, 0x80, 0x8f];
+    }
+
+    if (byte >= 0xf1 && byte <= 0xf3) {
+        return [3, 0x80, 0xbf];
+    }
+
+    return null;
+}
+
+//-----------------------------------------------------------------------------
+// Exports
+//-----------------------------------------------------------------------------
+
+/**
+ * Decodes UTF-8 bytes into a string. Unlike most decoders, invalid bytes
+ * cause an error instead of being replaced with U+FFFD, and the error has
+ * the line and column where the invalid bytes would be in the text, counted
+ * the same way as `parse()`. A byte order mark is kept in the text.
+ * @param {Uint8Array} bytes The bytes to decode.
+ * @returns {string} The decoded text.
+ * @throws {InvalidUtf8} When the bytes aren't valid UTF-8.
+ */
+export function decodeUtf8(bytes) {
+
+    const parts = [];
+    let codePoints = [];
+    let offset = 0;
+    let line = 1;
+    let column = 1;
+    let previousWasCR = false;
+    let i = 0;
+
+    while (i < bytes.length) {
+        const byte = bytes[i];
+        let codePoint = byte;
+        let length = 1;
+
+        if (byte >= 0x80) {
+            const info = getSequenceInfo(byte);
+
+            if (!info) {
+                throw new InvalidUtf8({ line, column, offset }, i);
+            }
+
+            const [continuationCount, min, max] = info;
+
+            codePoint = byte & (0x3f >> continuationCount);
+
+            for (let j = 1; j <= continuationCount; j++) {
+                const next = bytes[i + j];
+
+                if (next === undefined || next < (j === 1 ? min : 0x80) || next > (j === 1 ? max : 0xbf)) {
+                    throw new InvalidUtf8({ line, column, offset }, i);
+                }
+
+                codePoint = codePoint << 6 | next & 0x3f;
+            }
+
+            length += continuationCount;
+        }
+
+        codePoints.push(codePoint);
+
+        if (codePoints.length === CHUNK_SIZE) {
+            parts.push(String.fromCodePoint(...codePoints));
+            codePoints = [];
+        }
+
+        // a \n right after a \r is part of the same line break
+        if (codePoint === 0x0a && previousWasCR) {
+            offset++;
+        } else if (codePoint === 0x0a || codePoint === 0x0d) {
+            offset++;
+            line++;
+            column = 1;
+        } else {
+            const units = codePoint > 0xffff ? 2 : 1;
+
+            offset += units;
+            column += units;
+        }
+
+        previousWasCR = codePoint === 0x0d;
+        i += length;
+    }
+
+    parts.push(String.fromCodePoint(...codePoints));
+
+    return parts.join("");
+}
+
+/**
+ * Gets the number of bytes needed to encode a string as UTF-8 without
+ * encoding it. Lone surrogates count as U+FFFD.
+ * @param {string} text The text to measure.
+ * @returns {int} The number of bytes.
+ */
+export function getUtf8Length(text) {
+
+    let length = 0;
+
+    for (let i = 0; i < text.length; i++) {
+        const code = text.charCodeAt(i);
+
+        if (code < 0x80) {
+            length += 1;
+        } else if (code < 0x800) {
+            length += 2;
+        } else if (code >= 0xd800 && code <= 0xdbff && /[\udc00-\udfff]/.test(text.charAt(i + 1))) {
+            length += 4;
+            i++;
+        } else {
+            length += 3;
+        }
+    }
+
+    return length;
+}
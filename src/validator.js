@@ -0,0 +1,411 @@
+/**
+ * @fileoverview Streaming JSON validator that doesn't build an AST.
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+import { escapeToChar, expectedKeywords, isWhitespace, isIrregularWhitespace, isDigit, isHexDigit } from "./syntax.js";
+import { UnexpectedChar, UnexpectedEOF, EmptyDocument, MismatchedBracket, CommentsNotAllowed } from "./errors.js";
+
+//-----------------------------------------------------------------------------
+// Helpers
+//-----------------------------------------------------------------------------
+
+/*
+ * The states of a number, named for the last part read. A number can only
+ * end in one of the accepting states.
+ */
+const acceptingNumberStates = new Set(["zero", "integer", "fraction", "exponent"]);
+
+/**
+ * Gets the state of a number after reading a character.
+ * @param {string} state The state before the character.
+ * @param {string} c The character.
+ * @returns {string|undefined} The next state or `undefined` if the character
+ *      can't continue the number.
+ */
+function getNextNumberState(state, c) {
+    switch (state) {
+    case "sign":
+        return c === "0" ? "zero" : isDigit(c) ? "integer" : undefined;
+
+    case "zero":
+    case "integer":
+        if (c === ".") {
+            return "dot";
+        }
+
+        if (c === "e" || c === "E") {
+            return "e";
+        }
+
+        return state === "integer" && isDigit(c) ? "integer" : undefined;
+
+    case "dot":
+        return isDigit(c) ? "fraction" : undefined;
+
+    case "fraction":
+        if (c === "e" || c === "E") {
+            return "e";
+        }
+
+        return isDigit(c) ? "fraction" : undefined;
+
+    case "e":
+        if (c === "+" || c === "-") {
+            return "exponentSign";
+        }
+
+        return isDigit(c) ? "exponent" : undefined;
+
+    case "exponentSign":
+    case "exponent":
+        return isDigit(c) ? "exponent" : undefined;
+
+    // no default
+    }
+
+    return undefined;
+}
+
+//-----------------------------------------------------------------------------
+// Exports
+//-----------------------------------------------------------------------------
+
+/**
+ * Creates a validator that checks JSON text as it arrives in chunks, such as
+ * when a proxy passes a request body through. The validator only keeps
+ * track of its position and the brackets that are open, so the text is
+ * never stored. Unlike `parse()`, the validator follows the JSON grammar
+ * strictly: trailing commas and unescaped control characters in strings
+ * are errors.
+ * @param {boolean} [options.comments=false] Determines if comments are
+ *      allowed in the JSON.
+ * @returns {Object} An object with a `push(chunk)` method to call with each
+ *      chunk of text and a `finish()` method to call after the last chunk.
+ *      Both throw an error with a location as soon as the text is known to
+ *      be invalid, and every call after an error throws the same error.
+ */
+export function createValidator({ comments = false } = {}) {
+
+    const stack = [];
+    let state = "root";
+    let lexeme = null;
+    let line = 1;
+    let column = 1;
+    let offset = 0;
+    let previousWasCR = false;
+    let error = null;
+
+    function locate() {
+        return { line, column, offset };
+    }
+
+    function fail(ex) {
+        error = ex;
+        throw ex;
+    }
+
+    function unexpected(c) {
+        fail(new UnexpectedChar(c, locate()));
+    }
+
+    function endValue() {
+        state = stack.length ? "afterValue" : "end";
+    }
+
+    function open(c) {
+        stack.push({ value: c, loc: locate() });
+        state = c === "{" ? "firstMember" : "firstElement";
+    }
+
+    function close(c) {
+        const openToken = stack.pop();
+
+        if ((openToken.value === "{") !== (c === "}")) {
+            fail(new MismatchedBracket({ value: c, loc: { start: locate() } }, { value: openToken.value, loc: { start: openToken.loc } }));
+        }
+
+        endValue();
+    }
+
+    /**
+     * Starts a value at the current character.
+     * @param {string} c The character.
+     * @returns {void}
+     */
+    function startValue(c) {
+        if (c === "\"") {
+            lexeme = { type: "string", isName: false };
+        } else if (c === "-") {
+            lexeme = { type: "number", state: "sign" };
+        } else if (isDigit(c)) {
+            lexeme = { type: "number", state: c === "0" ? "zero" : "integer" };
+        } else if (expectedKeywords.has(c)) {
+            lexeme = { type: "keyword", rest: expectedKeywords.get(c).slice(1) };
+        } else if (c === "{" || c === "[") {
+            open(c);
+        } else {
+            unexpected(c);
+        }
+    }
+
+    /**
+     * Continues the token in progress with the current character.
+     * @param {string} c The character.
+     * @returns {boolean} True if the character was used, false if the token
+     *      ended before it.
+     */
+    function continueLexeme(c) {
+        switch (lexeme.type) {
+        case "string":
+            if (lexeme.hexDigits) {
+                if (!isHexDigit(c)) {
+                    unexpected(c);
+                }
+
+                lexeme.hexDigits--;
+            } else if (lexeme.escape) {
+                if (c === "u") {
+                    lexeme.hexDigits = 4;
+                } else if (!escapeToChar.has(c)) {
+                    unexpected(c);
+                }
+
+                lexeme.escape = false;
+            } else if (c === "\\") {
+                lexeme.escape = true;
+            } else if (c === "\"") {
+                const { isName } = lexeme;
+
+                lexeme = null;
+
+                if (isName) {
+                    state = "colon";
+                } else {
+                    endValue();
+                }
+            } else if (c < " ") {
+                unexpected(c);
+            }
+            return true;
+
+        case "number": {
+            const next = getNextNumberState(lexeme.state, c);
+
+            if (next) {
+                lexeme.state = next;
+                return true;
+            }
+
+            if (!acceptingNumberStates.has(lexeme.state)) {
+                unexpected(c);
+            }
+
+            lexeme = null;
+            endValue();
+            return false;
+        }
+
+        case "keyword":
+            if (c !== lexeme.rest[0]) {
+                unexpected(c);
+            }
+
+            lexeme.rest = lexeme.rest.slice(1);
+
+            if (!lexeme.rest) {
+                lexeme = null;
+                endValue();
+            }
+            return true;
+
+        case "slash":
+            if (!comments) {
+                fail(c === "/" || c === "*" ? new CommentsNotAllowed(lexeme.loc) : new UnexpectedChar("/", lexeme.loc));
+            }
+
+            if (c === "/") {
+                lexeme = { type: "lineComment" };
+            } else if (c === "*") {
+                lexeme = { type: "blockComment", star: false };
+            } else {
+                unexpected(c);
+            }
+            return true;
+
+        case "lineComment":
+            if (c === "\n" || c === "\r") {
+                lexeme = null;
+            }
+            return true;
+
+        case "blockComment":
+            if (lexeme.star && c === "/") {
+                lexeme = null;
+            } else {
+                lexeme.star = c === "*";
+            }
+            return true;
+
+        // no default
+        }
+
+        return true;
+    }
+
+    /**
+     * Checks a character that isn't part of a token in progress.
+     * @param {string} c The character.
+     * @returns {void}
+     */
+    function step(c) {
+
+        if (lexeme && continueLexeme(c)) {
+            return;
+        }
+
+        if (isWhitespace(c) || isIrregularWhitespace(c)) {
+            return;
+        }
+
+        /*
+         * A slash is read even when comments aren't allowed so that the
+         * next character can tell a comment apart from a stray slash.
+         */
+        if (c === "/") {
+            lexeme = { type: "slash", loc: locate() };
+            return;
+        }
+
+        switch (state) {
+        case "root":
+        case "value":
+            startValue(c);
+            break;
+
+        case "firstElement":
+            if (c === "]" || c === "}") {
+                close(c);
+            } else {
+                startValue(c);
+            }
+            break;
+
+        case "firstMember":
+        case "name":
+            if (c === "\"") {
+                lexeme = { type: "string", isName: true };
+            } else if ((c === "}" || c === "]") && state === "firstMember") {
+                close(c);
+            } else {
+                unexpected(c);
+            }
+            break;
+
+        case "colon":
+            if (c !== ":") {
+                unexpected(c);
+            }
+
+            state = "value";
+            break;
+
+        case "afterValue":
+            if (c === ",") {
+                state = stack[stack.length - 1].value === "{" ? "name" : "value";
+            } else if (c === "]" || c === "}") {
+                close(c);
+            } else {
+                unexpected(c);
+            }
+            break;
+
+        default:
+            unexpected(c);
+        }
+    }
+
+    /**
+     * Moves the location past a character. A `\n` right after a `\r` is part
+     * of the same line break, even when they arrive in different chunks.
+     * @param {string} c The character.
+     * @returns {void}
+     */
+    function advance(c) {
+        offset++;
+
+        if (c === "\n" && previousWasCR) {
+            previousWasCR = false;
+            return;
+        }
+
+        previousWasCR = c === "\r";
+
+        if (c === "\n" || c === "\r") {
+            line++;
+            column = 1;
+        } else {
+            column++;
+        }
+    }
+
+    return {
+
+        /**
+         * Validates the next chunk of text.
+         * @param {string} chunk The text to validate.
+         * @returns {void}
+         * @throws {Error} When the text is known to be invalid.
+         */
+        push(chunk) {
+
+            if (error) {
+                throw error;
+            }
+
+            for (const c of chunk) {
+                step(c);
+                advance(c);
+
+                // characters outside the BMP are two columns, like in parse()
+                if (c.length === 2) {
+                    offset++;
+                    column++;
+                }
+            }
+        },
+
+        /**
+         * Validates the end of the text.
+         * @returns {void}
+         * @throws {Error} When the text ended before the value was complete.
+         */
+        finish() {
+
+            if (error) {
+                throw error;
+            }
+
+            if (lexeme && lexeme.type === "number" && acceptingNumberStates.has(lexeme.state)) {
+                lexeme = null;
+                endValue();
+            } else if (lexeme && lexeme.type === "lineComment") {
+                lexeme = null;
+            } else if (lexeme && lexeme.type === "slash" && !comments) {
+                fail(new UnexpectedChar("/", lexeme.loc));
+            }
+
+            if (state === "root" && !lexeme) {
+                fail(new EmptyDocument({ line: 1, column: 1, offset: 0 }));
+            }
+
+            if (lexeme || state !== "end") {
+                fail(new UnexpectedEOF(locate()));
+            }
+        }
+    };
+}
@@ -0,0 +1,242 @@
+/**
+ * @fileoverview Tests for analyses
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+const { parse, findDuplicateElements, checkAllowedKeys, findHexCaseMismatches, findWhitespaceProblems, findWindowsPaths, applyEdits } = require("../api");
+const { expect } = require("chai");
+
+//-----------------------------------------------------------------------------
+// Tests
+//-----------------------------------------------------------------------------
+
+describe("findDuplicateElements()", () => {
+
+    it("should return an empty array when there are no duplicates", () => {
+        const ast = parse("[\"a\", \"b\", 1, \"1\"]");
+        expect(findDuplicateElements(ast.body)).to.deep.equal([]);
+    });
+
+    it("should report each duplicate with its original", () => {
+        const ast = parse("[\n  \"lib\",\n  \"dist\",\n  \"l\\u0069b\",\n  \"lib\"\n]");
+        const elements = ast.body.elements;
+
+        expect(findDuplicateElements(ast.body)).to.deep.equal([
+            { duplicate: elements[2], duplicateIndex: 2, original: elements[0], originalIndex: 0 },
+            { duplicate: elements[3], duplicateIndex: 3, original: elements[0], originalIndex: 0 }
+        ]);
+        expect(elements[2].loc.start.line).to.equal(4);
+    });
+
+    it("should find duplicate objects regardless of member order", () => {
+        const ast = parse("[{\"a\": 1, \"b\": [2]}, {\"b\": [2], \"a\": 1}]");
+        const result = findDuplicateElements(ast.body);
+
+        expect(result.map(({ duplicateIndex, originalIndex }) => [duplicateIndex, originalIndex])).to.deep.equal([[1, 0]]);
+    });
+
+    it("should use a custom comparison when passed", () => {
+        const ast = parse("[\"Lib\", \"lib\", \"LIB\"]");
+        const result = findDuplicateElements(ast.body, {
+            compare: (a, b) => a.value.toLowerCase() === b.value.toLowerCase()
+        });
+
+        expect(result.map(({ duplicateIndex, originalIndex }) => [duplicateIndex, originalIndex])).to.deep.equal([[1, 0], [2, 0]]);
+    });
+
+    it("should throw an error when the node isn't an array", () => {
+        expect(() => {
+            findDuplicateElements(parse("{}").body);
+        }).to.throw("Duplicate elements can only be found in an Array node.");
+    });
+
+});
+
+describe("checkAllowedKeys()", () => {
+
+    it("should return members that aren't allowed in document order", () => {
+        const ast = parse("{\"name\": 1, \"nmae\": 2, \"extra\": 3}");
+        const result = checkAllowedKeys(ast, ["name", "version"]);
+
+        expect(result.map(({ name }) => name)).to.deep.equal(["nmae", "extra"]);
+        expect(result[0].member).to.equal(ast.body.members[1]);
+        expect(result[0].loc).to.deep.equal({
+            start: { line: 1, column: 13, offset: 12 },
+            end: { line: 1, column: 19, offset: 18 }
+        });
+    });
+
+    it("should return an empty array when every member is allowed", () => {
+        expect(checkAllowedKeys(parse("{\"a\": {\"b\": 1}}").body, new Set(["a"]))).to.deep.equal([]);
+    });
+
+    it("should accept a parsed list of allowed names", () => {
+        const ast = parse("{\"a\": 1, \"b\": 2}");
+        const allowed = parse("[\"b\"]");
+
+        expect(checkAllowedKeys(ast, allowed).map(({ name }) => name)).to.deep.equal(["a"]);
+        expect(checkAllowedKeys(ast, allowed.body).map(({ name }) => name)).to.deep.equal(["a"]);
+    });
+
+    it("should throw an error when the node isn't an object", () => {
+        expect(() => {
+            checkAllowedKeys(parse("[]"), ["a"]);
+        }).to.throw("Allowed keys can only be checked in an Object node.");
+    });
+
+    it("should throw an error when an allowed name isn't a string", () => {
+        expect(() => {
+            checkAllowedKeys(parse("{}"), parse("[\"a\", 1]"));
+        }).to.throw("Allowed keys must be strings.");
+    });
+
+});
+
+describe("findHexCaseMismatches()", () => {
+
+    const text = "{\n  \"\\u00ff\": \"\\u00FF\\u0041\\u00aB\"\n}";
+
+    it("should expect the case of the first escape by default", () => {
+        expect(findHexCaseMismatches(text)).to.deep.equal([
+            {
+                escape: "\\u00FF",
+                loc: {
+                    start: { line: 2, column: 14, offset: 15 },
+                    end: { line: 2, column: 20, offset: 21 }
+                }
+            },
+            {
+                escape: "\\u00aB",
+                loc: {
+                    start: { line: 2, column: 26, offset: 27 },
+                    end: { line: 2, column: 32, offset: 33 }
+                }
+            }
+        ]);
+    });
+
+    it("should report escapes that aren't uppercase when hexCase is \"upper\"", () => {
+        const escapes = findHexCaseMismatches(text, { hexCase: "upper" }).map(({ escape }) => escape);
+        expect(escapes).to.deep.equal(["\\u00ff", "\\u00aB"]);
+    });
+
+    it("should not confuse an escaped backslash with an escape", () => {
+        expect(findHexCaseMismatches("[\"\\\\u00FF\", \"\\u00ff\"]", { hexCase: "lower" })).to.deep.equal([]);
+    });
+
+    it("should skip comments when comments are enabled", () => {
+        expect(findHexCaseMismatches("// \\u00FF\n\"\\u00ff\"", { hexCase: "lower", comments: true })).to.deep.equal([]);
+    });
+
+});
+
+describe("findWhitespaceProblems()", () => {
+
+    it("should return an empty array when there are no problems", () => {
+        expect(findWhitespaceProblems("{\n  \"a\": [\n    1\n  ]\n}\n")).to.deep.equal([]);
+    });
+
+    it("should find irregular whitespace between tokens", () => {
+        expect(findWhitespaceProblems("{\"a\":\u00a01,\n\"b\":\u200b2}")).to.deep.equal([
+            {
+                kind: "irregular-whitespace",
+                text: "\u00a0",
+                loc: {
+                    start: { line: 1, column: 6, offset: 5 },
+                    end: { line: 1, column: 7, offset: 6 }
+                }
+            },
+            {
+                kind: "irregular-whitespace",
+                text: "\u200b",
+                loc: {
+                    start: { line: 2, column: 5, offset: 13 },
+                    end: { line: 2, column: 6, offset: 14 }
+                }
+            }
+        ]);
+    });
+
+    it("should allow a byte order mark only at the start of the text", () => {
+        const problems = findWhitespaceProblems("\ufeff[1,\ufeff2]");
+
+        expect(problems).to.have.lengthOf(1);
+        expect(problems[0].loc.start.offset).to.equal(4);
+    });
+
+    it("should only check strings when strings is true", () => {
+        const text = "[\"a\\\"\u00a0b\"]";
+
+        expect(findWhitespaceProblems(text)).to.deep.equal([]);
+        expect(findWhitespaceProblems(text, { strings: true }).map(({ loc }) => loc.start.offset)).to.deep.equal([5]);
+    });
+
+    it("should check comments without treating quotes in them as strings", () => {
+        const text = "// \"quoted\n[1, /* \u3000 */\u00a02]";
+        const problems = findWhitespaceProblems(text, { comments: true });
+
+        expect(problems.map(({ loc }) => loc.start.offset)).to.deep.equal([18, 22]);
+    });
+
+    it("should report indentation that doesn't match the first indented line", () => {
+        const text = "{\n  \"a\": 1,\n\t\"b\": 2,\n \t\"c\": 3\n}";
+
+        expect(findWhitespaceProblems(text).map(({ kind, text, loc }) => [kind, text, loc.start.line])).to.deep.equal([
+            ["indentation", "\t", 3],
+            ["indentation", " \t", 4]
+        ]);
+    });
+
+    it("should report indentation that doesn't use the expected character", () => {
+        const text = "{\n\t\"a\": 1,\n  \"b\": 2\n}";
+
+        expect(findWhitespaceProblems(text, { indentation: "tabs" }).map(({ loc }) => loc.start.line)).to.deep.equal([3]);
+        expect(findWhitespaceProblems(text, { indentation: "spaces" }).map(({ loc }) => loc.start.line)).to.deep.equal([2]);
+    });
+
+});
+
+describe("findWindowsPaths()", () => {
+
+    it("should find a path whose escapes are valid but change the value", () => {
+        const text = "{\n  \"dir\": \"C:\\temp\"\n}";
+        const result = findWindowsPaths(text);
+
+        expect(result).to.deep.equal([{
+            text: "\"C:\\temp\"",
+            loc: {
+                start: { line: 2, column: 10, offset: 11 },
+                end: { line: 2, column: 19, offset: 20 }
+            },
+            fix: {
+                message: "Escape the backslashes in the path.",
+                range: [11, 20],
+                text: "\"C:\\\\temp\""
+            }
+        }]);
+        expect(parse(applyEdits(text, [result[0].fix])).body.members[0].value.value).to.equal("C:\\temp");
+    });
+
+    it("should find a path with invalid escapes", () => {
+        const text = "[\"D:\\Users\\me\\\\x\", \"ok\"]";
+        const [{ fix }] = findWindowsPaths(text);
+
+        expect(applyEdits(text, [fix])).to.equal("[\"D:\\\\Users\\\\me\\\\x\", \"ok\"]");
+    });
+
+    it("should not report paths that are already escaped or strings that aren't paths", () => {
+        expect(findWindowsPaths("[\"C:\\\\temp\", \"a\\tb\", \"C:/temp\"]")).to.deep.equal([]);
+    });
+
+    it("should skip comments when comments are enabled", () => {
+        const text = "/* \"C:\\temp\"\n*/ // \"C:\\temp\"\n\"E:\\new\"";
+        const result = findWindowsPaths(text, { comments: true });
+
+        expect(result.map(({ text: literal, loc }) => [literal, loc.start.line])).to.deep.equal([["\"E:\\new\"", 3]]);
+    });
+
+});
@@ -0,0 +1,147 @@
+/**
+ * @fileoverview Tests for binary converters
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+const { toCBOR, toMessagePack } = require("../binary");
+const { parse } = require("../api");
+const { expect } = require("chai");
+
+//-----------------------------------------------------------------------------
+// Helpers
+//-----------------------------------------------------------------------------
+
+/**
+ * Converts bytes to a hex string.
+ * @param {Uint8Array} bytes The bytes to convert.
+ * @returns {string} The hex string.
+ */
+function toHex(bytes) {
+    return Array.from(bytes, byte => byte.toString(16).padStart(2, "0")).join("");
+}
+
+//-----------------------------------------------------------------------------
+// Tests
+//-----------------------------------------------------------------------------
+
+describe("toCBOR()", () => {
+
+    // examples from RFC 8949, Appendix A, except for floats that fit in 16 bits
+    [
+        ["0", "00"],
+        ["23", "17"],
+        ["24", "1818"],
+        ["100", "1864"],
+        ["1000", "1903e8"],
+        ["1000000", "1a000f4240"],
+        ["1000000000000", "1b000000e8d4a51000"],
+        ["-1", "20"],
+        ["-100", "3863"],
+        ["-1000", "3903e7"],
+        ["1.1", "fb3ff199999999999a"],
+        ["1.5", "fa3fc00000"],
+        ["100000.5", "fa47c35040"],
+        ["-0", "fa80000000"],
+        ["12345678901234567890", "fb43e56a95319d63e1"],
+        ["false", "f4"],
+        ["true", "f5"],
+        ["null", "f6"],
+        ["\"\"", "60"],
+        ["\"IETF\"", "6449455446"],
+        ["\"\\u00fc\"", "62c3bc"],
+        ["\"\\u6c34\"", "63e6b0b4"],
+        ["\"\\ud800\\udd51\"", "64f0908591"],
+        ["[]", "80"],
+        ["[1, 2, 3]", "83010203"],
+        ["{}", "a0"],
+        ["{\"a\": 1, \"b\": [2, 3]}", "a26161016162820203"],
+        ["[1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,17,18,19,20,21,22,23,24,25]", "98190102030405060708090a0b0c0d0e0f101112131415161718181819"]
+    ].forEach(([text, hex]) => {
+        it(`should convert ${text}`, () => {
+            expect(toHex(toCBOR(parse(text)))).to.equal(hex);
+        });
+    });
+
+    it("should keep numbers with a fraction as floats when the text is passed", () => {
+        const text = "[1.0, 1e2, 3]";
+        const ast = parse(text);
+
+        expect(toHex(toCBOR(ast))).to.equal("83011864" + "03");
+        expect(toHex(toCBOR(ast, { text }))).to.equal("83fa3f800000fa42c8000003");
+    });
+
+    it("should use the last value of a duplicate member", () => {
+        expect(toHex(toCBOR(parse("{\"a\": 1, \"b\": 2, \"a\": 3}")))).to.equal("a2616103616202");
+    });
+
+    it("should convert a node inside a document", () => {
+        expect(toHex(toCBOR(parse("{\"a\": [true]}").body.members[0].value))).to.equal("81f5");
+    });
+
+    it("should convert deeply nested values without recursion", () => {
+        const depth = 100000;
+        const bytes = toCBOR(parse("[".repeat(depth) + "{\"a\": 1}" + "]".repeat(depth)));
+
+        expect(bytes.length).to.equal(depth + 4);
+        expect(toHex(bytes.slice(depth))).to.equal("a1616101");
+    });
+
+    it("should throw an error for an empty document", () => {
+        expect(() => toCBOR(parse("", { allowEmpty: true }))).to.throw("Cannot convert an empty document.");
+    });
+
+    it("should throw an error for a Member node", () => {
+        expect(() => toCBOR(parse("{\"a\": 1}").body.members[0])).to.throw("Cannot convert an object member outside of an object.");
+    });
+});
+
+describe("toMessagePack()", () => {
+
+    [
+        ["0", "00"],
+        ["127", "7f"],
+        ["200", "ccc8"],
+        ["1000", "cd03e8"],
+        ["70000", "ce00011170"],
+        ["4294967296", "cf0000000100000000"],
+        ["-1", "ff"],
+        ["-32", "e0"],
+        ["-33", "d0df"],
+        ["-200", "d1ff38"],
+        ["-40000", "d2ffff63c0"],
+        ["-2147483649", "d3ffffffff7fffffff"],
+        ["1.5", "ca3fc00000"],
+        ["1.1", "cb3ff199999999999a"],
+        ["false", "c2"],
+        ["true", "c3"],
+        ["null", "c0"],
+        ["\"a\"", "a161"],
+        ["\"\\u00fc\"", "a2c3bc"],
+        ["\"" + "a".repeat(32) + "\"", "d920" + "61".repeat(32)],
+        ["[]", "90"],
+        ["[1, [2]]", "920191" + "02"],
+        ["{\"a\": null}", "81a161c0"],
+        ["[" + "0,".repeat(15) + "0]", "dc0010" + "00".repeat(16)]
+    ].forEach(([text, hex]) => {
+        it(`should convert ${text.length > 20 ? text.slice(0, 20) + "..." : text}`, () => {
+            expect(toHex(toMessagePack(parse(text)))).to.equal(hex);
+        });
+    });
+
+    it("should keep numbers with a fraction as floats when the text is passed", () => {
+        const text = "[1.0, 2]";
+
+        expect(toHex(toMessagePack(parse(text), { text }))).to.equal("92ca3f80000002");
+    });
+
+    it("should use a 16-bit length for a long map", () => {
+        const members = Array.from({ length: 16 }, (_, i) => `"${ i.toString(16) }": 0`);
+        const bytes = toMessagePack(parse(`{${ members.join(",") }}`));
+
+        expect(toHex(bytes.slice(0, 6))).to.equal("de0010a13000");
+    });
+});
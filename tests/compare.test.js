@@ -0,0 +1,138 @@
+/**
+ * @fileoverview Tests for semantic comparison
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+const { parse, isEquivalent, compareNodes, fromValue, types: t } = require("../api");
+const { expect } = require("chai");
+
+//-----------------------------------------------------------------------------
+// Data
+//-----------------------------------------------------------------------------
+
+const equivalent = [
+    ["1", "1"],
+    ["1", "1.0"],
+    ["100", "1e2"],
+    ["\"a\"", "\"\\u0061\""],
+    ["\"/\"", "\"\\/\""],
+    ["null", "null"],
+    ["[1, [true]]", "[ 1,\n [ true ] ]"],
+    ["{\"a\": 1, \"b\": 2}", "{\"b\": 2, \"a\": 1}"],
+    ["{\"a\": 1, \"a\": 2}", "{\"a\": 2}"]
+];
+
+const different = [
+    ["1", "2"],
+    ["1", "\"1\""],
+    ["true", "false"],
+    ["null", "false"],
+    ["[1, 2]", "[2, 1]"],
+    ["[1]", "[1, 1]"],
+    ["{\"a\": 1}", "{\"a\": 1, \"b\": 2}"],
+    ["{\"a\": 1}", "{\"b\": 1}"],
+    ["{\"a\": 1}", "{\"a\": \"1\"}"]
+];
+
+//-----------------------------------------------------------------------------
+// Tests
+//-----------------------------------------------------------------------------
+
+describe("isEquivalent()", () => {
+
+    for (const [a, b] of equivalent) {
+        it(`should return true for ${ a } and ${ b }`, () => {
+            expect(isEquivalent(parse(a), parse(b))).to.be.true;
+            expect(isEquivalent(parse(b), parse(a))).to.be.true;
+        });
+    }
+
+    for (const [a, b] of different) {
+        it(`should return false for ${ a } and ${ b }`, () => {
+            expect(isEquivalent(parse(a), parse(b))).to.be.false;
+            expect(isEquivalent(parse(b), parse(a))).to.be.false;
+        });
+    }
+
+    it("should compare a document to a value node", () => {
+        expect(isEquivalent(parse("[1]"), t.array([t.number(1)]))).to.be.true;
+        expect(isEquivalent(t.array([t.number(1)]), parse("[1]"))).to.be.true;
+    });
+
+    it("should compare empty documents", () => {
+        const empty = () => parse("", { allowEmpty: true });
+
+        expect(isEquivalent(empty(), empty())).to.be.true;
+        expect(isEquivalent(empty(), parse("null"))).to.be.false;
+        expect(isEquivalent(parse("null"), empty())).to.be.false;
+    });
+
+    it("should compare deeply nested values without overflowing the stack", () => {
+        const depth = 200000;
+        const text = "[".repeat(depth) + "]".repeat(depth);
+
+        expect(isEquivalent(parse(text), parse(text))).to.be.true;
+        expect(isEquivalent(parse(text), parse("[".repeat(depth) + "1" + "]".repeat(depth)))).to.be.false;
+    });
+
+});
+
+describe("compareNodes()", () => {
+
+    it("should use the default policy", () => {
+        expect(compareNodes(parse("-0"), parse("0"))).to.deep.equal({
+            equivalent: true,
+            policy: { negativeZero: "equal", numberForms: "equal" }
+        });
+    });
+
+    it("should treat -0 and 0 as different when negativeZero is distinct", () => {
+        const result = compareNodes(parse("[-0]"), parse("[0.0]"), { negativeZero: "distinct" });
+
+        expect(result.equivalent).to.be.false;
+        expect(result.policy.negativeZero).to.equal("distinct");
+        expect(compareNodes(parse("-0"), parse("-0e1"), { negativeZero: "distinct" }).equivalent).to.be.true;
+    });
+
+    it("should treat integers and other number forms as different when numberForms is distinct", () => {
+        const options = texts => ({ numberForms: "distinct", sources: texts });
+        const check = (a, b) => compareNodes(parse(a), parse(b), options([a, b])).equivalent;
+
+        expect(check("1", "1.0")).to.be.false;
+        expect(check("1", "1e0")).to.be.false;
+        expect(check("1.0", "1e0")).to.be.true;
+        expect(check("{\"a\": [-5]}", "{ \"a\": [ -5 ] }")).to.be.true;
+    });
+
+    it("should use the value of numbers without a source location", () => {
+        const a = "[1, 1.5]";
+
+        expect(compareNodes(parse(a), fromValue([1, 1.5]), { numberForms: "distinct", sources: [a] }).equivalent).to.be.true;
+        expect(compareNodes(t.number(1), fromValue(1), { numberForms: "distinct" }).equivalent).to.be.true;
+    });
+
+    it("should throw an error when a needed source is missing", () => {
+        expect(() => {
+            compareNodes(parse("1"), parse("1"), { numberForms: "distinct" });
+        }).to.throw(TypeError, "Number forms can only be compared with the source text of each node.");
+    });
+
+    it("should throw an error for an unknown policy", () => {
+        expect(() => {
+            compareNodes(parse("1"), parse("1"), { negativeZero: "same" });
+        }).to.throw(TypeError, "Unknown negativeZero policy \"same\".");
+
+        expect(() => {
+            compareNodes(parse("1"), parse("1"), { numberForms: "strict" });
+        }).to.throw(TypeError, "Unknown numberForms policy \"strict\".");
+    });
+
+    it("should accept the same options in isEquivalent()", () => {
+        expect(isEquivalent(parse("-0"), parse("0"), { negativeZero: "distinct" })).to.be.false;
+    });
+
+});
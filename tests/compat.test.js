@@ -0,0 +1,229 @@
+/**
+ * @fileoverview Tests for compatibility adapters
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+const { parseValue, parseJsonc, toJsonToAst, toESTree } = require("../compat");
+const { parse, ErrorWithLocation } = require("../api");
+const { expect } = require("chai");
+
+//-----------------------------------------------------------------------------
+// Tests
+//-----------------------------------------------------------------------------
+
+describe("parseValue()", () => {
+
+    it("should return the same value as JSON.parse()", () => {
+        const text = "{ \"a\": [1, 2.5, \"b\", true, null], \"c\": {} }";
+
+        expect(parseValue(text)).to.deep.equal(JSON.parse(text));
+    });
+
+    it("should call the reviver the same way as JSON.parse()", () => {
+        const text = "{ \"a\": [1, 2], \"b\": { \"c\": 3 }, \"d\": 4 }";
+        const expected = [];
+        const actual = [];
+
+        function reviver(expectedCalls) {
+            return function(key, value) {
+                expectedCalls.push([key, JSON.stringify(value), Array.isArray(this)]);
+
+                if (key === "d") {
+                    return undefined;
+                }
+
+                return typeof value === "number" ? value * 10 : value;
+            };
+        }
+
+        const expectedValue = JSON.parse(text, reviver(expected));
+
+        expect(parseValue(text, reviver(actual))).to.deep.equal(expectedValue);
+        expect(actual).to.deep.equal(expected);
+    });
+
+    it("should throw an error with a location", () => {
+        expect(() => parseValue("[1,,]")).to.throw("Unexpected token Punctuator(,) found. Occurred while parsing element 1 of array at line 1. (1:4)");
+    });
+
+    it("should throw an error from the main entry point", () => {
+        expect(() => parseValue("[1,,]")).to.throw(ErrorWithLocation);
+    });
+});
+
+describe("parseJsonc()", () => {
+
+    it("should allow comments by default", () => {
+        const errors = [];
+
+        expect(parseJsonc("// a\n{ \"b\": 1 /* c */ }", errors)).to.deep.equal({ b: 1 });
+        expect(errors).to.deep.equal([]);
+    });
+
+    it("should add an error when comments are disallowed", () => {
+        const errors = [];
+
+        expect(parseJsonc("1 // a", errors, { disallowComments: true })).to.be.undefined;
+        expect(errors).to.have.lengthOf(1);
+        expect(errors[0].error).to.equal("CommentsNotAllowed");
+        expect(errors[0].offset).to.equal(2);
+        expect(errors[0].length).to.equal(1);
+    });
+
+    it("should add an error with no length at the end of the text", () => {
+        const errors = [];
+
+        expect(parseJsonc("[1", errors)).to.be.undefined;
+        expect(errors[0].error).to.equal("UnexpectedEOF");
+        expect(errors[0].offset).to.equal(2);
+        expect(errors[0].length).to.equal(0);
+    });
+
+    it("should only allow empty content when allowEmptyContent is true", () => {
+        const errors = [];
+
+        expect(parseJsonc(" ", errors, { allowEmptyContent: true })).to.be.undefined;
+        expect(errors).to.deep.equal([]);
+
+        parseJsonc(" ", errors);
+        expect(errors[0].error).to.equal("EmptyDocument");
+    });
+
+    it("should work without an errors array", () => {
+        expect(parseJsonc("{")).to.be.undefined;
+    });
+});
+
+describe("toJsonToAst()", () => {
+
+    it("should convert to the json-to-ast format", () => {
+        const text = "{\"a\": [1, \"x\", null]}";
+        const result = toJsonToAst(parse(text), text, { source: "a.json" });
+
+        function loc(start, end) {
+            return {
+                start: { line: 1, column: start + 1, offset: start },
+                end: { line: 1, column: end + 1, offset: end },
+                source: "a.json"
+            };
+        }
+
+        expect(result).to.deep.equal({
+            type: "Object",
+            children: [
+                {
+                    type: "Property",
+                    key: { type: "Identifier", value: "a", raw: "\"a\"", loc: loc(1, 4) },
+                    value: {
+                        type: "Array",
+                        children: [
+                            { type: "Literal", value: 1, raw: "1", loc: loc(7, 8) },
+                            { type: "Literal", value: "x", raw: "\"x\"", loc: loc(10, 13) },
+                            { type: "Literal", value: null, raw: "null", loc: loc(15, 19) }
+                        ],
+                        loc: loc(6, 20)
+                    },
+                    loc: loc(1, 20)
+                }
+            ],
+            loc: loc(0, 21)
+        });
+    });
+
+    it("should use a null source by default", () => {
+        expect(toJsonToAst(parse("true"), "true").loc.source).to.be.null;
+    });
+
+    it("should throw an error for an empty document", () => {
+        expect(() => toJsonToAst(parse("", { allowEmpty: true }), "")).to.throw("Cannot convert an empty document.");
+    });
+});
+
+describe("toESTree()", () => {
+
+    function loc(startColumn, endColumn, line = 1) {
+        return {
+            start: { line, column: startColumn },
+            end: { line, column: endColumn }
+        };
+    }
+
+    it("should convert a document to a Program with an ExpressionStatement", () => {
+        const text = "{\"a\": [-1, null]}";
+        const program = toESTree(parse(text), text);
+
+        expect(program.type).to.equal("Program");
+        expect(program.sourceType).to.equal("script");
+        expect(program.range).to.deep.equal([0, 17]);
+        expect(program.body).to.have.lengthOf(1);
+        expect(program.body[0].type).to.equal("ExpressionStatement");
+
+        const property = program.body[0].expression.properties[0];
+
+        expect(property).to.include({ type: "Property", kind: "init", computed: false, method: false, shorthand: false, start: 1, end: 16 });
+        expect(property.key).to.deep.equal({ type: "Literal", value: "a", raw: "\"a\"", start: 1, end: 4, range: [1, 4], loc: loc(1, 4) });
+        expect(property.value.type).to.equal("ArrayExpression");
+        expect(property.value.elements[1]).to.deep.equal({ type: "Literal", value: null, raw: "null", start: 11, end: 15, range: [11, 15], loc: loc(11, 15) });
+    });
+
+    it("should convert a negative number to a UnaryExpression", () => {
+        const text = "-2.5";
+        const { expression } = toESTree(parse(text), text).body[0];
+
+        expect(expression).to.deep.equal({
+            type: "UnaryExpression",
+            operator: "-",
+            prefix: true,
+            argument: { type: "Literal", value: 2.5, raw: "2.5", start: 1, end: 4, range: [1, 4], loc: loc(1, 4) },
+            start: 0,
+            end: 4,
+            range: [0, 4],
+            loc: loc(0, 4)
+        });
+    });
+
+    it("should convert tokens and comments to the format ESLint expects", () => {
+        const text = "// a\n[-1, /* b */ \"c\"]";
+        const program = toESTree(parse(text, { tokens: true, comments: true }), text);
+
+        expect(program.comments).to.deep.equal([
+            { type: "Line", value: " a", start: 0, end: 4, range: [0, 4], loc: loc(0, 4) },
+            { type: "Block", value: " b ", start: 10, end: 17, range: [10, 17], loc: loc(5, 12, 2) }
+        ]);
+        expect(program.tokens.map(token => [token.type, token.value])).to.deep.equal([
+            ["Punctuator", "["],
+            ["Punctuator", "-"],
+            ["Numeric", "1"],
+            ["Punctuator", ","],
+            ["String", "\"c\""],
+            ["Punctuator", "]"]
+        ]);
+        expect(program.tokens[2].loc).to.deep.equal(loc(2, 3, 2));
+    });
+
+    it("should leave out tokens that aren't JavaScript syntax", () => {
+        const text = "\uFEFF#!/usr/bin/env node\n[1]";
+        const program = toESTree(parse(text, { tokens: true, bom: true, shebang: true, eof: true }), text);
+
+        expect(program.tokens.map(token => [token.type, token.value])).to.deep.equal([
+            ["Punctuator", "["],
+            ["Numeric", "1"],
+            ["Punctuator", "]"]
+        ]);
+    });
+
+    it("should convert an empty document to an empty Program", () => {
+        const program = toESTree(parse("", { allowEmpty: true }), "");
+
+        expect(program.body).to.deep.equal([]);
+        expect(program.range).to.deep.equal([0, 0]);
+    });
+
+    it("should throw an error for a node that isn't a Document", () => {
+        expect(() => toESTree(parse("1").body, "1")).to.throw("Expected a Document node but found Number.");
+    });
+});
@@ -0,0 +1,133 @@
+/**
+ * @fileoverview Tests for detectMode() and inferStyle()
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+const { detectMode, inferStyle, parse } = require("../api");
+const { expect } = require("chai");
+
+//-----------------------------------------------------------------------------
+// Data
+//-----------------------------------------------------------------------------
+
+const json = [
+    "",
+    "true",
+    "-1.5e+10",
+    "1E-5",
+    "\"it's // not /* a comment */\"",
+    "{ \"a\": [1, 2, 3], \"b\": null }",
+    "[\"a,]\", \"b,}\"]"
+];
+
+const jsonc = [
+    "// comment\n{}",
+    "{ /* comment */ \"a\": 1 }",
+    "[1, 2, 3,]",
+    "{ \"a\": 1, }",
+    "[1, // comment\n]",
+    "[1, /* comment */ ]"
+];
+
+const json5 = [
+    "{ a: 1 }",
+    "'single'",
+    "[Infinity]",
+    "NaN",
+    "0x1F",
+    "+1",
+    ".5",
+    "5.",
+    "\"line \\\ncontinued\"",
+    "// comment\n{ unquoted: true }"
+];
+
+//-----------------------------------------------------------------------------
+// Tests
+//-----------------------------------------------------------------------------
+
+describe("detectMode()", () => {
+
+    for (const text of json) {
+        it(`should return "json" for ${ text }`, () => {
+            expect(detectMode(text)).to.equal("json");
+        });
+    }
+
+    for (const text of jsonc) {
+        it(`should return "jsonc" for ${ text }`, () => {
+            expect(detectMode(text)).to.equal("jsonc");
+        });
+    }
+
+    for (const text of json5) {
+        it(`should return "json5" for ${ text }`, () => {
+            expect(detectMode(text)).to.equal("json5");
+        });
+    }
+
+});
+
+describe("inferStyle()", () => {
+
+    it("should infer a two-space indent", () => {
+        const text = "{\n  \"a\": {\n    \"b\": [\n      1\n    ]\n  }\n}\n";
+
+        expect(inferStyle(text)).to.deep.equal({
+            indent: "  ",
+            spaceAfterColon: true,
+            trailingCommas: false,
+            lineEnding: "\n",
+            finalNewline: true
+        });
+    });
+
+    it("should infer tabs", () => {
+        const text = "{\r\n\t\"a\":[\r\n\t\t1,\r\n\t]\r\n}";
+
+        expect(inferStyle(text)).to.deep.equal({
+            indent: "\t",
+            spaceAfterColon: false,
+            trailingCommas: true,
+            lineEnding: "\r\n",
+            finalNewline: false
+        });
+    });
+
+    it("should use the most common increase in indentation", () => {
+        const text = "{\n    \"a\": 1,\n    \"b\": [1,\n            2],\n    \"c\": {\n        \"d\": 1\n    }\n}";
+        expect(inferStyle(text).indent).to.equal("    ");
+    });
+
+    it("should return an empty indent for text on one line", () => {
+        expect(inferStyle("{ \"a\": [1, 2] }")).to.deep.equal({
+            indent: "",
+            spaceAfterColon: true,
+            trailingCommas: false,
+            lineEnding: "\n",
+            finalNewline: false
+        });
+    });
+
+    it("should count lines that start with comments", () => {
+        const text = "{\n   // comment\n   \"a\": 1 /* a\n b */, \"b\": 2\n}";
+        expect(inferStyle(text).indent).to.equal("   ");
+    });
+
+    it("should not treat a comment before a closing bracket as a trailing comma", () => {
+        expect(inferStyle("[1, /* 2, */ 3]").trailingCommas).to.be.false;
+        expect(inferStyle("[1, 2, /* 3 */]").trailingCommas).to.be.true;
+    });
+
+    it("should accept the tokens of a document", () => {
+        const text = "{\n  \"a\": 1\n}";
+        const ast = parse(text, { tokens: true, eof: true });
+
+        expect(inferStyle(text, ast.tokens)).to.deep.equal(inferStyle(text));
+    });
+
+});
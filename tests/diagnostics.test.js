@@ -0,0 +1,101 @@
+/**
+ * @fileoverview Tests for diagnostics utilities
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+const { sortDiagnostics, groupDiagnosticsByLine, getMaxSeverity } = require("../api");
+const { expect } = require("chai");
+const { getError } = require("./helpers");
+
+//-----------------------------------------------------------------------------
+// Helpers
+//-----------------------------------------------------------------------------
+
+function createDiagnostic(code, line, column, offset, severity = "error") {
+    const location = { line, column, offset };
+
+    return {
+        code,
+        message: code,
+        severity,
+        range: { start: location, end: location }
+    };
+}
+
+//-----------------------------------------------------------------------------
+// Tests
+//-----------------------------------------------------------------------------
+
+describe("sortDiagnostics()", () => {
+
+    it("should sort errors and serialized errors by offset", () => {
+        const first = getError("[1 2]");
+        const second = createDiagnostic("B", 1, 5, 4);
+        const third = getError("\n\n   x");
+        const result = sortDiagnostics([third, second, first]);
+
+        expect(result).to.deep.equal([first, second, third]);
+    });
+
+    it("should keep the original order of errors at the same offset", () => {
+        const a = createDiagnostic("A", 1, 1, 0);
+        const b = createDiagnostic("B", 1, 1, 0);
+        const c = createDiagnostic("C", 1, 2, 1);
+
+        expect(sortDiagnostics([c, b, a])).to.deep.equal([b, a, c]);
+    });
+
+    it("should not change the original array", () => {
+        const errors = [createDiagnostic("B", 1, 2, 1), createDiagnostic("A", 1, 1, 0)];
+
+        sortDiagnostics(errors);
+        expect(errors[0].code).to.equal("B");
+    });
+
+});
+
+describe("groupDiagnosticsByLine()", () => {
+
+    it("should group errors by line in ascending order", () => {
+        const a = createDiagnostic("A", 3, 1, 20);
+        const b = createDiagnostic("B", 1, 5, 4);
+        const c = createDiagnostic("C", 3, 4, 23);
+        const d = getError("[1 2]");
+        const result = groupDiagnosticsByLine([c, a, b, d]);
+
+        expect([...result.keys()]).to.deep.equal([1, 3]);
+        expect(result.get(1)).to.deep.equal([d, b]);
+        expect(result.get(3)).to.deep.equal([a, c]);
+    });
+
+    it("should return an empty map for no errors", () => {
+        expect(groupDiagnosticsByLine([]).size).to.equal(0);
+    });
+
+});
+
+describe("getMaxSeverity()", () => {
+
+    it("should return the most severe severity", () => {
+        expect(getMaxSeverity([
+            createDiagnostic("A", 1, 1, 0, "info"),
+            createDiagnostic("B", 1, 1, 0, "warning")
+        ])).to.equal("warning");
+    });
+
+    it("should treat errors without a severity as errors", () => {
+        expect(getMaxSeverity([
+            createDiagnostic("A", 1, 1, 0, "warning"),
+            getError("[1 2]")
+        ])).to.equal("error");
+    });
+
+    it("should return null for no errors", () => {
+        expect(getMaxSeverity([])).to.be.null;
+    });
+
+});
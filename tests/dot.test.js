@@ -0,0 +1,83 @@
+/**
+ * @fileoverview Tests for DOT export
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+const { parse, fromValue, toDot } = require("../api");
+const { expect } = require("chai");
+
+//-----------------------------------------------------------------------------
+// Tests
+//-----------------------------------------------------------------------------
+
+describe("toDot()", () => {
+
+    it("should label nodes with their type, preview, and range", () => {
+        const result = toDot(parse("{\"a\": [1, true, null]}"));
+
+        expect(result).to.equal([
+            "digraph \"AST\" {",
+            "    node [shape=box, fontname=\"monospace\"];",
+            "    n0 [label=\"Document\\n[0, 22]\"];",
+            "    n1 [label=\"Object\\n[0, 22]\"];",
+            "    n2 [label=\"Member \\\"a\\\"\\n[1, 21]\"];",
+            "    n3 [label=\"String \\\"a\\\"\\n[1, 4]\"];",
+            "    n2 -> n3 [label=\"name\"];",
+            "    n4 [label=\"Array\\n[6, 21]\"];",
+            "    n5 [label=\"Number 1\\n[7, 8]\"];",
+            "    n4 -> n5 [label=\"0\"];",
+            "    n6 [label=\"Boolean true\\n[10, 14]\"];",
+            "    n4 -> n6 [label=\"1\"];",
+            "    n7 [label=\"Null\\n[16, 20]\"];",
+            "    n4 -> n7 [label=\"2\"];",
+            "    n2 -> n4 [label=\"value\"];",
+            "    n1 -> n2 [label=\"0\"];",
+            "    n0 -> n1 [label=\"body\"];",
+            "}"
+        ].join("\n"));
+    });
+
+    it("should escape quotes, backslashes, and line breaks in previews", () => {
+        const result = toDot(parse("\"a\\\"b\\\\c\\nd\""));
+
+        expect(result).to.include("n1 [label=\"String \\\"a\\\\\\\"b\\\\\\\\c\\\\nd\\\"\\n[0, 12]\"];");
+    });
+
+    it("should shorten long previews", () => {
+        const result = toDot(parse("\"abcdefghij\""), { previewLength: 8 });
+
+        expect(result).to.include("String \\\"abcd...\\n");
+    });
+
+    it("should use the name option as the graph name", () => {
+        const result = toDot(parse("1"), { name: "my \"doc\"" });
+
+        expect(result.split("\n")[0]).to.equal("digraph \"my \\\"doc\\\"\" {");
+    });
+
+    it("should start from any node", () => {
+        const result = toDot(parse("[1]").body.elements[0]);
+
+        expect(result).to.include("n0 [label=\"Number 1\\n[1, 2]\"];");
+        expect(result).to.not.include("n1");
+    });
+
+    it("should leave out the range of nodes with synthetic locations", () => {
+        const result = toDot(fromValue([true]));
+
+        expect(result).to.include("n0 [label=\"Document\"];");
+        expect(result).to.include("n2 [label=\"Boolean true\"];");
+    });
+
+    it("should convert an empty document", () => {
+        const result = toDot(parse("", { allowEmpty: true }));
+
+        expect(result).to.include("n0 [label=\"Document\\n[0, 0]\"];");
+        expect(result).to.not.include("->");
+    });
+
+});
@@ -0,0 +1,307 @@
+/**
+ * @fileoverview Tests for editor helpers
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+const { parse, tokenize, getDocumentSymbols, getFoldingRanges, getSelectionRange, getHoverInfo, getMatchingBracket } = require("../api");
+const { expect } = require("chai");
+
+//-----------------------------------------------------------------------------
+// Tests
+//-----------------------------------------------------------------------------
+
+describe("getDocumentSymbols()", () => {
+
+    it("should return no symbols for a scalar document", () => {
+        expect(getDocumentSymbols(parse("true"))).to.deep.equal([]);
+    });
+
+    it("should return no symbols for an empty document", () => {
+        expect(getDocumentSymbols(parse("", { allowEmpty: true }))).to.deep.equal([]);
+    });
+
+    it("should return a hierarchy of symbols for members and elements", () => {
+        const text = `{
+    "name": "momoa",
+    "files": ["api.js"]
+}`;
+
+        expect(getDocumentSymbols(parse(text))).to.deep.equal([
+            {
+                name: "name",
+                kind: 15,
+                range: {
+                    start: { line: 1, character: 4 },
+                    end: { line: 1, character: 19 }
+                },
+                selectionRange: {
+                    start: { line: 1, character: 4 },
+                    end: { line: 1, character: 10 }
+                },
+                children: []
+            },
+            {
+                name: "files",
+                kind: 18,
+                range: {
+                    start: { line: 2, character: 4 },
+                    end: { line: 2, character: 23 }
+                },
+                selectionRange: {
+                    start: { line: 2, character: 4 },
+                    end: { line: 2, character: 11 }
+                },
+                children: [
+                    {
+                        name: "0",
+                        kind: 15,
+                        range: {
+                            start: { line: 2, character: 14 },
+                            end: { line: 2, character: 22 }
+                        },
+                        selectionRange: {
+                            start: { line: 2, character: 14 },
+                            end: { line: 2, character: 22 }
+                        },
+                        children: []
+                    }
+                ]
+            }
+        ]);
+    });
+
+    it("should use the kind of each value", () => {
+        const symbols = getDocumentSymbols(parse("[\"a\", 1, true, null, [], {}]"));
+        expect(symbols.map(symbol => symbol.kind)).to.deep.equal([15, 16, 17, 21, 18, 19]);
+    });
+
+    it("should name a member with an empty name \"\"", () => {
+        const symbols = getDocumentSymbols(parse("{\"\": 1}"));
+        expect(symbols[0].name).to.equal("\"\"");
+    });
+
+});
+
+describe("getFoldingRanges()", () => {
+
+    const text = `{
+    /*
+     * Comment
+     */
+    "a": [
+        1,
+        2
+    ],
+    "b": { "c": [] },
+    "d": {
+    }
+}`;
+
+    it("should return ranges for multiline objects, arrays, and block comments", () => {
+        const ast = parse(text, { tokens: true, comments: true });
+
+        expect(getFoldingRanges(ast)).to.deep.equal([
+            { startLine: 0, endLine: 11 },
+            { startLine: 1, endLine: 3, kind: "comment" },
+            { startLine: 4, endLine: 7 },
+            { startLine: 9, endLine: 10 }
+        ]);
+    });
+
+    it("should accept an array of tokens", () => {
+        const tokens = tokenize(text, { comments: true });
+        expect(getFoldingRanges(tokens)).to.deep.equal(getFoldingRanges(parse(text, { tokens: true, comments: true })));
+    });
+
+    it("should not fold single-line block comments", () => {
+        const tokens = tokenize("/* one */ [\n]", { comments: true });
+        expect(getFoldingRanges(tokens)).to.deep.equal([{ startLine: 0, endLine: 1 }]);
+    });
+
+    it("should throw an error when the document doesn't have tokens", () => {
+        expect(() => {
+            getFoldingRanges(parse(text, { comments: true }));
+        }).to.throw("Document must be parsed with the tokens option.");
+    });
+
+});
+
+describe("getSelectionRange()", () => {
+
+    function toList(selectionRange) {
+        const ranges = [];
+
+        while (selectionRange) {
+            const { start, end } = selectionRange.range;
+            ranges.push([start.line, start.character, end.line, end.character]);
+            selectionRange = selectionRange.parent;
+        }
+
+        return ranges;
+    }
+
+    const text = `{
+    "a": [1, 22],
+    "b": true
+}`;
+
+    it("should expand from a value through its containers to the document", () => {
+        const result = getSelectionRange(parse(text), { line: 1, character: 14 });
+
+        expect(toList(result)).to.deep.equal([
+            [1, 13, 1, 15],
+            [1, 9, 1, 16],
+            [1, 4, 1, 16],
+            [0, 0, 3, 1]
+        ]);
+    });
+
+    it("should expand from a member name to the member", () => {
+        const result = getSelectionRange(parse(text), { line: 2, character: 5 });
+
+        expect(toList(result)).to.deep.equal([
+            [2, 4, 2, 7],
+            [2, 4, 2, 13],
+            [0, 0, 3, 1]
+        ]);
+    });
+
+    it("should start from the container when the position is on punctuation", () => {
+        const result = getSelectionRange(parse(text), { line: 1, character: 12 });
+
+        expect(toList(result)).to.deep.equal([
+            [1, 9, 1, 16],
+            [1, 4, 1, 16],
+            [0, 0, 3, 1]
+        ]);
+    });
+
+    it("should return null when the position is outside of the document", () => {
+        expect(getSelectionRange(parse(text), { line: 5, character: 0 })).to.be.null;
+    });
+
+});
+
+describe("getHoverInfo()", () => {
+
+    const text = `{
+    "a/b": ["x\\u0079", 2],
+    "c": { "d": null }
+}`;
+    const ast = parse(text);
+
+    it("should describe a value inside of an array", () => {
+        const result = getHoverInfo(ast, { line: 1, character: 14 });
+        const array = ast.body.members[0].value;
+
+        expect(result).to.deep.equal({
+            node: array.elements[0],
+            pointer: "/a~1b/0",
+            preview: "\"xy\"",
+            container: array,
+            key: 0,
+            range: {
+                start: { line: 1, character: 12 },
+                end: { line: 1, character: 21 }
+            }
+        });
+    });
+
+    it("should describe the value of a member when on the member name", () => {
+        const result = getHoverInfo(ast, { line: 2, character: 5 });
+
+        expect(result.node).to.equal(ast.body.members[1].value);
+        expect(result.pointer).to.equal("/c");
+        expect(result.preview).to.equal("object with 1 member");
+        expect(result.container).to.equal(ast.body);
+        expect(result.key).to.equal("c");
+    });
+
+    it("should describe a nested null value", () => {
+        const result = getHoverInfo(ast, { line: 2, character: 18 });
+
+        expect(result.pointer).to.equal("/c/d");
+        expect(result.preview).to.equal("null");
+    });
+
+    it("should describe the document body", () => {
+        const result = getHoverInfo(ast, { line: 0, character: 0 });
+
+        expect(result.node).to.equal(ast.body);
+        expect(result.pointer).to.equal("");
+        expect(result.preview).to.equal("object with 2 members");
+        expect(result.container).to.be.null;
+        expect(result.key).to.be.null;
+    });
+
+    it("should return null when the position is outside of the document", () => {
+        expect(getHoverInfo(ast, { line: 10, character: 0 })).to.be.null;
+    });
+
+});
+
+describe("getMatchingBracket()", () => {
+
+    const text = "{\n  \"a\": [\"]\", /* } */ [1]],\n  \"b\": {}\n}";
+    const tokens = tokenize(text, { comments: true });
+
+    function range(line, character) {
+        return {
+            start: { line, character },
+            end: { line, character: character + 1 }
+        };
+    }
+
+    it("should find the closing bracket for an opening bracket", () => {
+        expect(getMatchingBracket(tokens, { line: 0, character: 0 })).to.deep.equal(range(3, 0));
+        expect(getMatchingBracket(tokens, { line: 1, character: 7 })).to.deep.equal(range(1, 24));
+    });
+
+    it("should find the opening bracket for a closing bracket", () => {
+        expect(getMatchingBracket(tokens, { line: 1, character: 25 })).to.deep.equal(range(1, 7));
+        expect(getMatchingBracket(tokens, { line: 3, character: 1 })).to.deep.equal(range(0, 0));
+    });
+
+    it("should prefer the bracket after the position when between two brackets", () => {
+        expect(getMatchingBracket(tokens, { line: 1, character: 23 })).to.deep.equal(range(1, 21));
+        expect(getMatchingBracket(tokens, { line: 1, character: 24 })).to.deep.equal(range(1, 7));
+        expect(getMatchingBracket(tokens, { line: 2, character: 8 })).to.deep.equal(range(2, 7));
+    });
+
+    it("should accept a document parsed with tokens", () => {
+        const ast = parse(text, { tokens: true, comments: true });
+        expect(getMatchingBracket(ast, { line: 2, character: 7 })).to.deep.equal(range(2, 8));
+    });
+
+    it("should return null when there is no bracket at the position", () => {
+        expect(getMatchingBracket(tokens, { line: 1, character: 4 })).to.be.null;
+        expect(getMatchingBracket(tokens, { line: 1, character: 9 })).to.be.null;
+    });
+
+    it("should return null when the bracket isn't matched", () => {
+        expect(getMatchingBracket(tokenize("[[1]"), { line: 0, character: 0 })).to.be.null;
+    });
+
+});
+
+describe("offsetsOnly locations", () => {
+
+    const text = "{\n  \"a\": [1]\n}";
+    const message = "Locations must have lines and columns.";
+    const position = { line: 1, character: 8 };
+
+    it("should throw an error instead of returning positions without lines", () => {
+        const ast = parse(text, { offsetsOnly: true, tokens: true });
+
+        expect(() => getDocumentSymbols(ast)).to.throw(TypeError, message);
+        expect(() => getFoldingRanges(ast)).to.throw(TypeError, message);
+        expect(() => getSelectionRange(ast, position)).to.throw(TypeError, message);
+        expect(() => getHoverInfo(ast, position)).to.throw(TypeError, message);
+        expect(() => getMatchingBracket(ast, position)).to.throw(TypeError, message);
+    });
+
+});
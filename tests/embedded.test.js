@@ -0,0 +1,116 @@
+/**
+ * @fileoverview Tests for parseEmbedded()
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+const { parse, parseEmbedded, evaluate } = require("../api");
+const { expect } = require("chai");
+
+//-----------------------------------------------------------------------------
+// Tests
+//-----------------------------------------------------------------------------
+
+describe("parseEmbedded()", () => {
+
+    it("should parse the value of a string node", () => {
+        const text = "{ \"payload\": \"[1, 2, 3]\" }";
+        const ast = parse(text);
+        const result = parseEmbedded(ast.body.members[0].value, text);
+
+        expect(evaluate(result)).to.deep.equal([1, 2, 3]);
+    });
+
+    it("should map locations back to the outer text", () => {
+        const text = "{ \"payload\": \"[1, 2, 3]\" }";
+        const ast = parse(text);
+        const result = parseEmbedded(ast.body.members[0].value, text);
+        const element = result.body.elements[1];
+
+        expect(element.loc).to.deep.equal({
+            start: { line: 1, column: 19, offset: 18 },
+            end: { line: 1, column: 20, offset: 19 }
+        });
+        expect(text.slice(element.loc.start.offset, element.loc.end.offset)).to.equal("2");
+    });
+
+    it("should account for escape sequences when mapping locations", () => {
+        const text = "[\n  \"{\\\"na\\u006De\\\": \\\"x\\\"}\"\n]";
+        const ast = parse(text);
+        const result = parseEmbedded(ast.body.elements[0], text);
+        const member = result.body.members[0];
+
+        expect(evaluate(result)).to.deep.equal({ name: "x" });
+        expect(text.slice(member.name.loc.start.offset, member.name.loc.end.offset)).to.equal("\\\"na\\u006De\\\"");
+        expect(text.slice(member.value.loc.start.offset, member.value.loc.end.offset)).to.equal("\\\"x\\\"");
+        expect(member.name.loc.start).to.deep.equal({ line: 2, column: 5, offset: 6 });
+    });
+
+    it("should map ranges and tokens when requested", () => {
+        const text = "\"[true]\"";
+        const result = parseEmbedded(parse(text).body, text, { ranges: true, tokens: true });
+
+        expect(result.body.range).to.deep.equal([1, 7]);
+        expect(result.body.elements[0].range).to.deep.equal([2, 6]);
+        expect(result.tokens.map(token => token.range)).to.deep.equal([[1, 2], [2, 6], [6, 7]]);
+    });
+
+    it("should map comments once whether or not tokens are requested", () => {
+        const text = "\"[1 /* a */]\"";
+        const node = parse(text).body;
+        const withoutTokens = parseEmbedded(node, text, { comments: true });
+        const withTokens = parseEmbedded(node, text, { comments: true, tokens: true });
+
+        expect(withoutTokens.comments[0].loc.start.offset).to.equal(4);
+        expect(withTokens.comments[0].loc.start.offset).to.equal(4);
+        expect(withTokens.comments[0]).to.equal(withTokens.tokens[2]);
+    });
+
+    it("should map error locations back to the outer text", () => {
+        const text = "{\n  \"payload\": \"[1 2]\"\n}";
+        const node = parse(text).body.members[0].value;
+
+        expect(() => {
+            parseEmbedded(node, text);
+        }).to.throw("Unexpected token Number(2) found. (2:18)");
+    });
+
+    it("should map every location in an error back to the outer text", () => {
+        const text = "{\n  \"payload\": \"{\\\"a\\\": [1, 2}\"\n}";
+        const node = parse(text).body.members[0].value;
+        let error;
+
+        try {
+            parseEmbedded(node, text);
+        } catch (ex) {
+            error = ex;
+        }
+
+        expect(error.message).to.equal("Mismatched closing bracket } found; array opened at 2:23. Occurred while parsing value of \"a\" at line 2. (2:28)");
+        expect(error.data).to.include({ openLine: 2, openColumn: 23 });
+        expect(error.context[0].loc.start).to.deep.equal({ line: 2, column: 16, offset: 17 });
+        expect(error.stack.split("\n")[0]).to.equal(`Error: ${ error.message }`);
+    });
+
+    it("should include the source name in errors and the document", () => {
+        const text = "[\"{}\", \"[1 2]\"]";
+        const ast = parse(text);
+
+        expect(parseEmbedded(ast.body.elements[0], text, { sourceName: "a.json" }).sourceName).to.equal("a.json");
+        expect(() => {
+            parseEmbedded(ast.body.elements[1], text, { sourceName: "a.json" });
+        }).to.throw("Unexpected token Number(2) found. (a.json:1:12)");
+    });
+
+    it("should throw an error when the node isn't a string", () => {
+        const text = "[1]";
+
+        expect(() => {
+            parseEmbedded(parse(text).body, text);
+        }).to.throw("Embedded JSON can only be parsed from a String node.");
+    });
+
+});
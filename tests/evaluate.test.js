@@ -26,7 +26,8 @@ const primitiveValues = new Map([
 const objects = [
     { foo: "bar", baz: 5, bak: true },
     { a: 1, b: 2, c: 3, d: false },
-    { items: [1, 2, 3], "foo bar": "baz" }
+    { items: [1, 2, 3], "foo bar": "baz" },
+    { "": 1, "a/b": 2, "m~n": 3, "\\/\"": 4 }
 ];
 
 const arrays = [
@@ -96,4 +97,13 @@ describe("evaluate()", () => {
 
     });
 
+    describe("Empty Documents", () => {
+
+        it("should evaluate to undefined when the document has no body", () => {
+            const result = evaluate(parse("", { allowEmpty: true }));
+            expect(result).to.be.undefined;
+        });
+
+    });
+
 });
@@ -0,0 +1,149 @@
+/**
+ * @fileoverview Tests for fromValue()
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+const { fromValue, evaluate, print, iterator } = require("../api");
+const { expect } = require("chai");
+
+//-----------------------------------------------------------------------------
+// Data
+//-----------------------------------------------------------------------------
+
+const values = [
+    true,
+    false,
+    null,
+    15,
+    -0.5,
+    "Hello world",
+    "",
+    [],
+    {},
+    { a: "b", c: 2, d: true, e: null },
+    [1, "two", [3], { four: 4 }],
+    { items: [1, 2, 3], nested: { name: "foo", flag: false } }
+];
+
+const syntheticLoc = {
+    start: { line: 0, column: 0, offset: 0 },
+    end: { line: 0, column: 0, offset: 0 }
+};
+
+//-----------------------------------------------------------------------------
+// Tests
+//-----------------------------------------------------------------------------
+
+describe("fromValue()", () => {
+
+    for (const value of values) {
+        const text = JSON.stringify(value);
+
+        it(`should evaluate ${text} back to the same value`, () => {
+            expect(evaluate(fromValue(value))).to.deep.equal(value);
+        });
+
+        it(`should print ${text} the same as JSON.stringify()`, () => {
+            expect(print(fromValue(value))).to.equal(text);
+        });
+    }
+
+    it("should return a Document node", () => {
+        const result = fromValue("hi");
+        expect(result).to.deep.equal({
+            type: "Document",
+            body: {
+                type: "String",
+                value: "hi",
+                loc: syntheticLoc
+            },
+            loc: syntheticLoc
+        });
+    });
+
+    it("should give every node a synthetic location", () => {
+        const result = fromValue({ a: [1, { b: null }] });
+
+        for (const { node } of iterator(result)) {
+            expect(node.loc).to.deep.equal(syntheticLoc);
+        }
+    });
+
+    it("should not share location objects between nodes", () => {
+        const result = fromValue([1, 2]);
+        expect(result.body.elements[0].loc).to.not.equal(result.body.elements[1].loc);
+    });
+
+    it("should throw an error when a value is not finite", () => {
+        expect(() => {
+            fromValue([NaN]);
+        }).to.throw("Cannot represent NaN in JSON.");
+    });
+
+    it("should throw an error when a value cannot be represented", () => {
+        expect(() => {
+            fromValue({ a: undefined });
+        }).to.throw("Cannot represent a value of type undefined in JSON.");
+    });
+
+    it("should throw an error when a value contains itself", () => {
+        const value = { a: [1] };
+        value.a.push(value);
+
+        expect(() => {
+            fromValue(value);
+        }).to.throw(TypeError, "Cannot represent a circular structure in JSON.");
+    });
+
+    it("should allow the same value in more than one place", () => {
+        const shared = { a: 1 };
+        expect(evaluate(fromValue([shared, { b: shared }]))).to.deep.equal([{ a: 1 }, { b: { a: 1 } }]);
+    });
+
+    it("should use the toJSON() method of a Date", () => {
+        const date = new Date(Date.UTC(2020, 0, 1));
+        expect(evaluate(fromValue({ date }))).to.deep.equal({ date: "2020-01-01T00:00:00.000Z" });
+    });
+
+    it("should pass the key to a toJSON() method", () => {
+        const value = { toJSON: key => `key:${ key }` };
+        expect(evaluate(fromValue({ a: value, b: [value] }))).to.deep.equal({ a: "key:a", b: ["key:0"] });
+    });
+
+    it("should throw an error when an object isn't a plain object", () => {
+        expect(() => {
+            fromValue({ a: new Map() });
+        }).to.throw(TypeError, "Cannot represent a Map object in JSON.");
+    });
+
+    it("should convert an object without a prototype", () => {
+        const value = Object.create(null);
+        value.a = 1;
+
+        expect(print(fromValue(value))).to.equal("{\"a\":1}");
+    });
+
+    it("should convert deeply nested values without overflowing the stack", () => {
+        const depth = 100000;
+        let value = [];
+
+        for (let i = 0; i < depth; i++) {
+            value = [value];
+        }
+
+        let node = fromValue(value).body;
+        let count = 0;
+
+        while (node.elements.length) {
+            node = node.elements[0];
+            count++;
+        }
+
+        expect(count).to.equal(depth);
+    });
+
+});
@@ -0,0 +1,159 @@
+/**
+ * @fileoverview Tests for file system helpers
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+const { parseFile, parseFiles } = require("../fs");
+const { getParseInfo, ErrorWithLocation } = require("../api");
+const fs = require("fs");
+const path = require("path");
+const { expect } = require("chai");
+
+//-----------------------------------------------------------------------------
+// Data
+//-----------------------------------------------------------------------------
+
+const filesPath = "./tests/fixtures/files";
+const validPath = path.join(filesPath, "valid.json");
+const commentsPath = path.join(filesPath, "comments.jsonc");
+const invalidPath = path.join(filesPath, "invalid.json");
+const missingPath = path.join(filesPath, "missing.json");
+const invalidUtf8Path = path.join(filesPath, "invalid-utf8.json");
+
+//-----------------------------------------------------------------------------
+// Tests
+//-----------------------------------------------------------------------------
+
+describe("parseFile()", () => {
+
+    async function getError(filePath, options) {
+        try {
+            await parseFile(filePath, options);
+        } catch (error) {
+            return error;
+        }
+
+        return null;
+    }
+
+    it("should parse the file and attach its path as the source name", async () => {
+        const ast = await parseFile(commentsPath, { comments: true });
+
+        expect(ast.body.members[0].value.value).to.equal("comments");
+        expect(ast.sourceName).to.equal(commentsPath);
+    });
+
+    it("should reject with a parse error that includes the path", async () => {
+        const error = await getError(invalidPath);
+
+        expect(error.sourceName).to.equal(invalidPath);
+        expect(error.message).to.equal(`Unexpected token Punctuator(}) found. (${ invalidPath }:3:1)`);
+    });
+
+    it("should reject with an error from the main entry point", async () => {
+        const error = await getError(invalidPath);
+
+        expect(error).to.be.instanceOf(ErrorWithLocation);
+    });
+
+    it("should return a document the main entry point has parse information for", async () => {
+        const ast = await parseFile(commentsPath, { comments: true });
+
+        expect(getParseInfo(ast).mode).to.equal("jsonc");
+    });
+
+    it("should reject with a located error when the file isn't valid UTF-8", async () => {
+        const error = await getError(invalidUtf8Path);
+
+        expect(error.code).to.equal("InvalidUtf8");
+        expect(error.byteOffset).to.equal(18);
+        expect(error.message).to.equal(`Invalid UTF-8 byte sequence found at byte 18. (${ invalidUtf8Path }:2:17)`);
+    });
+
+    it("should reject with a file system error that includes the path", async () => {
+        const error = await getError(missingPath);
+
+        expect(error.code).to.equal("ENOENT");
+        expect(error.sourceName).to.equal(missingPath);
+    });
+
+});
+
+describe("parseFiles()", () => {
+
+    it("should return results keyed by path in the order given", async () => {
+        const results = await parseFiles([invalidPath, validPath]);
+        expect([...results.keys()]).to.deep.equal([invalidPath, validPath]);
+    });
+
+    it("should read no more than 16 files at once and keep the order given", async () => {
+        const paths = [];
+
+        for (let i = 0; i < 40; i++) {
+            paths.push(i % 2 ? `${ validPath }?${ i }` : `${ missingPath }?${ i }`);
+        }
+
+        const { readFile } = fs.promises;
+        let open = 0;
+        let maxOpen = 0;
+
+        fs.promises.readFile = async filePath => {
+            open++;
+            maxOpen = Math.max(maxOpen, open);
+
+            try {
+
+                // later files finish first to check the results are reordered
+                await new Promise(resolve => setTimeout(resolve, 40 - paths.indexOf(filePath)));
+                return await readFile(filePath.replace(/\?\d+$/, ""));
+            } finally {
+                open--;
+            }
+        };
+
+        let results;
+
+        try {
+            results = await parseFiles(paths);
+        } finally {
+            fs.promises.readFile = readFile;
+        }
+
+        expect(maxOpen).to.equal(16);
+        expect([...results.keys()]).to.deep.equal(paths);
+        expect([...results.values()].map(result => result.ast ? "ast" : result.error.code)).to.deep.equal(paths.map((_, i) => (i % 2 ? "ast" : "ENOENT")));
+    });
+
+    it("should parse each file and attach its path as the source name", async () => {
+        const results = await parseFiles([validPath]);
+        const { ast } = results.get(validPath);
+
+        expect(ast.body.members[0].value.value).to.equal("valid");
+        expect(ast.sourceName).to.equal(validPath);
+    });
+
+    it("should pass options to the parser", async () => {
+        const results = await parseFiles([commentsPath], { comments: true });
+        expect(results.get(commentsPath).ast.body.members[0].value.value).to.equal("comments");
+    });
+
+    it("should return parse errors that include the path", async () => {
+        const results = await parseFiles([validPath, invalidPath]);
+        const { error } = results.get(invalidPath);
+
+        expect(results.get(validPath).ast.type).to.equal("Document");
+        expect(error.sourceName).to.equal(invalidPath);
+        expect(error.message).to.equal(`Unexpected token Punctuator(}) found. (${ invalidPath }:3:1)`);
+    });
+
+    it("should return file system errors", async () => {
+        const results = await parseFiles([missingPath]);
+        expect(results.get(missingPath).error.code).to.equal("ENOENT");
+        expect(results.get(missingPath).error.sourceName).to.equal(missingPath);
+    });
+
+});
@@ -0,0 +1,135 @@
+/**
+ * @fileoverview Tests for typed getters
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+const { parse, fromValue, getString, getNumber, getBoolean, getArray, getObject,
+    getOptionalString, getOptionalNumber, getOptionalBoolean, getOptionalArray, getOptionalObject } = require("../api");
+const { expect } = require("chai");
+
+//-----------------------------------------------------------------------------
+// Data
+//-----------------------------------------------------------------------------
+
+const text = `{
+    "server": {
+        "host": "example.com",
+        "port": 8080,
+        "secure": true
+    },
+    "paths": ["/a", "/b"]
+}`;
+
+const nullableText = `{
+    "host": "example.com",
+    "port": 8080,
+    "proxy": null,
+    "paths": ["/a"]
+}`;
+
+//-----------------------------------------------------------------------------
+// Tests
+//-----------------------------------------------------------------------------
+
+describe("typed getters", () => {
+
+    const ast = parse(text);
+
+    it("should return values of the expected type", () => {
+        expect(getString(ast, "/server/host")).to.equal("example.com");
+        expect(getNumber(ast, "/server/port")).to.equal(8080);
+        expect(getBoolean(ast, "/server/secure")).to.equal(true);
+        expect(getArray(ast, "/paths")).to.deep.equal(["/a", "/b"]);
+        expect(getObject(ast, "/server")).to.deep.equal({
+            host: "example.com",
+            port: 8080,
+            secure: true
+        });
+    });
+
+    it("should accept an array of segments", () => {
+        expect(getString(ast, ["paths", 1])).to.equal("/b");
+    });
+
+    it("should return the default value when there is no match", () => {
+        expect(getString(ast, "/server/name", "momoa")).to.equal("momoa");
+        expect(getNumber(ast, "/paths/5", 0)).to.equal(0);
+    });
+
+    it("should return undefined when there is no match and no default", () => {
+        expect(getBoolean(ast, "/server/debug")).to.be.undefined;
+    });
+
+    it("should throw an error with a location when the type doesn't match", () => {
+        expect(() => {
+            getNumber(ast, "/server/host", 0);
+        }).to.throw("Expected Number at \"/server/host\" but found String. (3:17)");
+    });
+
+    it("should leave the location out of errors for nodes with synthetic locations", () => {
+        let error;
+
+        try {
+            getNumber(fromValue({ port: "80" }), "/port");
+        } catch (ex) {
+            error = ex;
+        }
+
+        expect(error.message).to.equal("Expected Number at \"/port\" but found String.");
+        expect(error.toJSON().range).to.be.null;
+    });
+
+    it("should format the pointer in errors when given an array of segments", () => {
+        expect(() => {
+            getObject(ast, ["paths"]);
+        }).to.throw("Expected Object at \"/paths\" but found Array. (7:14)");
+    });
+
+});
+
+describe("optional typed getters", () => {
+
+    const ast = parse(nullableText);
+
+    it("should return values of the expected type", () => {
+        expect(getOptionalString(ast, "/host")).to.equal("example.com");
+        expect(getOptionalNumber(ast, "/port")).to.equal(8080);
+        expect(getOptionalArray(ast, ["paths"])).to.deep.equal(["/a"]);
+        expect(getOptionalObject(ast, "")).to.deep.equal({
+            host: "example.com",
+            port: 8080,
+            proxy: null,
+            paths: ["/a"]
+        });
+    });
+
+    it("should return null when the value is null", () => {
+        expect(getOptionalString(ast, "/proxy")).to.be.null;
+        expect(getOptionalObject(ast, "/proxy")).to.be.null;
+    });
+
+    it("should return null when there is no match", () => {
+        expect(getOptionalNumber(ast, "/timeout")).to.be.null;
+    });
+
+    it("should throw an error with a location when the type doesn't match", () => {
+        expect(() => {
+            getOptionalNumber(ast, "/host");
+        }).to.throw("Expected Number or Null at \"/host\" but found String. (2:13)");
+    });
+
+    it("should throw an error for null when nullable is false", () => {
+        expect(() => {
+            getOptionalString(ast, "/proxy", { nullable: false });
+        }).to.throw("Expected String at \"/proxy\" but found Null. (4:14)");
+    });
+
+    it("should still return null when there is no match and nullable is false", () => {
+        expect(getOptionalBoolean(ast, "/debug", { nullable: false })).to.be.null;
+    });
+
+});
@@ -0,0 +1,32 @@
+/**
+ * @fileoverview Helpers shared by tests
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+const { parse } = require("../api");
+
+//-----------------------------------------------------------------------------
+// Exports
+//-----------------------------------------------------------------------------
+
+/**
+ * Parses text and returns the error that was thrown.
+ * @param {string} text The text to parse.
+ * @param {Object} [options] The options to pass to `parse()`.
+ * @returns {Error|null} The error, or `null` if parsing succeeded.
+ */
+function getError(text, options) {
+    try {
+        parse(text, options);
+    } catch (ex) {
+        return ex;
+    }
+
+    return null;
+}
+
+module.exports = { getError };
@@ -0,0 +1,153 @@
+/**
+ * @fileoverview Tests for location utilities
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+const { parse, iterator, fromValue, translateLocation, rebaseLocations, createSyntheticLocation, isSyntheticLocation, createLineIndex } = require("../api");
+const { expect } = require("chai");
+
+//-----------------------------------------------------------------------------
+// Tests
+//-----------------------------------------------------------------------------
+
+describe("translateLocation()", () => {
+
+    const start = { line: 3, column: 5, offset: 20 };
+
+    it("should shift columns on the first line", () => {
+        expect(translateLocation({ line: 1, column: 1, offset: 0 }, start)).to.deep.equal({ line: 3, column: 5, offset: 20 });
+        expect(translateLocation({ line: 1, column: 4, offset: 3 }, start)).to.deep.equal({ line: 3, column: 8, offset: 23 });
+    });
+
+    it("should keep columns on later lines", () => {
+        expect(translateLocation({ line: 2, column: 3, offset: 8 }, start)).to.deep.equal({ line: 4, column: 3, offset: 28 });
+    });
+
+});
+
+describe("rebaseLocations()", () => {
+
+    const fragment = "{\n  \"b\": [true] // c\n}";
+    const prefix = "{\n    \"a\": ";
+    const text = prefix + fragment + "\n}";
+    const start = { line: 2, column: 10, offset: prefix.length };
+
+    it("should make every node refer to the larger text", () => {
+        const ast = rebaseLocations(parse(fragment, { comments: true, tokens: true, ranges: true }), start);
+        const inner = parse(text, { comments: true }).body.members[0].value;
+
+        expect(ast.body.loc).to.deep.equal(inner.loc);
+        expect(ast.body.range).to.deep.equal([inner.loc.start.offset, inner.loc.end.offset]);
+        expect(ast.body.members[0].value.elements[0].loc).to.deep.equal(inner.members[0].value.elements[0].loc);
+
+        for (const { node } of iterator(ast, ({ phase }) => phase === "enter")) {
+            const { start: { offset: startOffset }, end: { offset: endOffset } } = node.loc;
+            expect(text.slice(startOffset, endOffset)).to.equal(fragment.slice(startOffset - start.offset, endOffset - start.offset));
+        }
+    });
+
+    it("should update tokens and comments once", () => {
+        const ast = rebaseLocations(parse(fragment, { comments: true, tokens: true }), start);
+        const [comment] = ast.comments;
+
+        expect(comment).to.equal(ast.tokens[6]);
+        expect(comment.loc.start).to.deep.equal({ line: 3, column: 15, offset: 27 });
+        expect(text.slice(comment.loc.start.offset, comment.loc.end.offset)).to.equal("// c");
+    });
+
+    it("should update the range of the document", () => {
+        const ast = rebaseLocations(parse(fragment, { comments: true, ranges: true }), start);
+        expect(ast.range).to.deep.equal({ range: [11, 33] });
+    });
+
+    it("should return the same node", () => {
+        const ast = parse("1");
+        expect(rebaseLocations(ast.body, start)).to.equal(ast.body);
+        expect(ast.body.loc.start).to.deep.equal(start);
+    });
+
+    it("should leave nodes with synthetic locations alone", () => {
+        const ast = parse("[1]");
+        const added = fromValue(2).body;
+
+        ast.body.elements.push(added);
+        rebaseLocations(ast, start);
+
+        expect(added.loc).to.deep.equal(createSyntheticLocation());
+        expect(ast.body.elements[0].loc.start.offset).to.equal(start.offset + 1);
+    });
+
+});
+
+describe("createSyntheticLocation()", () => {
+
+    it("should create a zeroed location", () => {
+        expect(createSyntheticLocation()).to.deep.equal({
+            start: { line: 0, column: 0, offset: 0 },
+            end: { line: 0, column: 0, offset: 0 }
+        });
+    });
+
+    it("should create a new location each time", () => {
+        expect(createSyntheticLocation()).to.not.equal(createSyntheticLocation());
+    });
+
+});
+
+describe("isSyntheticLocation()", () => {
+
+    it("should return true for synthetic and missing locations", () => {
+        expect(isSyntheticLocation(createSyntheticLocation())).to.be.true;
+        expect(isSyntheticLocation(fromValue([1]).body.elements[0].loc)).to.be.true;
+        expect(isSyntheticLocation(undefined)).to.be.true;
+    });
+
+    it("should return false for locations in source text", () => {
+        expect(isSyntheticLocation(parse("1").body.loc)).to.be.false;
+    });
+
+});
+
+describe("createLineIndex()", () => {
+
+    it("should return the line and column for an offset", () => {
+        const lineIndex = createLineIndex("[\n  1,\n  2\n]");
+        expect(lineIndex.getLocation(0)).to.deep.equal({ line: 1, column: 1, offset: 0 });
+        expect(lineIndex.getLocation(4)).to.deep.equal({ line: 2, column: 3, offset: 4 });
+        expect(lineIndex.getLocation(9)).to.deep.equal({ line: 3, column: 3, offset: 9 });
+    });
+
+    it("should treat \\r\\n and \\r as line breaks", () => {
+        const lineIndex = createLineIndex("1\r\n2\r3");
+        expect(lineIndex.getLocation(3)).to.deep.equal({ line: 2, column: 1, offset: 3 });
+        expect(lineIndex.getLocation(5)).to.deep.equal({ line: 3, column: 1, offset: 5 });
+    });
+
+    it("should return the location at the end of the text", () => {
+        const lineIndex = createLineIndex("1\n23");
+        expect(lineIndex.getLocation(4)).to.deep.equal({ line: 2, column: 3, offset: 4 });
+    });
+
+    it("should return the offset for a line and column", () => {
+        const lineIndex = createLineIndex("1\r\n2\r3\n45");
+        expect(lineIndex.getOffset(1, 1)).to.equal(0);
+        expect(lineIndex.getOffset(2, 1)).to.equal(3);
+        expect(lineIndex.getOffset(3, 1)).to.equal(5);
+        expect(lineIndex.getOffset(4, 2)).to.equal(8);
+    });
+
+    it("should match the locations from parse()", () => {
+        const text = "{\r\n  \"a\": [1,\n\r 2]\n}";
+        const lineIndex = createLineIndex(text);
+
+        for (const token of parse(text, { tokens: true }).tokens) {
+            expect(lineIndex.getLocation(token.loc.start.offset)).to.deep.equal(token.loc.start);
+            expect(lineIndex.getLocation(token.loc.end.offset)).to.deep.equal(token.loc.end);
+        }
+    });
+
+});
@@ -0,0 +1,66 @@
+/**
+ * @fileoverview Tests for malformed document generation
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+const { createMalformedCases } = require("../api");
+const fs = require("fs");
+const path = require("path");
+const { expect } = require("chai");
+const { getError } = require("./helpers");
+
+//-----------------------------------------------------------------------------
+// Tests
+//-----------------------------------------------------------------------------
+
+describe("createMalformedCases()", () => {
+
+    it("should create each kind of case", () => {
+        const cases = createMalformedCases("{ \"a\": [1, 2] }");
+
+        expect(cases).to.deep.equal([
+            { kind: "delete-comma", text: "{ \"a\": [1 2] }", code: "UnexpectedToken", index: 10 },
+            { kind: "delete-colon", text: "{ \"a\" [1, 2] }", code: "UnexpectedToken", index: 6 },
+            { kind: "swap-bracket", text: "{ \"a\": [1, 2} }", code: "MismatchedBracket", index: 12 },
+            { kind: "swap-bracket", text: "{ \"a\": [1, 2] ]", code: "MismatchedBracket", index: 14 },
+            { kind: "truncate-string", text: "{ \"a", code: "UnexpectedEOF", index: 4 }
+        ]);
+    });
+
+    it("should keep numbers apart when deleting a comma between them", () => {
+        const [result] = createMalformedCases("[1,2]");
+        expect(result).to.deep.equal({ kind: "delete-comma", text: "[1 2]", code: "UnexpectedToken", index: 3 });
+    });
+
+    it("should skip comments when the comments option is true", () => {
+        const cases = createMalformedCases("[1, /* , */ 2]", { comments: true });
+
+        expect(cases.map(({ kind }) => kind)).to.deep.equal(["delete-comma", "swap-bracket"]);
+        expect(cases[0].index).to.equal(11);
+    });
+
+    it("should return no cases for a document without punctuation or strings", () => {
+        expect(createMalformedCases("123")).to.deep.equal([]);
+    });
+
+    it("should predict the error for every case in the fixtures", () => {
+        const astsPath = "./tests/fixtures/asts";
+
+        fs.readdirSync(astsPath).forEach(fileName => {
+            const contents = fs.readFileSync(path.join(astsPath, fileName), "utf8").replace(/\r/g, "");
+            const text = contents.slice(0, contents.indexOf("---") - 1);
+
+            for (const { text: malformed, code, index } of createMalformedCases(text, { comments: true })) {
+                const error = getError(malformed, { comments: true });
+
+                expect(error, malformed).to.not.be.null;
+                expect([error.code, error.index], malformed).to.deep.equal([code, index]);
+            }
+        });
+    });
+
+});
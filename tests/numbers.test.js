@@ -0,0 +1,93 @@
+/**
+ * @fileoverview Tests for number literal helpers
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+const { formatNumber, parseNumberLiteral } = require("../api");
+const { expect } = require("chai");
+
+//-----------------------------------------------------------------------------
+// Data
+//-----------------------------------------------------------------------------
+
+const roundTripNumbers = [0, 1, -1, 0.1, 0.1 + 0.2, 123.456, 1e21, 1.5e-7, -2.5e300, 5e-324, Number.MAX_VALUE];
+
+const formats = [
+    [1234, "1234", "1234", "1.234e+3"],
+    [0.5, "0.5", "0.5", "5e-1"],
+    [1e21, "1e+21", "1000000000000000000000", "1e+21"],
+    [-1.5e-7, "-1.5e-7", "-0.00000015", "-1.5e-7"],
+    [120, "120", "120", "1.2e+2"],
+    [-0, "0", "0", "0e+0"]
+];
+
+const validLiterals = ["0", "-0", "1", "-12.5", "0.001", "1e5", "1E+5", "21e-51", "1.5e300"];
+
+const invalidLiterals = new Map([
+    ["", "Unexpected end of input found. (1:1)"],
+    ["-", "Unexpected end of input found. (1:2)"],
+    ["01", "Unexpected character 1 found. (1:2)"],
+    ["1.", "Unexpected end of input found. (1:3)"],
+    [".5", "Unexpected character . found. (1:1)"],
+    ["+1", "Unexpected character + found. (1:1)"],
+    ["1e", "Unexpected end of input found. (1:3)"],
+    ["1e+x", "Unexpected character x found. (1:4)"],
+    ["1.5.2", "Unexpected character . found. (1:4)"],
+    ["0x10", "Unexpected character x found. (1:2)"]
+]);
+
+//-----------------------------------------------------------------------------
+// Tests
+//-----------------------------------------------------------------------------
+
+describe("formatNumber()", () => {
+
+    for (const value of roundTripNumbers) {
+        for (const exponent of ["auto", "never", "always"]) {
+            it(`should round-trip ${ value } with exponent "${ exponent }"`, () => {
+                expect(Number(formatNumber(value, { exponent }))).to.equal(value);
+            });
+        }
+
+        it(`should format ${ value } the same as JSON.stringify() by default`, () => {
+            expect(formatNumber(value)).to.equal(JSON.stringify(value));
+        });
+    }
+
+    for (const [value, auto, never, always] of formats) {
+        it(`should format ${ value } with each exponent style`, () => {
+            expect(formatNumber(value, { exponent: "auto" })).to.equal(auto);
+            expect(formatNumber(value, { exponent: "never" })).to.equal(never);
+            expect(formatNumber(value, { exponent: "always" })).to.equal(always);
+        });
+    }
+
+    it("should throw an error when the number isn't finite", () => {
+        expect(() => {
+            formatNumber(Infinity);
+        }).to.throw("Cannot represent Infinity in JSON.");
+    });
+
+});
+
+describe("parseNumberLiteral()", () => {
+
+    for (const text of validLiterals) {
+        it(`should parse ${ text }`, () => {
+            expect(parseNumberLiteral(text)).to.equal(Number(text));
+        });
+    }
+
+    for (const [text, message] of invalidLiterals) {
+        it(`should throw an error for "${ text }"`, () => {
+            expect(() => {
+                parseNumberLiteral(text);
+            }).to.throw(message);
+        });
+    }
+
+});
@@ -0,0 +1,136 @@
+/**
+ * @fileoverview Tests for ObjectView
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+const { parse, evaluate, ObjectView } = require("../api");
+const { expect } = require("chai");
+
+//-----------------------------------------------------------------------------
+// Tests
+//-----------------------------------------------------------------------------
+
+describe("ObjectView", () => {
+
+    const ast = parse("{ \"b\": 1, \"a\": 2, \"b\": 3, \"c\": null }");
+
+    it("should throw an error when the node isn't an object", () => {
+        expect(() => {
+            new ObjectView(parse("[]").body);
+        }).to.throw("An ObjectView can only be created from an Object node.");
+    });
+
+    it("should count unique keys", () => {
+        const view = new ObjectView(ast.body);
+        expect(view.size).to.equal(3);
+    });
+
+    it("should report whether a key exists", () => {
+        const view = new ObjectView(ast.body);
+        expect(view.has("a")).to.be.true;
+        expect(view.has("d")).to.be.false;
+    });
+
+    it("should use the last member with a duplicate key by default", () => {
+        const view = new ObjectView(ast.body);
+        expect(view.get("b")).to.equal(ast.body.members[2].value);
+        expect(view.getMember("b")).to.equal(ast.body.members[2]);
+        expect(evaluate(ast.body).b).to.equal(view.get("b").value);
+    });
+
+    it("should use the first member with a duplicate key when duplicates is \"first\"", () => {
+        const view = new ObjectView(ast.body, { duplicates: "first" });
+        expect(view.get("b")).to.equal(ast.body.members[0].value);
+        expect(view.getMember("b")).to.equal(ast.body.members[0]);
+    });
+
+    it("should return undefined for missing keys", () => {
+        const view = new ObjectView(ast.body);
+        expect(view.get("d")).to.be.undefined;
+        expect(view.getMember("d")).to.be.undefined;
+    });
+
+    it("should iterate in the order keys first appear", () => {
+        const view = new ObjectView(ast.body, { duplicates: "last" });
+
+        expect([...view.keys()]).to.deep.equal(["b", "a", "c"]);
+        expect([...view.values()].map(node => node.value)).to.deep.equal([3, 2, null]);
+        expect([...view].map(([key, node]) => [key, node.type])).to.deep.equal([
+            ["b", "Number"],
+            ["a", "Number"],
+            ["c", "Null"]
+        ]);
+    });
+
+    describe("membersMatching()", () => {
+
+        const view = new ObjectView(parse("{ \"x-a\": 1, \"b\": 2, \"x-\\u0062\": 3 }").body);
+
+        it("should return the names and members that match in order", () => {
+            const result = [...view.membersMatching(key => key.startsWith("x-"))];
+
+            expect(result.map(([key]) => key)).to.deep.equal(["x-a", "x-b"]);
+            expect(result[1][1]).to.equal(view.node.members[2]);
+            expect(result[1][1].loc.start).to.deep.equal({ line: 1, column: 21, offset: 20 });
+        });
+
+        it("should pass the member to the predicate", () => {
+            const result = [...view.membersMatching((key, member) => member.value.value > 1)];
+            expect(result.map(([key]) => key)).to.deep.equal(["b", "x-b"]);
+        });
+
+        it("should return every member with a duplicate key", () => {
+            const duplicates = parse("{ \"x-a\": 1, \"b\": 2, \"x-a\": 3 }").body;
+
+            for (const option of ["first", "last"]) {
+                const result = [...new ObjectView(duplicates, { duplicates: option }).membersMatching(key => key === "x-a")];
+
+                expect(result.map(([key]) => key)).to.deep.equal(["x-a", "x-a"]);
+                expect(result[0][1]).to.equal(duplicates.members[0]);
+                expect(result[1][1]).to.equal(duplicates.members[2]);
+            }
+        });
+
+    });
+
+    describe("find()", () => {
+
+        // "\u00e9" is a precomposed é and "e\u0301" is e with a combining accent
+        const view = new ObjectView(parse("{ \"Name\": 1, \"caf\u00e9\": 2, \"name\": 3, \"R\u0301\": 4 }").body);
+
+        it("should prefer an exact match", () => {
+            const result = view.find("name", { ignoreCase: true });
+            expect(result.value.value).to.equal(3);
+            expect(result.match).to.equal("exact");
+        });
+
+        it("should return undefined when there's no match", () => {
+            expect(view.find("NAME")).to.be.undefined;
+            expect(view.find("cafe\u0301")).to.be.undefined;
+        });
+
+        it("should match names that differ in case when ignoreCase is true", () => {
+            const result = view.find("NAME", { ignoreCase: true });
+            expect(result.member).to.equal(view.getMember("Name"));
+            expect(result.match).to.equal("ignoreCase");
+        });
+
+        it("should match names after normalization when normalize is true", () => {
+            const result = view.find("cafe\u0301", { normalize: true });
+            expect(result.value.value).to.equal(2);
+            expect(result.match).to.equal("normalized");
+        });
+
+        it("should match names that differ in case and normalization when both are true", () => {
+            const result = view.find("\u0155", { ignoreCase: true, normalize: true });
+            expect(result.value.value).to.equal(4);
+            expect(result.match).to.equal("ignoreCase");
+        });
+
+    });
+
+});
@@ -8,10 +8,11 @@
 // Imports
 //-----------------------------------------------------------------------------
 
-const { parse } = require("../");
+const { parse, parseAuto, parseBytes, parsePrefix, parseSequence, parseTolerant, createParser, getParseInfo, fromValue, ErrorWithLocation, formatErrorMessage, createLineIndex, evaluate } = require("../");
 const fs = require("fs");
 const path = require("path");
 const { expect } = require("chai");
+const { getError } = require("./helpers");
 
 //-----------------------------------------------------------------------------
 // Tests
@@ -43,17 +44,979 @@ describe("parse()", () => {
 
             expect(() => {
                 parse(text);
-            }).to.throw("Unexpected end of input found.");
+            }).to.throw("Unexpected end of input found. Occurred while parsing element 2 of array at line 2.");
+        });
+
+        it("should throw an error when there is a dangling comma", () => {
+            const text = `{
+   "key1": 1,
+}`;
+
+            expect(() => {
+                parse(text);
+            }).to.throw("Unexpected token Punctuator(}) found.");
+        });
+
+        it("should throw an error when an object is closed by a square bracket", () => {
+            const text = `{
+    "a": {
+        "b": 1
+    ]
+}`;
+
+            expect(() => {
+                parse(text);
+            }).to.throw("Mismatched closing bracket ] found; object opened at 2:10. Occurred while parsing value of \"a\" at line 2. (4:5)");
+        });
+
+        it("should throw an error when an empty object is closed by a square bracket", () => {
+            expect(() => {
+                parse("{]");
+            }).to.throw("Mismatched closing bracket ] found; object opened at 1:1. (1:2)");
+        });
+
+        it("should throw an error when an array is closed by a curly brace", () => {
+            expect(() => {
+                parse("[1, 2}");
+            }).to.throw("Mismatched closing bracket } found; array opened at 1:1. (1:6)");
+            expect(() => {
+                parse("[}");
+            }).to.throw("Mismatched closing bracket } found; array opened at 1:1. (1:2)");
+        });
+
+        it("should throw an error when there is a colon in an array", () => {
+            expect(() => {
+                parse("{ \"a\": [\"b\": 1] }");
+            }).to.throw("Unexpected colon found in array opened at 1:8; arrays contain values, not members. Occurred while parsing value of \"a\" at line 1. (1:12)");
+        });
+
+        it("should throw an error when an object member name isn't a string", () => {
+            expect(() => {
+                parse("{1: 2}");
+            }).to.throw("Object member names must be strings but found Number(1). (1:2)");
+            expect(() => {
+                parse("{ \"a\": 1, null: 2 }");
+            }).to.throw("Object member names must be strings but found Null(null). (1:11)");
+            expect(() => {
+                parse("{ [1]: 2 }");
+            }).to.throw("Object member names must be strings but found Punctuator([). (1:3)");
+        });
+
+        it("should still report a trailing comma in an object as an unexpected token", () => {
+            expect(() => {
+                parse("{ \"a\": 1, }");
+            }).to.throw("Unexpected token Punctuator(}) found.");
+        });
+
+        it("should throw an error when the document is empty", () => {
+            expect(() => {
+                parse("");
+            }).to.throw("Document is empty. (1:1)");
+        });
+
+        it("should throw an error when the document is only whitespace", () => {
+            expect(() => {
+                parse("  \n\t ");
+            }).to.throw("Document is empty. (1:1)");
+        });
+
+        it("should throw an error when the document is only comments", () => {
+            expect(() => {
+                parse("// nothing here", { comments: true });
+            }).to.throw("Document is empty. (1:1)");
+        });
+
+        it("should throw an error when the document is a lone comma", () => {
+            expect(() => {
+                parse(",");
+            }).to.throw("Unexpected token Punctuator(,) found.");
+        });
+    });
+
+    describe("error context", () => {
+        it("should include the member being parsed in the error message", () => {
+            const text = `{
+  "name": "momoa",
+  "dependencies": {
+    "a": 1
+    "b": 2
+  }
+}`;
+
+            expect(() => {
+                parse(text);
+            }).to.throw("Unexpected token String(\"b\") found. Occurred while parsing value of \"dependencies\" at line 3. (5:5)");
+        });
+
+        it("should include the array element being parsed in the error message", () => {
+            const text = "[1, [2, 3 4]]";
+
+            expect(() => {
+                parse(text);
+            }).to.throw("Unexpected token Number(4) found. Occurred while parsing element 1 of array at line 1. (1:11)");
+        });
+
+        it("should attach the path being parsed to the error", () => {
+            const text = "{ \"a\": [true, { \"b\": } ] }";
+            let error;
+
+            try {
+                parse(text);
+            } catch (ex) {
+                error = ex;
+            }
+
+            expect(error.context.map(frame => frame.name === undefined ? frame.index : frame.name)).to.deep.equal(["a", 1, "b"]);
+            expect(error.context[0].loc.start).to.deep.equal({ line: 1, column: 3, offset: 2 });
+        });
+
+        it("should not change the error message when the error is at the top level", () => {
+            expect(() => {
+                parse("{ \"a\": 1, }");
+            }).to.throw(/found\. \(1:11\)$/);
+        });
+
+        it("should throw an error when the input ends inside an array", () => {
+            expect(() => {
+                parse("[1,");
+            }).to.throw("Unexpected end of input found. (1:4)");
+        });
+
+        it("should throw an error when the input ends inside an object", () => {
+            expect(() => {
+                parse("{ \"a\": { \"b\": 1");
+            }).to.throw("Unexpected end of input found. Occurred while parsing value of \"a\" at line 1. (1:16)");
+        });
+
+        it("should include the member being parsed in a tokenization error message", () => {
+            expect(() => {
+                parse("{\"deps\":{\"a\": x}}");
+            }).to.throw("Unexpected character x found. Occurred while parsing value of \"a\" at line 1. (1:15)");
+        });
+
+        it("should include the member being parsed when a string isn't closed", () => {
+            let error;
+
+            try {
+                parse("{\"deps\":{\"a\": \"b}}");
+            } catch (ex) {
+                error = ex;
+            }
+
+            expect(error.message).to.match(/\. Occurred while parsing value of "a" at line 1\. \(1:\d+\)$/);
+            expect(error.context.map(frame => frame.name)).to.deep.equal(["deps", "a"]);
+        });
+
+        it("should add the context as its own sentence after a message with more than one sentence", () => {
+            expect(() => {
+                parse("{\"a\": // b\n1}");
+            }).to.throw("Comments aren't allowed in JSON. Use the comments option to parse JSONC. Occurred while parsing value of \"a\" at line 1. (1:7)");
+        });
+
+        it("should report a tokenization error before an earlier unexpected token", () => {
+            expect(() => {
+                parse("[1 2 x]");
+            }).to.throw("Unexpected character x found. (1:6)");
+        });
+    });
+
+    describe("sourceName", () => {
+        it("should include the source name in parse error messages", () => {
+            expect(() => {
+                parse("{\n  \"a\": [1 2]\n}", { sourceName: "config/app.jsonc" });
+            }).to.throw("Unexpected token Number(2) found. Occurred while parsing value of \"a\" at line 2. (config/app.jsonc:2:11)");
+        });
+
+        it("should include the source name in tokenization error messages", () => {
+            expect(() => {
+                parse("[1, x]", { sourceName: "data.json" });
+            }).to.throw("Unexpected character x found. (data.json:1:5)");
+        });
+
+        it("should attach the source name to errors", () => {
+            let error;
+
+            try {
+                parse("", { sourceName: "empty.json" });
+            } catch (ex) {
+                error = ex;
+            }
+
+            expect(error.sourceName).to.equal("empty.json");
+            expect(error.message).to.equal("Document is empty. (empty.json:1:1)");
+        });
+
+        it("should attach the source name to the document", () => {
+            const result = parse("1", { sourceName: "one.json" });
+            expect(result.sourceName).to.equal("one.json");
+        });
+
+        it("should not attach a source name to the document when not passed", () => {
+            const result = parse("1");
+            expect(result).to.not.have.property("sourceName");
+        });
+    });
+
+    describe("error JSON", () => {
+
+        it("should serialize a parse error with a stable shape", () => {
+            const error = getError("[1, 2 3]", { sourceName: "data.json" });
+            expect(JSON.parse(JSON.stringify(error))).to.deep.equal({
+                code: "UnexpectedToken",
+                message: "Unexpected token Number(3) found. (data.json:1:7)",
+                severity: "error",
+                range: {
+                    start: { line: 1, column: 7, offset: 6 },
+                    end: { line: 1, column: 7, offset: 6 }
+                },
+                sourceName: "data.json",
+                suggestedFix: {
+                    message: "Add a comma.",
+                    range: [5, 5],
+                    text: ","
+                }
+            });
+        });
+
+        it("should have a location matching the line, column, and index", () => {
+            const error = getError("{\n  \"a\": }");
+
+            expect(error.location).to.deep.equal({ line: 2, column: 8, offset: 9 });
+            expect(error.location).to.not.equal(error.location);
+        });
+
+        it("should be an instance of ErrorWithLocation", () => {
+            expect(getError("[1,")).to.be.instanceOf(ErrorWithLocation);
+            expect(getError("[1 2]")).to.be.instanceOf(ErrorWithLocation);
+            expect(getError("'a'")).to.be.instanceOf(ErrorWithLocation);
+        });
+
+        it("should use null for the source name when there isn't one", () => {
+            const error = getError("\"abc");
+            expect(error.toJSON()).to.deep.equal({
+                code: "UnexpectedEOF",
+                message: "Unexpected end of input found. (1:5)",
+                severity: "error",
+                range: {
+                    start: { line: 1, column: 5, offset: 4 },
+                    end: { line: 1, column: 5, offset: 4 }
+                },
+                sourceName: null,
+                suggestedFix: null
+            });
+        });
+
+        it("should use the static code of the error class as the code", () => {
+            expect(getError("@").code).to.equal("UnexpectedChar");
+            expect(getError("").code).to.equal("EmptyDocument");
+            expect(getError("@").constructor.code).to.equal("UnexpectedChar");
+        });
+
+        it("should not depend on the name of the error class", () => {
+            const error = getError("[");
+            const Renamed = class extends error.constructor {};
+
+            expect(new Renamed({ line: 1, column: 1, offset: 0 }).code).to.equal("UnexpectedEOF");
+        });
+
+        it("should set index to the offset of the error", () => {
+            expect(getError("\n  @").index).to.equal(3);
+        });
+    });
+
+    describe("error messages", () => {
+
+        it("should attach the values used in the message as data", () => {
+            expect(getError("[1 2]").data).to.deep.equal({ tokenType: "Number", tokenValue: "2" });
+            expect(getError("[1}").data).to.deep.equal({ bracket: "}", container: "array", openLine: 1, openColumn: 1 });
+            expect(getError("\"abc\"", { maxStringLength: 2 }).data).to.deep.equal({ limit: "maxStringLength", maximum: 2 });
+            expect(getError("[").data).to.deep.equal({});
+        });
+
+        it("should use the default message without the location", () => {
+            const error = getError("[1 2]", { sourceName: "a.json" });
+            expect(formatErrorMessage(error, {})).to.equal("Unexpected token Number(2) found.");
+        });
+
+        it("should use a template for the error code", () => {
+            const error = getError("@");
+            expect(formatErrorMessage(error, { UnexpectedChar: "Caractère inattendu {character} ({unknown})." })).to.equal("Caractère inattendu @ ({unknown}).");
+        });
+
+        it("should use a function for the error code", () => {
+            const error = getError("1e123", { maxExponentDigits: 2 });
+            const messages = {
+                LimitExceeded: data => `${ data.limit }: ${ data.maximum }`
+            };
+
+            expect(formatErrorMessage(error, messages)).to.equal("maxExponentDigits: 2");
+        });
+
+        it("should use a function for all codes", () => {
+            const messages = (code, data) => (code === "EmptyDocument" ? "Vide." : undefined);
+
+            expect(formatErrorMessage(getError(""), messages)).to.equal("Vide.");
+            expect(formatErrorMessage(getError("{1}"), messages)).to.equal("Object member names must be strings but found Number(1).");
+        });
+
+        it("should return the message of other errors", () => {
+            expect(formatErrorMessage(new TypeError("Oops."), {})).to.equal("Oops.");
+        });
+    });
+
+    describe("suggested fixes", () => {
+
+        function getFix(text, options) {
+            try {
+                parse(text, options);
+            } catch (ex) {
+                return ex.suggestedFix;
+            }
+
+            return null;
+        }
+
+        it("should suggest double quotes for a single-quoted string", () => {
+            expect(getFix("{ \"a\": 'it\\'s \"b\"' }")).to.deep.equal({
+                message: "Use double quotes.",
+                range: [7, 18],
+                text: "\"it's \\\"b\\\"\""
+            });
+        });
+
+        it("should suggest quotes for an unquoted key", () => {
+            expect(getFix("{ \"a\": 1, name: 2 }")).to.deep.equal({
+                message: "Add quotes around \"name\".",
+                range: [10, 14],
+                text: "\"name\""
+            });
+        });
+
+        it("should suggest removing a trailing comma", () => {
+            expect(getFix("{ \"a\": 1, /* end */ }", { comments: true })).to.deep.equal({
+                message: "Remove the trailing comma.",
+                range: [8, 9],
+                text: ""
+            });
+        });
+
+        it("should suggest adding a missing comma between members", () => {
+            expect(getFix("{\n  \"a\": { \"b\": 1 }\n  \"c\": 2\n}")).to.deep.equal({
+                message: "Add a comma.",
+                range: [19, 19],
+                text: ","
+            });
+        });
+
+        it("should suggest escaping the backslashes in a Windows path", () => {
+            expect(getFix("{ \"dir\": \"C:\\Users\\temp\" }")).to.deep.equal({
+                message: "Escape the backslashes in the path.",
+                range: [9, 24],
+                text: "\"C:\\\\Users\\\\temp\""
+            });
+        });
+
+        it("should suggest a space for irregular whitespace", () => {
+            expect(getFix("[1,\u00A02]", { strictWhitespace: true })).to.deep.equal({
+                message: "Replace with a space.",
+                range: [3, 4],
+                text: " "
+            });
+        });
+
+        it("should not suggest a fix for an invalid escape that isn't in a path", () => {
+            expect(getFix("[\"a\\qb\"]")).to.be.undefined;
+        });
+
+        it("should not suggest a comma when a colon is missing", () => {
+            expect(getFix("{ \"a\" \"b\" }")).to.be.undefined;
+        });
+
+        it("should not suggest a fix for other errors", () => {
+            expect(getFix("[1,")).to.be.undefined;
+            expect(getFix("1 2")).to.be.undefined;
+            expect(getFix("{ @: 1 }")).to.be.undefined;
+        });
+    });
+
+    describe("allowEmpty", () => {
+        it("should return a document with a null body when the document is empty", () => {
+            const result = parse("", { allowEmpty: true });
+            expect(result).to.deep.equal({
+                type: "Document",
+                body: null,
+                loc: {
+                    start: { line: 1, column: 1, offset: 0 },
+                    end: { line: 1, column: 1, offset: 0 }
+                }
+            });
+        });
+
+        it("should return a document with a null body when the document is only whitespace", () => {
+            const result = parse("\n\n  ", { allowEmpty: true, ranges: true });
+            expect(result.body).to.be.null;
+            expect(result.loc.end).to.deep.equal({ line: 3, column: 3, offset: 4 });
+            expect(result.range).to.deep.equal({ range: [0, 4] });
+            expect(parse("\n\n  ", { allowEmpty: true, offsetsOnly: true }).loc.end).to.deep.equal({ offset: 4 });
+        });
+
+        it("should return a document with a null body when the document is only comments", () => {
+            const result = parse("/* nothing */", { allowEmpty: true, comments: true });
+            expect(result.body).to.be.null;
+            expect(result.loc.end).to.deep.equal({ line: 1, column: 14, offset: 13 });
+        });
+
+        it("should still throw an error for a lone comma", () => {
+            expect(() => {
+                parse(",", { allowEmpty: true });
+            }).to.throw("Unexpected token Punctuator(,) found.");
+        });
+    });
+
+    describe("integersOnly", () => {
+
+        it("should parse integers", () => {
+            const ast = parse("[0, -12, 9007199254740993]", { integersOnly: true });
+            expect(ast.body.elements.map(element => element.value)).to.deep.equal([0, -12, 9007199254740992]);
+        });
+
+        it("should throw an error for a number with a fraction", () => {
+            expect(() => {
+                parse("{\n  \"a\": 1.0\n}", { integersOnly: true });
+            }).to.throw("Expected an integer but found 1.0. Occurred while parsing value of \"a\" at line 2. (2:8)");
+        });
+
+        it("should throw an error for a number with an exponent", () => {
+            expect(() => {
+                parse("[1, 2e3]", { integersOnly: true });
+            }).to.throw("Expected an integer but found 2e3. Occurred while parsing element 1 of array at line 1. (1:5)");
+        });
+
+        it("should allow fractions and exponents by default", () => {
+            expect(parse("[1.5, 2E-3]").body.elements).to.have.lengthOf(2);
+        });
+
+    });
+
+    describe("validateString", () => {
+
+        it("should call the validator with each decoded string and its location", () => {
+            const calls = [];
+
+            parse("{ \"a\\u0062\": [\"c\"] }", {
+                validateString(value, loc) {
+                    calls.push([value, loc.start.offset, loc.end.offset]);
+                }
+            });
+
+            expect(calls).to.deep.equal([["ab", 2, 11], ["c", 14, 17]]);
+        });
+
+        it("should throw the message returned by the validator", () => {
+            const validateString = value => (value.length > 3 ? "String is longer than 3 characters." : undefined);
+
+            expect(() => {
+                parse("[\"abc\",\n \"abcd\"]", { validateString });
+            }).to.throw("String is longer than 3 characters. Occurred while parsing element 1 of array at line 2. (2:2)");
+        });
+
+        it("should include the error code", () => {
+            try {
+                parse("\"\"", { validateString: () => "Empty." });
+                expect.fail("Expected an error.");
+            } catch (ex) {
+                expect(ex.code).to.equal("InvalidString");
+                expect(ex.index).to.equal(0);
+            }
+        });
+
+    });
+
+    describe("rootTypes", () => {
+
+        it("should parse a root value with an allowed type", () => {
+            const ast = parse("{ \"a\": 1 }", { rootTypes: ["Object"] });
+            expect(ast.body.type).to.equal("Object");
+        });
+
+        it("should throw an error at the root value when its type isn't allowed", () => {
+            expect(() => {
+                parse("\n  [1, 2]", { rootTypes: ["Object"] });
+            }).to.throw("Expected the root value to be Object but found Array. (2:3)");
+        });
+
+        it("should list every allowed type in the error", () => {
+            expect(() => {
+                parse("true", { rootTypes: ["Object", "Array"] });
+            }).to.throw("Expected the root value to be Object or Array but found Boolean.");
+
+            expect(() => {
+                parse("null", { rootTypes: ["Object", "Array", "String"] });
+            }).to.throw("Expected the root value to be Object, Array, or String but found Null.");
+        });
+
+        it("should include the error code and source name", () => {
+            try {
+                parse("1", { rootTypes: ["Object"], sourceName: "app.json" });
+                expect.fail("Expected an error.");
+            } catch (ex) {
+                expect(ex.code).to.equal("UnexpectedRootType");
+                expect(ex.sourceName).to.equal("app.json");
+                expect(ex.message).to.equal("Expected the root value to be Object but found Number. (app.json:1:1)");
+            }
+        });
+
+        it("should allow an empty document when allowEmpty is true", () => {
+            const ast = parse("", { rootTypes: ["Object"], allowEmpty: true });
+            expect(ast.body).to.be.null;
+        });
+
+    });
+
+    describe("graphemeColumns", () => {
+        it("should count code units in error columns by default", () => {
+            expect(() => {
+                parse("[\"e\u0301\" x]");
+            }).to.throw("Unexpected character x found. Occurred while parsing element 0 of array at line 1. (1:7)");
+        });
+
+        it("should count combining marks with their base character", () => {
+            expect(() => {
+                parse("[\"e\u0301\" x]", { graphemeColumns: true });
+            }).to.throw("Unexpected character x found. Occurred while parsing element 0 of array at line 1. (1:6)");
+        });
+
+        it("should count surrogate pairs as one character", () => {
+            expect(() => {
+                parse("{\n\"\ud83d\ude00\": 1 2}", { graphemeColumns: true });
+            }).to.throw("Unexpected token Number(2) found. (2:8)");
+        });
+
+        it("should update the column used with sourceName", () => {
+            try {
+                parse("[\"e\u0301\" x]", { graphemeColumns: true, sourceName: "a.json" });
+            } catch (ex) {
+                expect(ex.message).to.equal("Unexpected character x found. Occurred while parsing element 0 of array at line 1. (a.json:1:6)");
+                expect(ex.column).to.equal(6);
+                expect(ex.index).to.equal(6);
+                return;
+            }
+
+            throw new Error("Expected an error.");
+        });
+    });
+
+    describe("progress and cancellation", () => {
+        const text = JSON.stringify(Array.from({ length: 50 }, (_, i) => i));
+
+        it("should report progress at each interval and when complete", () => {
+            const offsets = [];
+
+            parse(text, {
+                callbackInterval: 25,
+                onProgress({ offset, length }) {
+                    expect(length).to.equal(text.length);
+                    offsets.push(offset);
+                }
+            });
+
+            // 50 numbers, 49 commas, and 2 brackets is 101 tokens
+            expect(offsets).to.have.lengthOf(5);
+            expect(offsets[0]).to.equal(text.indexOf(",12"));
+            expect(offsets[4]).to.equal(text.length);
+        });
+
+        it("should throw an error when shouldCancel returns true", () => {
+            let calls = 0;
+
+            expect(() => {
+                parse(text, {
+                    callbackInterval: 10,
+                    shouldCancel() {
+                        return ++calls === 2;
+                    }
+                });
+            }).to.throw(/^Parsing was cancelled\. \(1:\d+\)/);
+            expect(calls).to.equal(2);
+        });
+
+        it("should finish parsing when shouldCancel returns false", () => {
+            const result = parse(text, {
+                callbackInterval: 1,
+                shouldCancel() {
+                    return false;
+                }
+            });
+            expect(result.body.elements).to.have.lengthOf(50);
+        });
+    });
+
+    describe("maxInputBytes", () => {
+
+        it("should allow input up to the maximum size in UTF-8 bytes", () => {
+            const ast = parse("[\"é😀\"]", { maxInputBytes: 10 });
+            expect(ast.body.elements[0].value).to.equal("é😀");
+        });
+
+        it("should throw an error for input that's too large", () => {
+            try {
+                parse("[\"é😀\"]", { maxInputBytes: 9 });
+                expect.fail("Expected an error.");
+            } catch (ex) {
+                expect(ex.code).to.equal("LimitExceeded");
+                expect(ex.message).to.equal("Input is longer than the maximum of 9 bytes. (1:1)");
+                expect(ex.index).to.equal(0);
+            }
+        });
+
+        it("should check the size before tokenizing", () => {
+            expect(() => {
+                parse("'not json'", { maxInputBytes: 5, sourceName: "a.json" });
+            }).to.throw("Input is longer than the maximum of 5 bytes. (a.json:1:1)");
+        });
+
+        it("should check the size of bytes before decoding", () => {
+            expect(() => {
+                parseBytes(Uint8Array.from([0xff, 0xff, 0xff]), { maxInputBytes: 2 });
+            }).to.throw("Input is longer than the maximum of 2 bytes. (1:1)");
+        });
+
+    });
+
+    describe("maxStringLength", () => {
+
+        it("should allow strings up to the maximum length", () => {
+            const ast = parse("{ \"ab\": \"\\u0041\\u0042\\u0043\" }", { maxStringLength: 3 });
+            expect(ast.body.members[0].value.value).to.equal("ABC");
+        });
+
+        it("should throw an error for a decoded string that's too long", () => {
+            expect(() => {
+                parse("[\"a\",\n \"\\u0041\\u0042\\u0043\\u0044\"]", { maxStringLength: 3 });
+            }).to.throw("String is longer than the maximum of 3 characters. Occurred while parsing element 1 of array at line 2. (2:2)");
+        });
+
+        it("should throw an error for a string too long to decode to the maximum length", () => {
+            expect(() => {
+                parse("[\"" + "a".repeat(100000) + "\"]", { maxStringLength: 3 });
+            }).to.throw("String is longer than the maximum of 3 characters. Occurred while parsing element 0 of array at line 1. (1:2)");
+            expect(() => {
+                parse("\"\\u0041\\u0042\"", { maxStringLength: 1 });
+            }).to.throw("String is longer than the maximum of 1 characters. (1:1)");
+        });
+
+        it("should check member names", () => {
+            try {
+                parse("{ \"abcd\": 1 }", { maxStringLength: 3 });
+                expect.fail("Expected an error.");
+            } catch (ex) {
+                expect(ex.code).to.equal("LimitExceeded");
+                expect(ex.index).to.equal(2);
+            }
+        });
+
+    });
+
+    describe("maxExponentDigits", () => {
+
+        it("should allow exponents up to the maximum number of digits", () => {
+            expect(parse("[1e10, -2.5E+99]", { maxExponentDigits: 2 }).body.elements[1].value).to.equal(-2.5e99);
+        });
+
+        it("should throw an error at the first exponent digit past the limit", () => {
+            try {
+                parse("[1,\n -1.5e-" + "9".repeat(100000) + "]", { maxExponentDigits: 3 });
+                expect.fail("Expected an error.");
+            } catch (ex) {
+                expect(ex.code).to.equal("LimitExceeded");
+                expect(ex.message).to.equal("Number exponent is longer than the maximum of 3 digits. (2:11)");
+            }
+        });
+
+        it("should apply to documents from parseSequence()", () => {
+            expect(() => {
+                [...parseSequence("\u001e1e100\n", { maxExponentDigits: 2 })];
+            }).to.throw("Number exponent is longer than the maximum of 2 digits. (1:6)");
+        });
+
+    });
+
+    describe("trailingContent", () => {
+
+        it("should throw an error for trailing content by default", () => {
+            expect(() => parse("{} x")).to.throw("Unexpected character x found. (1:4)");
+            expect(() => parse("{} []", { trailingContent: "error" })).to.throw("Unexpected token Punctuator([) found. (1:4)");
+        });
+
+        it("should ignore trailing content when trailingContent is \"ignore\"", () => {
+            const ast = parse("{\"a\": 1}\n--- not JSON", { trailingContent: "ignore" });
+
+            expect(ast.body.members[0].value.value).to.equal(1);
+            expect(ast).to.not.have.property("trailingOffset");
+        });
+
+        it("should return the offset of the first trailing token when trailingContent is \"return\"", () => {
+            const text = "[1] /* c */ {\"next\": true}";
+            const ast = parse(text, { trailingContent: "return", comments: true });
+
+            expect(ast.trailingOffset).to.equal(12);
+            expect(parse(text.slice(ast.trailingOffset)).body.type).to.equal("Object");
+        });
+
+        it("should return the offset of text that can't be tokenized when trailingContent is \"return\"", () => {
+            expect(parse("1  @@", { trailingContent: "return" }).trailingOffset).to.equal(3);
+        });
+
+        it("should return null when there's no trailing content and trailingContent is \"return\"", () => {
+            expect(parse("[1] // done\n", { trailingContent: "return", comments: true }).trailingOffset).to.be.null;
+        });
+
+        it("should still throw errors inside of the value", () => {
+            expect(() => parse("[1 x", { trailingContent: "ignore" })).to.throw("Unexpected character x found. Occurred while parsing element 0 of array at line 1. (1:4)");
+        });
+
+    });
+
+    describe("maxLineLength", () => {
+        it("should throw a LineTooLong error when a line is too long", () => {
+            expect(() => {
+                parse("{\n    \"name\": \"momoa\"\n}", { maxLineLength: 10 });
+            }).to.throw("Line 2 is longer than the maximum of 10 characters. (2:11)");
+        });
+    });
+
+    describe("escapedSolidus", () => {
+        it("should add escapedSolidus to strings with a \\/ escape", () => {
+            const result = parse("{ \"a\\/b\": \"http:\\/\\/example.com\" }");
+            const member = result.body.members[0];
+            expect(member.name.escapedSolidus).to.be.true;
+            expect(member.value.escapedSolidus).to.be.true;
+            expect(member.value.value).to.equal("http://example.com");
+        });
+
+        it("should not add escapedSolidus to other strings", () => {
+            const result = parse("[\"a/b\", \"a\\\\/b\", \"a\\nb\"]");
+            expect(result.body.elements.map(element => "escapedSolidus" in element)).to.deep.equal([false, false, false]);
+        });
+
+        it("should add escapedSolidus after an escaped backslash", () => {
+            const result = parse("\"a\\\\\\/b\"");
+            expect(result.body.value).to.equal("a\\/b");
+            expect(result.body.escapedSolidus).to.be.true;
+        });
+    });
+
+    describe("tolerant", () => {
+        it("should assume missing commas between members and elements", () => {
+            const text = "{ \"a\": [1 2] \"b\": 3 }";
+            const result = parse(text, { tolerant: true, tokens: true });
+            expect(JSON.stringify(result.body.members.map(member => member.name.value))).to.equal("[\"a\",\"b\"]");
+            expect(result.body.members[0].value.elements).to.have.lengthOf(2);
+
+            const synthetic = result.tokens.filter(token => token.synthetic);
+            expect(synthetic.map(token => [token.value, token.loc.start.offset, token.loc.end.offset])).to.deep.equal([
+                [",", 9, 9],
+                [",", 12, 12]
+            ]);
+            expect(result.tokens.map(token => token.loc.start.offset)).to.deep.equal([0, 2, 5, 7, 8, 9, 10, 11, 12, 13, 16, 18, 20]);
+        });
+
+        it("should assume missing closing brackets at the end of the text", () => {
+            const result = parse("{ \"a\": [1, { \"b\": 2", { tolerant: true, tokens: true, ranges: true });
+            const synthetic = result.tokens.filter(token => token.synthetic);
+
+            expect(synthetic.map(token => [token.value, token.range])).to.deep.equal([
+                ["}", [19, 19]],
+                ["]", [19, 19]],
+                ["}", [19, 19]]
+            ]);
+            expect(result.body.loc.end.offset).to.equal(19);
+            expect(result.tokens[result.tokens.length - 1]).to.equal(synthetic[2]);
+        });
+
+        it("should place synthetic tokens before trailing comments", () => {
+            const result = parse("[1 /* a */ 2 // b", { tolerant: true, tokens: true, comments: true });
+            expect(result.tokens.map(token => token.value)).to.deep.equal(["[", "1", ",", "/* a */", "2", "]", "// b"]);
+            expect(result.body.loc.end.offset).to.equal(12);
+        });
+
+        it("should not add synthetic tokens to valid JSON", () => {
+            const result = parse("[1, {}]", { tolerant: true, tokens: true });
+            expect(result.tokens.some(token => token.synthetic)).to.be.false;
+        });
+
+        it("should still throw other errors", () => {
+            expect(() => {
+                parse("{ \"a\" 1 }", { tolerant: true });
+            }).to.throw("Unexpected token Number(1) found. (1:7)");
+
+            expect(() => {
+                parse("[1 2]");
+            }).to.throw("Unexpected token Number(2) found. (1:4)");
         });
 
-        it("should throw an error when there is a dangling comma", () => {
-            const text = `{
-   "key1": 1,
-}`;
+        it("should record an error in the parse info for each assumed punctuator", () => {
+            const { errors } = getParseInfo(parse("[1 2, [3", { tolerant: true }));
+            expect(errors.map(error => [error.code, error.index])).to.deep.equal([
+                ["UnexpectedToken", 3],
+                ["UnexpectedEOF", 8],
+                ["UnexpectedEOF", 8]
+            ]);
+        });
+
+        it("should not record errors for valid JSON", () => {
+            expect(getParseInfo(parse("[1, 2]", { tolerant: true })).errors).to.deep.equal([]);
+            expect(getParseInfo(parse("[1, 2]")).errors).to.deep.equal([]);
+        });
+    });
+
+    describe("strictWhitespace", () => {
+        it("should throw an IrregularWhitespace error naming the character", () => {
+            try {
+                parse("{\n\"a\":\u30001}", { strictWhitespace: true });
+                throw new Error("Expected an error.");
+            } catch (ex) {
+                expect(ex.code).to.equal("IrregularWhitespace");
+                expect(ex.codePoint).to.equal("U+3000");
+                expect(ex.message).to.equal("Irregular whitespace U+3000 (IDEOGRAPHIC SPACE) found. Only spaces, tabs, carriage returns, and line feeds are allowed. Occurred while parsing value of \"a\" at line 2. (2:5)");
+            }
+        });
+
+        it("should allow irregular whitespace in strings", () => {
+            const result = parse("\"a\u00A0b\u2028c\"", { strictWhitespace: true });
+            expect(result.body.value).to.equal("a\u00A0b\u2028c");
+        });
+
+        it("should allow a byte order mark at the start of the text", () => {
+            expect(parse("\uFEFF[]", { strictWhitespace: true }).body.type).to.equal("Array");
+        });
+
+        it("should skip irregular whitespace by default", () => {
+            expect(parse("[1,\u00A0\u20282]").body.elements).to.have.lengthOf(2);
+        });
+    });
+
+    describe("offsetsOnly", () => {
+        it("should store only offsets in node and token locations", () => {
+            const result = parse("{\n  \"a\": [1, 2]\n}", { offsetsOnly: true, tokens: true });
+            const member = result.body.members[0];
+
+            expect(result.loc).to.deep.equal({ start: { offset: 0 }, end: { offset: 17 } });
+            expect(member.loc).to.deep.equal({ start: { offset: 4 }, end: { offset: 15 } });
+            expect(member.value.elements[1].loc).to.deep.equal({ start: { offset: 13 }, end: { offset: 14 } });
+            expect(result.tokens[1].loc).to.deep.equal({ start: { offset: 4 }, end: { offset: 7 } });
+        });
+
+        it("should compute the same locations as full parsing on demand", () => {
+            const text = "[\r\n  {\"a\": true},\n  null\n]";
+            const lineIndex = createLineIndex(text);
+            const full = parse(text);
+            const lite = parse(text, { offsetsOnly: true });
+
+            expect(lineIndex.getLocation(lite.body.elements[1].loc.start.offset)).to.deep.equal(full.body.elements[1].loc.start);
+            expect(lineIndex.getLocation(lite.body.loc.end.offset)).to.deep.equal(full.body.loc.end);
+        });
+
+        it("should report errors with the same messages as full parsing", () => {
+            const cases = [
+                ["{\n \"a\": [1,\n 2 3]}", {}],
+                ["[1}", {}],
+                ["[1,\n\n", {}],
+                ["  ", {}],
+                ["{\n\"a\":{\"b\": [1, 2.5]}}", { integersOnly: true }],
+                ["{\n\"a\": 1e999}", { maxExponentDigits: 2 }]
+            ];
+
+            for (const [text, options] of cases) {
+                let expected;
+
+                try {
+                    parse(text, options);
+                } catch (ex) {
+                    expected = ex;
+                }
+
+                expect(() => {
+                    parse(text, { ...options, offsetsOnly: true });
+                }).to.throw(expected.message);
+            }
+        });
+
+        it("should include the line and column in error locations", () => {
+            try {
+                parse("[\n  1\n  2]", { offsetsOnly: true });
+                throw new Error("Expected an error.");
+            } catch (ex) {
+                expect(ex.location).to.deep.equal({ line: 3, column: 3, offset: 8 });
+            }
+        });
+    });
+
+    describe("shebang", () => {
+        it("should skip a first-line shebang", () => {
+            const result = parse("#!/usr/bin/env node\n{\"a\": 1}", { shebang: true });
+            expect(result.body.type).to.equal("Object");
+            expect(result.body.loc.start).to.deep.equal({ line: 2, column: 1, offset: 20 });
+        });
+
+        it("should include the Shebang token in the tokens array", () => {
+            const result = parse("#!/usr/bin/env node\n1", { shebang: true, tokens: true });
+            expect(result.tokens.map(token => token.type)).to.deep.equal(["Shebang", "Number"]);
+        });
+
+        it("should skip a byte order mark and a shebang", () => {
+            const result = parse("\uFEFF#!/usr/bin/env node\n{\"a\": 1}", { bom: true, shebang: true, tokens: true });
+
+            expect(result.body.type).to.equal("Object");
+            expect(result.tokens.slice(0, 2).map(token => token.type)).to.deep.equal(["BOM", "Shebang"]);
+            expect(parse("\uFEFF#!/usr/bin/env node\n1", { shebang: true }).body.value).to.equal(1);
+        });
 
+        it("should throw an error for a shebang by default", () => {
             expect(() => {
-                parse(text);
-            }).to.throw("Unexpected token Punctuator(}) found.");
+                parse("#!/usr/bin/env node\n1");
+            }).to.throw("Unexpected character # found. (1:1)");
+        });
+    });
+
+    describe("comments", () => {
+        it("should return a comments array when comments:true is passed", () => {
+            const text = "// a\n[1, /* b */ 2]";
+            const result = parse(text, { comments: true });
+            expect(result.comments.map(({ type, value }) => [type, value])).to.deep.equal([
+                ["LineComment", "// a"],
+                ["BlockComment", "/* b */"]
+            ]);
+            expect(result.tokens).to.be.undefined;
+        });
+
+        it("should skip any number of comments in a row", () => {
+            const result = parse("[" + "/**/".repeat(200000) + "1]", { comments: true });
+            expect(result.body.elements[0].value).to.equal(1);
+        });
+
+        it("should share comment tokens with the tokens array", () => {
+            const result = parse("[1] // a", { comments: true, tokens: true });
+            expect(result.comments[0]).to.equal(result.tokens[3]);
+        });
+
+        it("should include comments when the AST is serialized", () => {
+            const result = JSON.parse(JSON.stringify(parse("/* a */ 1", { comments: true, ranges: true })));
+            expect(result.comments).to.deep.equal([
+                {
+                    type: "BlockComment",
+                    value: "/* a */",
+                    loc: {
+                        start: { line: 1, column: 1, offset: 0 },
+                        end: { line: 1, column: 8, offset: 7 }
+                    },
+                    range: [0, 7]
+                }
+            ]);
+        });
+
+        it("should not return a comments array when comments is not passed", () => {
+            expect(parse("1").comments).to.be.undefined;
         });
     });
 
@@ -79,11 +1042,56 @@ describe("parse()", () => {
             expect(result.tokens).to.be.undefined;
         });
 
+        it("should end the tokens array with an EOF token when eof:true is passed", () => {
+            const text = "\"hi\" ";
+            const result = parse(text, { tokens: true, eof: true });
+            expect(result.tokens.map(token => token.type)).to.deep.equal(["String", "EOF"]);
+            expect(result.tokens[1].loc.start).to.deep.equal({ line: 1, column: 6, offset: 5 });
+        });
+
         it("should not return a tokens array when tokens:false is passed", () => {
             const text = "\"hi\"";
             const result = parse(text, { tokens: false });
             expect(result.tokens).to.be.undefined;
         });
+
+        it("should add the indices of the brackets to arrays and objects", () => {
+            const text = "#!/usr/bin/env node\n{ /* a */ \"b\": [1, // c\n{}] }";
+            const result = parse(text, { tokens: true, comments: true, shebang: true });
+            const { body } = result;
+            const array = body.members[0].value;
+            const object = array.elements[1];
+
+            expect([body.openTokenIndex, body.closeTokenIndex]).to.deep.equal([1, 12]);
+            expect([array.openTokenIndex, array.closeTokenIndex]).to.deep.equal([5, 11]);
+            expect([object.openTokenIndex, object.closeTokenIndex]).to.deep.equal([9, 10]);
+            expect(result.tokens[array.openTokenIndex].loc.start).to.deep.equal(array.loc.start);
+            expect(result.tokens[array.closeTokenIndex].loc.end).to.deep.equal(array.loc.end);
+        });
+
+        it("should add the indices of synthetic closing brackets", () => {
+            const result = parse("[{\"a\": 1 \"b\": 2", { tokens: true, tolerant: true });
+            const { body } = result;
+
+            expect(body.closeTokenIndex).to.equal(result.tokens.length - 1);
+            expect(result.tokens[body.elements[0].closeTokenIndex]).to.include({ value: "}", synthetic: true });
+            expect(result.tokens[body.elements[0].openTokenIndex].value).to.equal("{");
+        });
+
+        it("should add the indices of synthetic closing brackets before trailing comments", () => {
+            const result = parse("[[1 // c", { tokens: true, comments: true, tolerant: true });
+            const { body } = result;
+
+            expect(result.tokens.map(token => token.value)).to.deep.equal(["[", "[", "1", "]", "]", "// c"]);
+            expect(body.elements[0].closeTokenIndex).to.equal(3);
+            expect(body.closeTokenIndex).to.equal(4);
+        });
+
+        it("should not add token indices when tokens is not passed", () => {
+            const result = parse("[{}]");
+            expect(result.body).to.not.have.property("openTokenIndex");
+            expect(result.body.elements[0]).to.not.have.property("closeTokenIndex");
+        });
     });
 
     describe("fixtures", () => {
@@ -106,3 +1114,492 @@ describe("parse()", () => {
 
 
 });
+
+describe("parseBytes()", () => {
+
+    it("should parse UTF-8 bytes", () => {
+        const text = "{ \"a\": \"caf\u00e9\" }";
+
+        expect(parseBytes(Buffer.from(text, "utf8"))).to.deep.equal(parse(text));
+    });
+
+    it("should pass options to the parser", () => {
+        const ast = parseBytes(Buffer.from("// a\n1", "utf8"), { comments: true });
+
+        expect(ast.body.value).to.equal(1);
+    });
+
+    it("should throw an error with the source name for invalid UTF-8", () => {
+        expect(() => {
+            parseBytes(Uint8Array.from([0x5b, 0x0a, 0x22, 0xc3, 0x22, 0x5d]), { sourceName: "a.json" });
+        }).to.throw("Invalid UTF-8 byte sequence found at byte 3. (a.json:2:2)");
+    });
+
+});
+
+describe("parsePrefix()", () => {
+
+    it("should stop after the first value", () => {
+        const text = "{ \"a\": 1 }garbage";
+        const { ast, offset } = parsePrefix(text);
+
+        expect(ast.body.type).to.equal("Object");
+        expect(offset).to.equal(10);
+        expect(text.slice(offset)).to.equal("garbage");
+    });
+
+    it("should ignore text after the value that can't be tokenized", () => {
+        const text = "// config\n{ \"draft\": true }\n---\n# Title 'quoted'\n";
+        const { ast, offset } = parsePrefix(text, { comments: true, tokens: true });
+
+        expect(ast.body.members[0].value.value).to.be.true;
+        expect(offset).to.equal(27);
+        expect(text.slice(offset)).to.equal("\n---\n# Title 'quoted'\n");
+        expect(ast.tokens.map(token => token.value)).to.deep.equal([
+            "// config", "{", "\"draft\"", ":", "true", "}"
+        ]);
+    });
+
+    it("should ignore a second value", () => {
+        const text = "[1, 2] [3]";
+        const { ast, offset } = parsePrefix(text, { tokens: true });
+
+        expect(ast.body.elements).to.have.lengthOf(2);
+        expect(offset).to.equal(6);
+        expect(ast.tokens).to.have.lengthOf(5);
+    });
+
+    it("should ignore the eof option", () => {
+        const { ast } = parsePrefix("true false", { tokens: true, eof: true });
+        expect(ast.tokens.map(token => token.type)).to.deep.equal(["Boolean"]);
+    });
+
+    it("should throw an error from inside the value", () => {
+        expect(() => {
+            parsePrefix("{ \"a\": 1, }\n---");
+        }).to.throw("Unexpected token Punctuator(}) found. (1:11)");
+    });
+
+    it("should keep the context of a tokenizer error after a complete member", () => {
+        expect(() => {
+            parsePrefix("{\"a\": 1 @} x");
+        }).to.throw("Unexpected character @ found. Occurred while parsing value of \"a\" at line 1. (1:9)");
+        expect(() => {
+            parsePrefix("{\"a\": @} x");
+        }).to.throw("Unexpected character @ found. Occurred while parsing value of \"a\" at line 1. (1:7)");
+    });
+
+    it("should throw the tokenizer error when the value isn't complete", () => {
+        expect(() => {
+            parsePrefix("{ \"a\": 'b' }");
+        }).to.throw("Unexpected character ' found. Occurred while parsing value of \"a\" at line 1. (1:8)");
+    });
+
+    it("should throw an error when the text doesn't start with a value", () => {
+        expect(() => {
+            parsePrefix("# Title");
+        }).to.throw("Unexpected character # found. (1:1)");
+    });
+
+});
+
+describe("parseSequence()", () => {
+
+    const RS = "\u001e";
+
+    function parseAll(text, options) {
+        const values = [];
+
+        try {
+            for (const document of parseSequence(text, options)) {
+                values.push(document);
+            }
+        } catch (ex) {
+            values.push(ex);
+        }
+
+        return values;
+    }
+
+    it("should return a document for each record", () => {
+        const documents = parseAll(`${ RS }{"a": 1}\n${ RS }[2,\n3]\n`);
+
+        expect(documents).to.have.lengthOf(2);
+        expect(documents[0].body.type).to.equal("Object");
+        expect(documents[1].body.type).to.equal("Array");
+    });
+
+    it("should use locations relative to the whole text", () => {
+        const text = `${ RS }1\n${ RS } [true]\n`;
+        const [, document] = parseAll(text, { ranges: true });
+
+        expect(document.loc).to.deep.equal({
+            start: { line: 2, column: 2, offset: 4 },
+            end: { line: 2, column: 9, offset: 11 }
+        });
+        expect(document.body.elements[0].range).to.deep.equal([6, 10]);
+        expect(text.slice(6, 10)).to.equal("true");
+    });
+
+    it("should only include the tokens and comments in each record", () => {
+        const [first, second] = parseAll(`${ RS }1 // a\n${ RS }/* b */ 2\n`, { tokens: true, comments: true });
+
+        expect(first.tokens.map(token => token.value)).to.deep.equal(["1", "// a"]);
+        expect(second.comments.map(comment => comment.value)).to.deep.equal(["/* b */"]);
+    });
+
+    it("should ignore consecutive record separators", () => {
+        const documents = parseAll(`${ RS }${ RS }1\n${ RS }${ RS }${ RS }2`);
+
+        expect(documents.map(document => document.body.value)).to.deep.equal([1, 2]);
+    });
+
+    it("should return nothing for an empty sequence", () => {
+        expect(parseAll("")).to.deep.equal([]);
+        expect(parseAll(RS)).to.deep.equal([]);
+    });
+
+    it("should return the records before an error", () => {
+        const [first, error] = parseAll(`${ RS }1\n${ RS }[1,\n${ RS }2`);
+
+        expect(first.body.value).to.equal(1);
+        expect(error.code).to.equal("UnexpectedEOF");
+        expect(error.message).to.equal("Unexpected end of input found. (2:5)");
+    });
+
+    it("should throw an error for text that can't be tokenized in a record", () => {
+        const [first, error] = parseAll(`${ RS }1\n${ RS }2 'x'\n${ RS }3`);
+
+        expect(first.body.value).to.equal(1);
+        expect(error.message).to.equal("Unexpected character ' found. (2:4)");
+    });
+
+    it("should throw an error for more than one value in a record", () => {
+        const [error] = parseAll(`${ RS }1 2`);
+
+        expect(error.message).to.equal("Unexpected token Number(2) found. (1:4)");
+    });
+
+    it("should throw an error when the text doesn't start with a record separator", () => {
+        const [error] = parseAll(`1\n${ RS }2`, { sourceName: "log.json-seq" });
+
+        expect(error.message).to.equal("Unexpected token Number(1) found. (log.json-seq:1:1)");
+    });
+
+});
+
+describe("createParser()", () => {
+
+    it("should throw an error for the tolerant option", () => {
+        expect(() => {
+            createParser("[1 2", { tolerant: true });
+        }).to.throw(TypeError, "The tolerant option can't be used with createParser().");
+    });
+
+    it("should read tokens one at a time", () => {
+        const parser = createParser("[1, true]");
+
+        expect(parser.offset).to.equal(0);
+        expect(parser.peek().value).to.equal("[");
+        expect(parser.next().value).to.equal("[");
+        expect(parser.next().value).to.equal("1");
+        expect(parser.offset).to.equal(2);
+        expect(parser.next().value).to.equal(",");
+        expect(parser.next().value).to.equal("true");
+        expect(parser.next().value).to.equal("]");
+        expect(parser.next()).to.be.null;
+        expect(parser.peek()).to.be.null;
+        expect(parser.offset).to.equal(9);
+    });
+
+    it("should include comment tokens when comments are enabled", () => {
+        const parser = createParser("// a\n1", { comments: true });
+
+        expect(parser.next().type).to.equal("LineComment");
+        expect(parser.next().type).to.equal("Number");
+    });
+
+    it("should parse a value with the same locations as parse()", () => {
+        const text = "[\n {\"a\": [1, 2]} // b\n, 3]";
+        const parser = createParser(text, { comments: true });
+
+        parser.next();
+
+        const node = parser.parseValue();
+
+        expect(node).to.deep.equal(parse(text, { comments: true }).body.elements[0]);
+        expect(parser.offset).to.equal(17);
+        expect(parser.next().type).to.equal("LineComment");
+    });
+
+    it("should return to a checkpoint", () => {
+        const parser = createParser("{\"a\": 1} {\"b\": 2}");
+        const checkpoint = parser.checkpoint();
+
+        expect(parser.parseValue().type).to.equal("Object");
+        expect(parser.offset).to.equal(9);
+
+        parser.rewind(checkpoint);
+
+        expect(parser.offset).to.equal(0);
+        expect(parser.next().value).to.equal("{");
+    });
+
+    it("should throw an error for a checkpoint from another parser", () => {
+        const checkpoint = createParser("1").checkpoint();
+
+        expect(() => {
+            createParser("1").rewind(checkpoint);
+        }).to.throw("The checkpoint wasn't created by this parser.");
+    });
+
+    it("should only throw a tokenizing error when it's reached", () => {
+        const parser = createParser("[1, 'x']");
+
+        parser.next();
+        expect(parser.parseValue().value).to.equal(1);
+        expect(parser.next().value).to.equal(",");
+        expect(parser.offset).to.equal(4);
+        expect(() => parser.next()).to.throw("Unexpected character ' found. (1:5)");
+        expect(() => parser.peek()).to.throw("Unexpected character ' found. (1:5)");
+        expect(parser.offset).to.equal(4);
+    });
+
+    it("should parse values one after another", () => {
+        const parser = createParser("1 [2] {\"a\": 3} // c\n\"d\"", { comments: true });
+        const values = [];
+
+        while (parser.peek()) {
+            if (parser.peek().type === "LineComment") {
+                parser.next();
+            } else {
+                values.push(evaluate(parser.parseValue()));
+            }
+        }
+
+        expect(values).to.deep.equal([1, [2], { a: 3 }, "d"]);
+    });
+
+    it("should throw an error at the end of the text when there are no more values", () => {
+        const parser = createParser("[1]\n2 ");
+
+        parser.parseValue();
+        parser.parseValue();
+
+        let error;
+
+        try {
+            parser.parseValue();
+        } catch (ex) {
+            error = ex;
+        }
+
+        expect(error.code).to.equal("UnexpectedEOF");
+        expect(error.message).to.equal("Unexpected end of input found. (2:3)");
+    });
+
+    it("should count grapheme columns in errors after earlier text", () => {
+        const parser = createParser("[\"e\u0301\u{1F600}\", {1}]", { graphemeColumns: true });
+
+        parser.next();
+        parser.next();
+        parser.next();
+
+        expect(() => parser.parseValue()).to.throw("Object member names must be strings but found Number(1). (1:9)");
+    });
+
+    it("should not change a tokenizing error that's thrown more than once", () => {
+        const parser = createParser("[1, 'x'", { sourceName: "a.json" });
+
+        expect(() => parser.parseValue()).to.throw(/^Unexpected character ' found\. \(a\.json:1:5\)$/);
+        expect(() => parser.parseValue()).to.throw(/^Unexpected character ' found\. \(a\.json:1:5\)$/);
+        parser.next();
+        parser.next();
+        parser.next();
+        expect(() => parser.next()).to.throw(/^Unexpected character ' found\. \(a\.json:1:5\)$/);
+    });
+
+    it("should update the stack of an error when details are added to its message", () => {
+        const parser = createParser("{\"a\": 'b'}", { sourceName: "a.json" });
+        let error;
+
+        try {
+            parser.parseValue();
+        } catch (ex) {
+            error = ex;
+        }
+
+        expect(error.message).to.equal("Unexpected character ' found. Occurred while parsing value of \"a\" at line 1. (a.json:1:7)");
+        expect(error.stack.split("\n")[0]).to.equal(`Error: ${ error.message }`);
+    });
+
+    it("should keep its position when a value can't be parsed", () => {
+        const parser = createParser("[1, }");
+        const checkpoint = parser.checkpoint();
+
+        expect(() => parser.parseValue()).to.throw("Mismatched closing bracket } found; array opened at 1:1. (1:5)");
+        expect(parser.checkpoint()).to.deep.equal(checkpoint);
+    });
+
+});
+
+describe("parseTolerant()", () => {
+
+    it("should return the AST and the errors that were recovered from", () => {
+        const text = "{\"a\": [1 2], \"b\": 3";
+        const { ast, errors } = parseTolerant(text, { sourceName: "a.json" });
+
+        expect(evaluate(ast)).to.deep.equal({ a: [1, 2], b: 3 });
+        expect(errors.map(error => error.message)).to.deep.equal([
+            "Unexpected token Number(2) found. Occurred while parsing value of \"a\" at line 1. (a.json:1:10)",
+            "Unexpected end of input found. (a.json:1:20)"
+        ]);
+        expect(errors[0].message).to.equal(getError(text, { sourceName: "a.json" }).message);
+    });
+
+    it("should return no errors for valid JSON", () => {
+        const { ast, errors } = parseTolerant("[1, 2]");
+
+        expect(evaluate(ast)).to.deep.equal([1, 2]);
+        expect(errors).to.deep.equal([]);
+    });
+
+    it("should return errors with the locations and codes an editor needs", () => {
+        const { errors } = parseTolerant("[1\n2, {\"a\": 3");
+
+        expect(errors.map(({ code, line, column, index }) => ({ code, line, column, index }))).to.deep.equal([
+            { code: "UnexpectedToken", line: 2, column: 1, index: 3 },
+            { code: "UnexpectedEOF", line: 2, column: 11, index: 13 },
+            { code: "UnexpectedEOF", line: 2, column: 11, index: 13 }
+        ]);
+    });
+
+    it("should return the synthetic tokens for each assumed punctuator", () => {
+        const { ast } = parseTolerant("[1 2", { tokens: true });
+
+        expect(ast.tokens.filter(token => token.synthetic).map(token => token.value)).to.deep.equal([",", "]"]);
+    });
+
+    it("should return a copy of the errors in the parse info", () => {
+        const { ast, errors } = parseTolerant("[1 2");
+
+        errors.pop();
+        expect(getParseInfo(ast).errors).to.have.lengthOf(2);
+    });
+
+    it("should throw errors that can't be recovered from", () => {
+        expect(() => {
+            parseTolerant("{ \"a\" 1 }");
+        }).to.throw("Unexpected token Number(1) found. (1:7)");
+    });
+
+});
+
+describe("parseAuto()", () => {
+
+    it("should use the options as given for small input", () => {
+        const result = parseAuto("[1, 2]", { tokens: true, ranges: true });
+        expect(result.tokens).to.have.lengthOf(5);
+        expect(result.body.range).to.deep.equal([0, 6]);
+    });
+
+    it("should turn off tokens and ranges for large input", () => {
+        const result = parseAuto("[1, 2]", { tokens: true, ranges: true, thresholds: { tokens: 6, ranges: 6 } });
+        expect(result.tokens).to.be.undefined;
+        expect(result.body.range).to.be.undefined;
+        expect(getParseInfo(result).options).to.include({ tokens: false, ranges: false });
+    });
+
+    it("should use each threshold separately", () => {
+        const result = parseAuto("[1, 2]", { tokens: true, ranges: true, thresholds: { tokens: 7, ranges: 6 } });
+        expect(result.tokens).to.have.lengthOf(5);
+        expect(result.body.range).to.be.undefined;
+    });
+
+    it("should not include the thresholds in the parse options", () => {
+        const result = parseAuto("1", { thresholds: { tokens: 0 } });
+        expect(getParseInfo(result).options).to.not.have.property("thresholds");
+    });
+
+    it("should throw the same errors as parse()", () => {
+        expect(() => {
+            parseAuto("{1}");
+        }).to.throw("Object member names must be strings but found Number(1). (1:2)");
+    });
+
+});
+
+describe("getParseInfo()", () => {
+
+    it("should return the json mode and the options with defaults", () => {
+        const info = getParseInfo(parse("{}", { tokens: true }));
+
+        expect(info.mode).to.equal("json");
+        expect(info.options.tokens).to.be.true;
+        expect(info.options.comments).to.be.false;
+        expect(Object.isFrozen(info.options)).to.be.true;
+    });
+
+    it("should return the jsonc mode when comments are allowed", () => {
+        expect(getParseInfo(parse("1 // one", { comments: true })).mode).to.equal("jsonc");
+    });
+
+    it("should return the options for documents from other parse functions", () => {
+        expect(getParseInfo(parsePrefix("[1] x", { ranges: true }).ast).options.ranges).to.be.true;
+        expect(getParseInfo(parseBytes(Buffer.from("1"), { sourceName: "a.json" })).options.sourceName).to.equal("a.json");
+        expect([...parseSequence("\u001e1\n", { comments: true })].map(document => getParseInfo(document).mode)).to.deep.equal(["jsonc"]);
+    });
+
+    it("should not add anything to the serialized document", () => {
+        expect(JSON.parse(JSON.stringify(parse("1")))).to.not.have.property("options");
+    });
+
+    it("should return the line count and the location of the end of the text", () => {
+        const info = getParseInfo(parse("{\r\n  \"a\": 1\r\n}\n"));
+
+        expect(info.lineCount).to.equal(4);
+        expect(info.endLocation).to.deep.equal({ line: 4, column: 1, offset: 15 });
+    });
+
+    it("should include text after the value for parsePrefix()", () => {
+        const info = getParseInfo(parsePrefix("{}\n---\nmore").ast);
+
+        expect(info.lineCount).to.equal(3);
+        expect(info.endLocation).to.deep.equal({ line: 3, column: 5, offset: 11 });
+    });
+
+    it("should only include the record for parseSequence()", () => {
+        const text = "\u001e[1,\n2]\n\u001e{\n}\n\n";
+
+        for (const offsetsOnly of [false, true]) {
+            const infos = [...parseSequence(text, { offsetsOnly })].map(getParseInfo);
+
+            expect(infos.map(info => info.lineCount)).to.deep.equal([3, 4]);
+            expect(infos.map(info => info.endLocation)).to.deep.equal([
+                { line: 3, column: 1, offset: 8 },
+                { line: 6, column: 1, offset: 14 }
+            ]);
+        }
+    });
+
+    it("should return null for a value from createParser()", () => {
+        expect(getParseInfo(createParser("[1]").parseValue())).to.be.null;
+    });
+
+    it("should return the line count when lines weren't counted during parsing", () => {
+        const text = "[\n1,\r2]  ";
+        const info = getParseInfo(parse(text, { offsetsOnly: true }));
+
+        expect(info.endLocation).to.deep.equal(getParseInfo(parse(text)).endLocation);
+        expect(info.lineCount).to.equal(3);
+    });
+
+    it("should return a line count of 1 for an empty document", () => {
+        expect(getParseInfo(parse("", { allowEmpty: true })).lineCount).to.equal(1);
+    });
+
+    it("should return null for a document that wasn't parsed", () => {
+        expect(getParseInfo(fromValue(1))).to.be.null;
+    });
+
+});
@@ -0,0 +1,439 @@
+/**
+ * @fileoverview Tests for JSON Pointer helpers
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+const { parse, evaluate, parsePointer, formatPointer, resolvePointer, resolvePointers, createPointerIndex, iterateWithPointers, iterateStrings, resolveReferences, iterator } = require("../api");
+const { expect } = require("chai");
+
+//-----------------------------------------------------------------------------
+// Data
+//-----------------------------------------------------------------------------
+
+// example from RFC 6901, section 5
+const rfcText = `{
+    "foo": ["bar", "baz"],
+    "": 0,
+    "a/b": 1,
+    "c%d": 2,
+    "e^f": 3,
+    "g|h": 4,
+    "i\\\\j": 5,
+    "k\\"l": 6,
+    " ": 7,
+    "m~n": 8
+}`;
+
+const rfcPointers = new Map([
+    ["", JSON.parse(rfcText)],
+    ["/foo", ["bar", "baz"]],
+    ["/foo/0", "bar"],
+    ["/", 0],
+    ["/a~1b", 1],
+    ["/c%d", 2],
+    ["/e^f", 3],
+    ["/g|h", 4],
+    ["/i\\j", 5],
+    ["/k\"l", 6],
+    ["/ ", 7],
+    ["/m~0n", 8]
+]);
+
+//-----------------------------------------------------------------------------
+// Tests
+//-----------------------------------------------------------------------------
+
+describe("parsePointer()", () => {
+
+    it("should return an empty array for the empty pointer", () => {
+        expect(parsePointer("")).to.deep.equal([]);
+    });
+
+    it("should return an empty segment for \"/\"", () => {
+        expect(parsePointer("/")).to.deep.equal([""]);
+    });
+
+    it("should unescape ~0 and ~1", () => {
+        expect(parsePointer("/a~1b/m~0n/~01")).to.deep.equal(["a/b", "m~n", "~1"]);
+    });
+
+    it("should throw an error when the pointer doesn't start with /", () => {
+        expect(() => {
+            parsePointer("foo");
+        }).to.throw("JSON Pointer \"foo\" must be empty or start with \"/\".");
+    });
+
+    it("should throw an error when the pointer has an invalid escape", () => {
+        expect(() => {
+            parsePointer("/a~2");
+        }).to.throw("Invalid escape in JSON Pointer \"/a~2\".");
+    });
+
+});
+
+describe("formatPointer()", () => {
+
+    it("should return an empty string for an empty path", () => {
+        expect(formatPointer([])).to.equal("");
+    });
+
+    it("should escape ~ and /", () => {
+        expect(formatPointer(["a/b", "m~n", "~1", 0, ""])).to.equal("/a~1b/m~0n/~01/0/");
+    });
+
+    it("should round-trip with parsePointer()", () => {
+        const path = ["", "/", "~", "~/~1", "0"];
+        expect(parsePointer(formatPointer(path))).to.deep.equal(path);
+    });
+
+});
+
+describe("resolvePointer()", () => {
+
+    const ast = parse(rfcText);
+
+    for (const [pointer, value] of rfcPointers) {
+        it(`should resolve "${ pointer }"`, () => {
+            expect(evaluate(resolvePointer(ast, pointer))).to.deep.equal(value);
+        });
+    }
+
+    it("should accept an array of segments", () => {
+        expect(evaluate(resolvePointer(ast, ["foo", 1]))).to.equal("baz");
+    });
+
+    it("should resolve from a node other than a document", () => {
+        expect(evaluate(resolvePointer(ast.body.members[0].value, "/1"))).to.equal("baz");
+    });
+
+    it("should return undefined when the pointer doesn't match", () => {
+        expect(resolvePointer(ast, "/missing")).to.be.undefined;
+        expect(resolvePointer(ast, "/foo/2")).to.be.undefined;
+        expect(resolvePointer(ast, "/foo/-")).to.be.undefined;
+        expect(resolvePointer(ast, "/foo/01")).to.be.undefined;
+        expect(resolvePointer(ast, "/foo/0/bar")).to.be.undefined;
+        expect(resolvePointer(ast, "/missing/deeper")).to.be.undefined;
+    });
+
+    it("should use the last member when a name is duplicated", () => {
+        const result = resolvePointer(parse("{ \"a\": 1, \"a\": 2 }"), "/a");
+        expect(result.value).to.equal(2);
+    });
+
+    describe("edge-case member names", () => {
+
+        it("should resolve an empty member name", () => {
+            const result = resolvePointer(parse("{ \"\": { \"\": true } }"), "//");
+            expect(result.value).to.be.true;
+        });
+
+        it("should resolve a member name made entirely of escapes", () => {
+            const text = "{ \"\\u0061\\u002F\\u007e\": 1, \"\\\\\\/\\\"\": 2 }";
+            const ast = parse(text);
+
+            expect(ast.body.members[0].name.value).to.equal("a/~");
+            expect(ast.body.members[1].name.value).to.equal("\\/\"");
+            expect(resolvePointer(ast, "/a~1~0").value).to.equal(1);
+            expect(resolvePointer(ast, formatPointer(["\\/\""])).value).to.equal(2);
+        });
+
+        it("should not treat an escaped / in a member name as a separator", () => {
+            const ast = parse("{ \"a\": { \"b\": 1 }, \"a/b\": 2 }");
+
+            expect(resolvePointer(ast, "/a/b").value).to.equal(1);
+            expect(resolvePointer(ast, "/a~1b").value).to.equal(2);
+        });
+
+    });
+
+});
+
+describe("resolvePointers()", () => {
+
+    const ast = parse(rfcText);
+
+    it("should resolve the same nodes as resolvePointer()", () => {
+        const pointers = [...rfcPointers.keys()];
+        const expected = pointers.map(pointer => resolvePointer(ast, pointer));
+
+        expect(resolvePointers(ast, pointers)).to.deep.equal(expected);
+        resolvePointers(ast, pointers).forEach((node, i) => {
+            expect(node).to.equal(expected[i]);
+        });
+    });
+
+    it("should return undefined for pointers that don't match", () => {
+        const result = resolvePointers(ast, ["/foo/1", "/missing", "/foo/5", "/foo/-", "/foo/0/x", "/foo"]);
+
+        expect(result.map(node => node && evaluate(node))).to.deep.equal([
+            "baz",
+            undefined,
+            undefined,
+            undefined,
+            undefined,
+            ["bar", "baz"]
+        ]);
+    });
+
+    it("should resolve duplicate pointers and arrays of segments", () => {
+        const result = resolvePointers(ast, ["/foo/0", ["foo", 0], "/foo/0"]);
+        expect(result[0]).to.equal(result[1]);
+        expect(result[0]).to.equal(result[2]);
+    });
+
+    it("should use the last member when a name is duplicated", () => {
+        const result = resolvePointers(parse("{ \"a\": 1, \"a\": 2 }"), ["/a"]);
+        expect(result[0].value).to.equal(2);
+    });
+
+    it("should return undefined for every pointer in an empty document", () => {
+        const result = resolvePointers(parse("", { allowEmpty: true }), ["", "/a"]);
+        expect(result).to.deep.equal([undefined, undefined]);
+    });
+
+    it("should return an empty array when there are no pointers", () => {
+        expect(resolvePointers(ast, [])).to.deep.equal([]);
+    });
+
+    it("should throw an error when a pointer is invalid", () => {
+        expect(() => {
+            resolvePointers(ast, ["/foo", "bar"]);
+        }).to.throw("JSON Pointer \"bar\" must be empty or start with \"/\".");
+    });
+
+});
+
+describe("createPointerIndex()", () => {
+
+    const ast = parse(rfcText);
+
+    it("should include every node in the tree", () => {
+        const index = createPointerIndex(ast);
+        const nodes = [...iterator(ast, ({ phase }) => phase === "enter")].map(({ node }) => node);
+
+        expect(index.size).to.equal(nodes.length);
+        nodes.forEach(node => expect(index.has(node)).to.be.true);
+    });
+
+    it("should map each value to a pointer that resolves to it", () => {
+        const index = createPointerIndex(ast);
+        const names = new Set(ast.body.members.map(member => member.name));
+
+        for (const [node, { pointer, path }] of index) {
+            if (node.type !== "Member" && node.type !== "Document" && !names.has(node)) {
+                expect(resolvePointer(ast, pointer)).to.equal(node);
+                expect(resolvePointer(ast, path)).to.equal(node);
+            }
+        }
+    });
+
+    it("should map members and their names to the pointer of the value", () => {
+        const index = createPointerIndex(ast);
+        const member = ast.body.members[2];
+
+        expect(index.get(member)).to.deep.equal({ pointer: "/a~1b", path: ["a/b"], depth: 1 });
+        expect(index.get(member.name)).to.equal(index.get(member));
+    });
+
+    it("should map the document and its body to the empty pointer", () => {
+        const index = createPointerIndex(ast);
+
+        expect(index.get(ast)).to.deep.equal({ pointer: "", path: [], depth: 0 });
+        expect(index.get(ast.body)).to.deep.equal({ pointer: "", path: [], depth: 0 });
+    });
+
+    it("should use numbers for array indices in paths", () => {
+        const index = createPointerIndex(ast);
+        expect(index.get(ast.body.members[0].value.elements[1])).to.deep.equal({ pointer: "/foo/1", path: ["foo", 1], depth: 2 });
+    });
+
+    it("should count the containers around each node as its depth", () => {
+        const nested = parse("{ \"a\": [[{ \"b\": 1 }]] }");
+        const index = createPointerIndex(nested);
+        const inner = nested.body.members[0].value.elements[0].elements[0];
+
+        expect(index.get(nested.body.members[0].value).depth).to.equal(1);
+        expect(index.get(inner).depth).to.equal(3);
+        expect(index.get(inner.members[0].value).depth).to.equal(4);
+        expect(Math.max(...[...index.values()].map(({ depth }) => depth))).to.equal(4);
+    });
+
+    it("should handle an empty document", () => {
+        const index = createPointerIndex(parse("", { allowEmpty: true }));
+        expect(index.size).to.equal(1);
+    });
+
+});
+
+describe("iterateWithPointers()", () => {
+
+    it("should yield every value in document order", () => {
+        const ast = parse("{ \"a\": [1, { \"b\": null }], \"c\": \"d\" }");
+        const result = [...iterateWithPointers(ast)].map(({ node, pointer, depth }) => [node.type, pointer, depth]);
+
+        expect(result).to.deep.equal([
+            ["Object", "", 0],
+            ["Array", "/a", 1],
+            ["Number", "/a/0", 2],
+            ["Object", "/a/1", 2],
+            ["Null", "/a/1/b", 3],
+            ["String", "/c", 1]
+        ]);
+    });
+
+    it("should yield pointers and paths that resolve to each node", () => {
+        const ast = parse(rfcText);
+        const results = [...iterateWithPointers(ast)];
+
+        expect(results).to.have.lengthOf(rfcPointers.size + 1);
+
+        for (const { node, pointer, path } of results) {
+            expect(resolvePointer(ast, pointer)).to.equal(node);
+            expect(resolvePointer(ast, path)).to.equal(node);
+        }
+    });
+
+    it("should match the entries in createPointerIndex()", () => {
+        const ast = parse(rfcText);
+        const index = createPointerIndex(ast);
+
+        for (const { node, pointer, path, depth } of iterateWithPointers(ast)) {
+            expect(index.get(node)).to.deep.equal({ pointer, path, depth });
+        }
+    });
+
+    it("should start from a node other than a document", () => {
+        const ast = parse("{ \"a\": [true] }");
+        const result = [...iterateWithPointers(ast.body.members[0].value)].map(({ pointer }) => pointer);
+
+        expect(result).to.deep.equal(["", "/0"]);
+    });
+
+    it("should yield nothing for an empty document", () => {
+        const ast = parse("", { allowEmpty: true });
+        expect([...iterateWithPointers(ast)]).to.deep.equal([]);
+    });
+
+});
+
+describe("iterateStrings()", () => {
+
+    it("should yield every string value in document order", () => {
+        const ast = parse("{ \"a\": [\"x\", 1, { \"b\": \"y\\u0021\" }], \"c\": \"z\" }");
+        const result = [...iterateStrings(ast)].map(({ value, pointer, isName }) => [value, pointer, isName]);
+
+        expect(result).to.deep.equal([
+            ["x", "/a/0", false],
+            ["y!", "/a/2/b", false],
+            ["z", "/c", false]
+        ]);
+    });
+
+    it("should include member names when names is true", () => {
+        const ast = parse("{ \"a/b\": \"x\", \"c\": [\"y\"] }");
+        const result = [...iterateStrings(ast, { names: true })].map(({ value, pointer, isName }) => [value, pointer, isName]);
+
+        expect(result).to.deep.equal([
+            ["a/b", "/a~1b", true],
+            ["x", "/a~1b", false],
+            ["c", "/c", true],
+            ["y", "/c/0", false]
+        ]);
+    });
+
+    it("should yield the location and node of each string", () => {
+        const ast = parse("[1, \"ab\"]");
+        const [result] = [...iterateStrings(ast)];
+
+        expect(result.node).to.equal(ast.body.elements[1]);
+        expect(result.loc).to.deep.equal({
+            start: { line: 1, column: 5, offset: 4 },
+            end: { line: 1, column: 9, offset: 8 }
+        });
+    });
+
+    it("should yield a root string", () => {
+        expect([...iterateStrings(parse("\"a\""))].map(({ pointer }) => pointer)).to.deep.equal([""]);
+    });
+
+    it("should yield nothing for an empty document", () => {
+        const ast = parse("", { allowEmpty: true });
+        expect([...iterateStrings(ast)]).to.deep.equal([]);
+    });
+
+});
+
+describe("resolveReferences()", () => {
+
+    const text = `{
+    "definitions": {
+        "a/b": { "type": "string" },
+        "c d": { "type": "number" }
+    },
+    "properties": {
+        "x": { "$ref": "#/definitions/a~1b" },
+        "y": { "$ref": "#/definitions/c%20d" },
+        "z": { "$ref": "\\/definitions\\/a~1b" },
+        "w": { "$ref": "other.json#/definitions/a" },
+        "v": { "$ref": "#/definitions/missing" }
+    }
+}`;
+
+    it("should resolve references to the same document", () => {
+        const ast = parse(text);
+        const definitions = ast.body.members[0].value;
+        const result = resolveReferences(ast);
+
+        expect(result.map(({ pointer }) => pointer)).to.deep.equal([
+            "/definitions/a~1b",
+            "/definitions/c d",
+            "/definitions/a~1b",
+            "/definitions/missing"
+        ]);
+        expect(result[0].target).to.equal(definitions.members[0].value);
+        expect(result[1].target).to.equal(definitions.members[1].value);
+        expect(result[2].target).to.equal(definitions.members[0].value);
+        expect(result[0].error).to.be.null;
+    });
+
+    it("should include the locations of the reference and the target", () => {
+        const [result] = resolveReferences(parse(text));
+
+        expect(result.node.loc.start).to.deep.equal({ line: 7, column: 24, offset: 146 });
+        expect(result.target.loc.start).to.deep.equal({ line: 3, column: 16, offset: 38 });
+    });
+
+    it("should return a null target when nothing matches", () => {
+        const result = resolveReferences(parse(text));
+        expect(result[3].target).to.be.null;
+        expect(result[3].error).to.be.null;
+    });
+
+    it("should return an error for an invalid pointer", () => {
+        const [escape, encoding] = resolveReferences(parse("[{ \"$ref\": \"#/a~2\" }, { \"$ref\": \"#/%E0\" }]"));
+
+        expect(escape.pointer).to.be.null;
+        expect(escape.target).to.be.null;
+        expect(escape.error).to.be.instanceOf(SyntaxError);
+        expect(escape.error.message).to.equal("Invalid escape in JSON Pointer \"/a~2\".");
+        expect(encoding.error.message).to.equal("Invalid percent-encoding in JSON Pointer \"#/%E0\".");
+    });
+
+    it("should resolve a fragment for the whole document", () => {
+        const ast = parse("{ \"$ref\": \"#\" }");
+        expect(resolveReferences(ast)[0].target).to.equal(ast.body);
+    });
+
+    it("should check the values of other keys", () => {
+        const ast = parse("{ \"a\": 1, \"link\": \"/a\", \"$ref\": \"/a\", \"b\": [\"/a\"] }");
+        const result = resolveReferences(ast, { keys: ["link"] });
+
+        expect(result.map(({ node }) => node)).to.deep.equal([ast.body.members[1].value]);
+        expect(evaluate(result[0].target)).to.equal(1);
+    });
+
+});
@@ -8,7 +8,7 @@
 // Imports
 //-----------------------------------------------------------------------------
 
-const { parse, print } = require("../");
+const { parse, print, preview, fromValue, getRawText, sliceText } = require("../");
 const { expect } = require("chai");
 
 //-----------------------------------------------------------------------------
@@ -54,6 +54,195 @@ describe("print()", () => {
             });
         }
 
+        it("should print escaped solidus characters unescaped without the source", () => {
+            expect(print(parse("[\"a\\/b\"]"))).to.equal("[\"a/b\"]");
+        });
+
+    });
+
+    describe("source", () => {
+
+        const source = `{
+    "name" : "momoa",
+    "values": [ 1.0, 2e0 ],
+    "nested": {"a":true}
+}`;
+
+        it("should return the source when nothing was modified", () => {
+            expect(print(parse(source), { indent: 4, source })).to.equal(source);
+        });
+
+        it("should copy unmodified subtrees from the source", () => {
+            const ast = parse(source);
+            const values = ast.body.members[1].value;
+            values.elements.push(fromValue(3).body);
+
+            expect(print(ast, { indent: 2, source, modified: [values] })).to.equal(`{
+  "name" : "momoa",
+  "values": [
+    1.0,
+    2e0,
+    3
+  ],
+  "nested": {"a":true}
+}`);
+        });
+
+        it("should print modified scalar nodes", () => {
+            const ast = parse(source);
+            const name = ast.body.members[0].value;
+            name.value = "other";
+
+            expect(print(ast, { source, modified: [name] })).to.equal("{\"name\":\"other\",\"values\": [ 1.0, 2e0 ],\"nested\": {\"a\":true}}");
+        });
+
+        it("should keep escaped solidus characters in modified strings", () => {
+            const text = "[\"a\\/b\", \"c/d\"]";
+            const ast = parse(text);
+            const [first, second] = ast.body.elements;
+            first.value = "x/y";
+            second.value = "z/w";
+
+            expect(print(ast, { source: text, modified: [first, second] })).to.equal("[\"x\\/y\",\"z/w\"]");
+        });
+
+        it("should keep a shebang line from the source", () => {
+            const text = "#!/usr/bin/env node\n{\"a\": 1}";
+            const ast = parse(text, { shebang: true });
+            const value = ast.body.members[0].value;
+            value.value = 2;
+
+            expect(print(ast, { source: text, modified: [value] })).to.equal("#!/usr/bin/env node\n{\"a\":2}");
+        });
+
+        it("should keep a byte order mark and a shebang line from the source", () => {
+            const text = "\uFEFF#!/usr/bin/env node\n{\"a\": 1}";
+            const ast = parse(text, { bom: true, shebang: true });
+            const value = ast.body.members[0].value;
+            value.value = 2;
+
+            expect(print(ast, { source: text, modified: [value] })).to.equal("\uFEFF#!/usr/bin/env node\n{\"a\":2}");
+        });
+
+        it("should drop comments inside of modified containers", () => {
+            const text = "{\n  // the name\n  \"name\": \"a\",\n  \"nested\": [1 /* one */]\n}";
+            const ast = parse(text, { comments: true });
+            const name = ast.body.members[0].value;
+            name.value = "b";
+
+            expect(print(ast, { source: text, modified: [name] })).to.equal("{\"name\":\"b\",\"nested\": [1 /* one */]}");
+        });
+
+        it("should print nodes without a source location", () => {
+            const ast = fromValue({ a: [1e21, null] });
+            expect(print(ast, { source: "" })).to.equal("{\"a\":[1e+21,null]}");
+        });
+
+    });
+
+    describe("getRawText()", () => {
+
+        it("should return the source text of a node", () => {
+            const text = "{ \"a\": [ 1.50 , \"\\u0062\" ] }";
+            const ast = parse(text);
+            const array = ast.body.members[0].value;
+
+            expect(getRawText(array, text)).to.equal("[ 1.50 , \"\\u0062\" ]");
+            expect(getRawText(array.elements[0], text)).to.equal("1.50");
+            expect(getRawText(ast.body.members[0], text)).to.equal("\"a\": [ 1.50 , \"\\u0062\" ]");
+        });
+
+        it("should throw an error when the source doesn't match the node", () => {
+            const ast = parse("{ \"a\": \"😀\" }");
+
+            expect(() => {
+                getRawText(ast.body, "{}");
+            }).to.throw(RangeError, "Range [0, 13] is outside of the text (length 2).");
+
+            expect(() => {
+                getRawText(ast.body.members[0].value, "{ \"a\":😀\"\" }");
+            }).to.throw(RangeError, "Offset 7 is in the middle of a surrogate pair.");
+        });
+
+    });
+
+    describe("sliceText()", () => {
+
+        it("should return the text in a range", () => {
+            expect(sliceText("a😀b", [1, 3])).to.equal("😀");
+            expect(sliceText("abc", [3, 3])).to.equal("");
+        });
+
+        it("should throw an error when the range is invalid", () => {
+            expect(() => {
+                sliceText("abc", [2, 1]);
+            }).to.throw(RangeError, "Range [2, 1] is invalid.");
+
+            expect(() => {
+                sliceText("abc", [-1, 1]);
+            }).to.throw(RangeError, "Range [-1, 1] is invalid.");
+
+            expect(() => {
+                sliceText("abc", [0, 1.5]);
+            }).to.throw(RangeError, "Range [0, 1.5] is invalid.");
+        });
+
+        it("should throw an error when the range is past the end of the text", () => {
+            expect(() => {
+                sliceText("abc", [1, 4]);
+            }).to.throw(RangeError, "Range [1, 4] is outside of the text (length 3).");
+        });
+
+        it("should throw an error when an offset splits a surrogate pair", () => {
+            expect(() => {
+                sliceText("a😀b", [2, 4]);
+            }).to.throw(RangeError, "Offset 2 is in the middle of a surrogate pair.");
+
+            expect(() => {
+                sliceText("a😀b", [0, 2]);
+            }).to.throw(RangeError, "Offset 2 is in the middle of a surrogate pair.");
+        });
+
+    });
+
+    describe("preview()", () => {
+
+        const ast = parse("{\"a\": 1, \"b\": [true, null, \"x\\ny\"]}");
+
+        it("should render the whole node on one line when it fits", () => {
+            expect(preview(ast)).to.equal("{\"a\": 1, \"b\": [true, null, \"x\\ny\"]}");
+            expect(preview(ast.body.members[0])).to.equal("\"a\": 1");
+            expect(preview(parse("{\n  \"a\": []\n}"), 11)).to.equal("{\"a\": []}");
+        });
+
+        it("should cut off the rendering and close what's open when it doesn't fit", () => {
+            expect(preview(ast, 12)).to.equal("{\"a\": 1, …}");
+            expect(preview(ast, 20)).to.equal("{\"a\": 1, \"b\": […]}");
+            expect(preview(ast, 30)).to.equal("{\"a\": 1, \"b\": [true, null, …]}");
+            expect(preview(ast, 2)).to.equal("…");
+        });
+
+        it("should never be longer than the maximum length", () => {
+            for (let maxLength = 1; maxLength < 40; maxLength++) {
+                expect(preview(ast, maxLength).length).to.be.below(maxLength + 1);
+            }
+        });
+
+        it("should not split escapes or surrogate pairs", () => {
+            expect(preview(parse("\"a\\nb\""), 4)).to.equal("\"a…\"");
+            expect(preview(parse("\"a\\nb\""), 5)).to.equal("\"a…\"");
+            expect(preview(parse("\"a\\nbc\""), 6)).to.equal("\"a\\n…\"");
+            expect(preview(parse("\"😀😀\""), 5)).to.equal("\"😀…\"");
+        });
+
+        it("should return an empty string for an empty document", () => {
+            expect(preview(parse("", { allowEmpty: true }))).to.equal("");
+        });
+
+        it("should throw an error when the maximum length isn't a positive integer", () => {
+            expect(() => preview(ast, 0)).to.throw(RangeError, "The maximum length must be a positive integer.");
+        });
+
     });
 
-});
\ No newline at end of file
+});
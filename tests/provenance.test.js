@@ -0,0 +1,107 @@
+/**
+ * @fileoverview Tests for provenance tracking
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+const { parse, evaluate, fromValue, replace, trackProvenance, getProvenance, copyProvenance } = require("../api");
+const { expect } = require("chai");
+
+//-----------------------------------------------------------------------------
+// Tests
+//-----------------------------------------------------------------------------
+
+describe("trackProvenance()", () => {
+
+    it("should record the source and range of every node", () => {
+        const ast = trackProvenance(parse("{ \"a\": [1] }", { sourceName: "base.json" }));
+        const member = ast.body.members[0];
+
+        expect(getProvenance(ast)).to.deep.equal({ source: "base.json", range: [0, 12] });
+        expect(getProvenance(member)).to.deep.equal({ source: "base.json", range: [2, 10] });
+        expect(getProvenance(member.name)).to.deep.equal({ source: "base.json", range: [2, 5] });
+        expect(getProvenance(member.value.elements[0])).to.deep.equal({ source: "base.json", range: [8, 9] });
+    });
+
+    it("should not track nodes with synthetic locations", () => {
+        const ast = parse("[1]", { sourceName: "base.json" });
+        const added = fromValue(2).body;
+
+        ast.body.elements.push(added);
+        trackProvenance(ast);
+
+        expect(getProvenance(added)).to.be.null;
+        expect(getProvenance(ast.body.elements[0])).to.deep.equal({ source: "base.json", range: [1, 2] });
+    });
+
+    it("should use the source that's passed in", () => {
+        const ast = trackProvenance(parse("1", { sourceName: "a.json" }), "layer:1");
+        expect(getProvenance(ast.body).source).to.equal("layer:1");
+    });
+
+    it("should throw an error when there's no source", () => {
+        expect(() => {
+            trackProvenance(parse("1"));
+        }).to.throw(TypeError, "A source is required when the document has no sourceName.");
+    });
+
+});
+
+describe("getProvenance()", () => {
+
+    it("should follow nodes into other trees", () => {
+        const base = trackProvenance(parse("{ \"a\": 1, \"b\": 2 }"), "base.json");
+        const local = trackProvenance(parse("{\n  \"b\": 3\n}"), "local.json");
+
+        // merge local members over the base members
+        const names = new Set(local.body.members.map(member => member.name.value));
+        const merged = fromValue({});
+        merged.body.members = [
+            ...base.body.members.filter(member => !names.has(member.name.value)),
+            ...local.body.members
+        ];
+
+        expect(evaluate(merged)).to.deep.equal({ a: 1, b: 3 });
+        expect(merged.body.members.map(member => getProvenance(member))).to.deep.equal([
+            { source: "base.json", range: [2, 8] },
+            { source: "local.json", range: [4, 10] }
+        ]);
+    });
+
+    it("should return null for nodes that weren't tracked", () => {
+        expect(getProvenance(fromValue({ a: 1 }).body)).to.be.null;
+        expect(getProvenance(parse("1").body)).to.be.null;
+    });
+
+});
+
+describe("copyProvenance()", () => {
+
+    it("should give a replacement node the origin of the original", () => {
+        const ast = trackProvenance(parse("[1, 2]"), "list.json");
+        const original = ast.body.elements[1];
+
+        replace(ast, {
+            enter(node) {
+                if (node === original) {
+                    return copyProvenance(node, { ...node, value: 20 });
+                }
+
+                return undefined;
+            }
+        });
+
+        expect(ast.body.elements[1]).to.not.equal(original);
+        expect(getProvenance(ast.body.elements[1])).to.deep.equal({ source: "list.json", range: [4, 5] });
+    });
+
+    it("should clear the origin when the original wasn't tracked", () => {
+        const tracked = trackProvenance(parse("1"), "one.json").body;
+        copyProvenance(fromValue(2).body, tracked);
+        expect(getProvenance(tracked)).to.be.null;
+    });
+
+});
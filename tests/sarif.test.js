@@ -0,0 +1,141 @@
+/**
+ * @fileoverview Tests for toSarif()
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+const { parse, fromValue, getNumber, toSarif } = require("../api");
+const { expect } = require("chai");
+const { getError } = require("./helpers");
+
+//-----------------------------------------------------------------------------
+// Tests
+//-----------------------------------------------------------------------------
+
+describe("toSarif()", () => {
+
+    it("should create an empty log when there are no errors", () => {
+        expect(toSarif([])).to.deep.equal({
+            $schema: "https://json.schemastore.org/sarif-2.1.0.json",
+            version: "2.1.0",
+            runs: [
+                {
+                    tool: {
+                        driver: {
+                            name: "momoa",
+                            informationUri: "https://github.com/humanwhocodes/momoa",
+                            rules: []
+                        }
+                    },
+                    results: []
+                }
+            ]
+        });
+    });
+
+    it("should convert errors into results with physical locations", () => {
+        const error = getError("{\n  \"a\": 1,\n}", { sourceName: "config/app.json" });
+        const log = toSarif([error]);
+
+        expect(log.runs[0].tool.driver.rules).to.deep.equal([{ id: "UnexpectedToken" }]);
+        expect(log.runs[0].results).to.deep.equal([
+            {
+                ruleId: "UnexpectedToken",
+                ruleIndex: 0,
+                level: "error",
+                message: {
+                    text: "Unexpected token Punctuator(}) found."
+                },
+                locations: [
+                    {
+                        physicalLocation: {
+                            artifactLocation: { uri: "config/app.json" },
+                            region: {
+                                startLine: 3,
+                                startColumn: 1,
+                                endLine: 3,
+                                endColumn: 1,
+                                charOffset: 12,
+                                charLength: 0
+                            }
+                        }
+                    }
+                ]
+            }
+        ]);
+    });
+
+    it("should list each rule once", () => {
+        const errors = [
+            getError("[1 2]", { sourceName: "a.json" }),
+            getError("", { sourceName: "b.json" }),
+            getError("[3 4]", { sourceName: "c.json" })
+        ];
+        const log = toSarif(errors);
+
+        expect(log.runs[0].tool.driver.rules).to.deep.equal([
+            { id: "UnexpectedToken" },
+            { id: "EmptyDocument" }
+        ]);
+        expect(log.runs[0].results.map(result => result.ruleIndex)).to.deep.equal([0, 1, 0]);
+    });
+
+    it("should accept serialized errors", () => {
+        const error = getError("[1 2]", { sourceName: "a.json" });
+        const log = toSarif([JSON.parse(JSON.stringify(error))]);
+        expect(log).to.deep.equal(toSarif([error]));
+    });
+
+    it("should use the sourceName option for errors without a source name", () => {
+        const log = toSarif([getError("[1 2]")], { sourceName: "fallback.json" });
+        expect(log.runs[0].results[0].locations[0].physicalLocation.artifactLocation.uri).to.equal("fallback.json");
+    });
+
+    it("should omit the region for errors at synthetic locations", () => {
+        let error;
+
+        try {
+            getNumber(fromValue({ port: "80" }), "/port");
+        } catch (ex) {
+            error = ex;
+        }
+
+        const log = toSarif([error], { sourceName: "generated.json" });
+        expect(log.runs[0].results[0].locations).to.deep.equal([{
+            physicalLocation: {
+                artifactLocation: { uri: "generated.json" }
+            }
+        }]);
+    });
+
+    it("should omit locations when there is no source name", () => {
+        const log = toSarif([getError("[1 2]")]);
+        expect(log.runs[0].results[0].locations).to.deep.equal([]);
+    });
+
+    it("should remove the location from the message text", () => {
+        const log = toSarif([getError("[1 2]")], { sourceName: "a.json" });
+        expect(log.runs[0].results[0].message.text).to.equal("Unexpected token Number(2) found.");
+    });
+
+    it("should map severities to SARIF levels", () => {
+        const error = getError("[1 2]", { sourceName: "a.json" }).toJSON();
+        const log = toSarif([
+            { ...error, severity: "error" },
+            { ...error, severity: "warning" },
+            { ...error, severity: "info" },
+            { ...error, severity: undefined }
+        ]);
+
+        expect(log.runs[0].results.map(result => result.level)).to.deep.equal(["error", "warning", "note", "error"]);
+    });
+
+    it("should include the tool version when passed", () => {
+        const log = toSarif([], { toolVersion: "1.2.3" });
+        expect(log.runs[0].tool.driver.version).to.equal("1.2.3");
+    });
+
+});
@@ -0,0 +1,136 @@
+/**
+ * @fileoverview Stress tests for parsing very large and deeply nested input
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+const { parse } = require("../api");
+const { expect } = require("chai");
+const { getError } = require("./helpers");
+
+//-----------------------------------------------------------------------------
+// Data
+//-----------------------------------------------------------------------------
+
+const SIZE = 1000000;
+
+//-----------------------------------------------------------------------------
+// Helpers
+//-----------------------------------------------------------------------------
+
+/**
+ * Creates text with arrays nested to a depth.
+ * @param {int} depth The number of arrays.
+ * @returns {string} The text.
+ */
+function createNestedArrays(depth) {
+    return "[".repeat(depth) + "]".repeat(depth);
+}
+
+/**
+ * Counts the array operations while a function runs: one for each item
+ * added to or removed from the end of an array, and one for each item
+ * visited or copied by other array methods and by iteration. Parsing that
+ * walks or copies the stack of open containers for each one makes this
+ * grow with the square of the nesting depth, without depending on how fast
+ * the machine is.
+ * @param {Function} fn The function to run.
+ * @returns {int} The number of operations.
+ */
+function countArrayWork(fn) {
+    const stackMethods = ["push", "pop"];
+    const names = [
+        ...stackMethods, "shift", "unshift", "splice", "slice", "concat",
+        "map", "filter", "forEach", "some", "every", "find", "findIndex",
+        "indexOf", "lastIndexOf", "includes", "reduce", "join", Symbol.iterator
+    ];
+    const originals = names.map(name => Array.prototype[name]);
+    let count = 0;
+
+    names.forEach((name, i) => {
+        const isStackMethod = stackMethods.includes(name);
+
+        Array.prototype[name] = function(...args) {
+            count += isStackMethod ? Math.max(args.length, 1) : this.length;
+            return originals[i].apply(this, args);
+        };
+    });
+
+    try {
+        fn();
+    } finally {
+        names.forEach((name, i) => {
+            Array.prototype[name] = originals[i];
+        });
+    }
+
+    return count;
+}
+
+//-----------------------------------------------------------------------------
+// Tests
+//-----------------------------------------------------------------------------
+
+describe("parse() stress", function() {
+
+    this.timeout(120000);
+
+    it("should parse arrays nested a million deep", () => {
+        const result = parse(createNestedArrays(SIZE));
+        let node = result.body;
+        let depth = 1;
+
+        while (node.elements.length) {
+            node = node.elements[0];
+            depth++;
+        }
+
+        expect(depth).to.equal(SIZE);
+        expect(node.loc.start.offset).to.equal(SIZE - 1);
+    });
+
+    it("should parse objects nested a million deep", () => {
+        const result = parse("{\"a\":".repeat(SIZE) + "null" + "}".repeat(SIZE));
+        let node = result.body;
+
+        for (let i = 0; i < SIZE; i++) {
+            node = node.members[0].value;
+        }
+
+        expect(node.type).to.equal("Null");
+    });
+
+    it("should parse an array with a million elements", () => {
+        const result = parse("[" + "1,".repeat(SIZE - 1) + "1]");
+        expect(result.body.elements).to.have.lengthOf(SIZE);
+    });
+
+    it("should report an error a million arrays deep", () => {
+        expect(() => {
+            parse("[".repeat(SIZE));
+        }).to.throw(`Unexpected end of input found. Occurred while parsing element 0 of array at line 1. (1:${ SIZE + 1 })`);
+    });
+
+    it("should do work in proportion to the nesting depth", () => {
+        const smallText = createNestedArrays(SIZE / 8);
+        const largeText = createNestedArrays(SIZE / 2);
+        const small = countArrayWork(() => parse(smallText));
+        const large = countArrayWork(() => parse(largeText));
+
+        // four times the depth would take sixteen times the work if parsing were quadratic
+        expect(large / small).to.be.below(5);
+    });
+
+    it("should do work in proportion to the nesting depth when reporting an error", () => {
+        const smallText = "[".repeat(SIZE / 8);
+        const largeText = "[".repeat(SIZE / 2);
+        const small = countArrayWork(() => getError(smallText));
+        const large = countArrayWork(() => getError(largeText));
+
+        expect(large / small).to.be.below(5);
+    });
+
+});
@@ -0,0 +1,109 @@
+/**
+ * @fileoverview Tests for string literal helpers
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+const { validateStringLiteral, canonicalizeStringLiteral, isEquivalentStringLiteral } = require("../api");
+const { expect } = require("chai");
+
+//-----------------------------------------------------------------------------
+// Data
+//-----------------------------------------------------------------------------
+
+const validStrings = [
+    "\"\"",
+    "\"hello\"",
+    "\"\\\"\\\\\\/\\b\\f\\n\\r\\t\"",
+    "\"\\u0041\\u00e9\\u00E9\"",
+    "\"\\ud83d\\ude00\"",
+    "\"café 😀\""
+];
+
+const invalidStrings = new Map([
+    ["", "Unexpected end of input found. (1:1)"],
+    ["hello", "Unexpected character h found. (1:1)"],
+    ["\"hello", "Unexpected end of input found. (1:7)"],
+    ["\"hello\\", "Unexpected end of input found. (1:8)"],
+    ["\"a\\x\"", "Unexpected character x found. (1:4)"],
+    ["\"a\\u00G1\"", "Unexpected character G found. (1:7)"],
+    ["\"a\\u00", "Unexpected end of input found. (1:7)"],
+    ["\"a\tb\"", "Unexpected character \t found. (1:3)"],
+    ["\"a\"b", "Unexpected character b found. (1:4)"]
+]);
+
+//-----------------------------------------------------------------------------
+// Tests
+//-----------------------------------------------------------------------------
+
+describe("validateStringLiteral()", () => {
+
+    for (const text of validStrings) {
+        it(`should decode ${ text } the same as JSON.parse()`, () => {
+            expect(validateStringLiteral(text)).to.equal(JSON.parse(text));
+        });
+    }
+
+    for (const [text, message] of invalidStrings) {
+        it(`should throw an error for ${ JSON.stringify(text) }`, () => {
+            expect(() => {
+                validateStringLiteral(text);
+            }).to.throw(message);
+        });
+    }
+
+    it("should report the offset of the error in the literal", () => {
+        let error;
+
+        try {
+            validateStringLiteral("\"abc\\q\"");
+        } catch (ex) {
+            error = ex;
+        }
+
+        expect(error.index).to.equal(5);
+        expect(error.code).to.equal("UnexpectedChar");
+    });
+
+});
+
+describe("canonicalizeStringLiteral()", () => {
+
+    it("should remove unnecessary escapes", () => {
+        expect(canonicalizeStringLiteral("\"\\u0041\\/\\u00FF\"")).to.equal("\"A/\u00ff\"");
+    });
+
+    it("should use short escapes and lowercase hex digits for control characters", () => {
+        expect(canonicalizeStringLiteral("\"\\u000A\\u001F\\u0022\"")).to.equal("\"\\n\\u001f\\\"\"");
+    });
+
+    it("should return the same canonical form for equivalent literals", () => {
+        expect(canonicalizeStringLiteral("\"\\u00ff\"")).to.equal(canonicalizeStringLiteral("\"\\u00FF\""));
+    });
+
+    it("should throw an error for an invalid literal", () => {
+        expect(() => {
+            canonicalizeStringLiteral("\"\\x\"");
+        }).to.throw("Unexpected character x found. (1:3)");
+    });
+
+});
+
+describe("isEquivalentStringLiteral()", () => {
+
+    it("should ignore the case of hex digits", () => {
+        expect(isEquivalentStringLiteral("\"\\u00FF\"", "\"\\u00ff\"")).to.be.true;
+    });
+
+    it("should treat escaped and literal characters as equal", () => {
+        expect(isEquivalentStringLiteral("\"a\\/b\"", "\"\\u0061/b\"")).to.be.true;
+    });
+
+    it("should return false for different strings", () => {
+        expect(isEquivalentStringLiteral("\"a\"", "\"A\"")).to.be.false;
+    });
+
+});
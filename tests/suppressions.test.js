@@ -0,0 +1,62 @@
+/**
+ * @fileoverview Tests for suppression comments
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+const { applySuppressions } = require("../api");
+const { expect } = require("chai");
+const { getError } = require("./helpers");
+
+//-----------------------------------------------------------------------------
+// Tests
+//-----------------------------------------------------------------------------
+
+describe("applySuppressions()", () => {
+
+    const text = "{\n    // momoa-expect-error\n    \"a\": 1 2\n}";
+
+    it("should suppress an error on the line after a suppression comment", () => {
+        const error = getError(text, { comments: true });
+        const result = applySuppressions([error], text);
+
+        expect(result).to.deep.equal({
+            errors: [],
+            suppressed: [error],
+            unused: []
+        });
+    });
+
+    it("should not suppress errors on other lines", () => {
+        const source = "// momoa-expect-error\n\n[1 2]";
+        const error = getError(source, { comments: true });
+        const result = applySuppressions([error], source);
+
+        expect(result.errors).to.deep.equal([error]);
+        expect(result.suppressed).to.deep.equal([]);
+        expect(result.unused).to.deep.equal([1]);
+    });
+
+    it("should accept serialized errors", () => {
+        const error = getError(text, { comments: true }).toJSON();
+        expect(applySuppressions([error], text).suppressed).to.deep.equal([error]);
+    });
+
+    it("should only recognize comments that begin with the directive", () => {
+        const source = "[\n// not a momoa-expect-error\n1 2]";
+        const error = getError(source, { comments: true });
+        expect(applySuppressions([error], source).errors).to.deep.equal([error]);
+    });
+
+    it("should use the directive option", () => {
+        const source = text.replace("momoa-expect-error", "json-ignore");
+        const error = getError(source, { comments: true });
+
+        expect(applySuppressions([error], source).errors).to.deep.equal([error]);
+        expect(applySuppressions([error], source, { directive: "json-ignore" }).suppressed).to.deep.equal([error]);
+    });
+
+});
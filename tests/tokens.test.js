@@ -8,8 +8,10 @@
 // Imports
 //-----------------------------------------------------------------------------
 
-const { tokenize } = require("../");
+const { tokenize, tokenizeColumnar, tokenFilters, tokenTextEquals, matchesKeyword, getPunctuatorKind, getTokenSegments, getCommentText, verifyTokenCoverage, parse } = require("../");
 const { expect } = require("chai");
+const fs = require("fs");
+const path = require("path");
 
 //-----------------------------------------------------------------------------
 // Data
@@ -162,6 +164,13 @@ describe("tokenize()", () => {
         });
     });
 
+    it("should throw an error when a number ends after the decimal point or exponent", () => {
+        expect(() => tokenize("1.")).to.throw("Unexpected end of input found. (1:3)");
+        expect(() => tokenize("1e")).to.throw("Unexpected end of input found. (1:3)");
+        expect(() => tokenize("[1.]")).to.throw("Unexpected character ] found. (1:4)");
+        expect(() => tokenize("[1e+]")).to.throw("Unexpected character ] found. (1:5)");
+    });
+
     it("should throw an error when an invalid keyword is found", () => { 
         expect(() => {
             tokenize("no");
@@ -181,7 +190,7 @@ describe("tokenize()", () => {
             it("should throw an error when a line comment is found and comments aren't enabled", () => { 
                 expect(() => {
                     tokenize("// foo");
-                }).to.throw("Unexpected character / found. (1:1)");
+                }).to.throw("Comments aren't allowed in JSON. Use the comments option to parse JSONC. (1:1)");
             });
     
             
@@ -236,6 +245,25 @@ describe("tokenize()", () => {
             it("should throw an error when a block comment is found and comments aren't enabled", () => { 
                 expect(() => {
                     tokenize("/* foo */");
+                }).to.throw("Comments aren't allowed in JSON. Use the comments option to parse JSONC. (1:1)");
+            });
+
+            it("should throw an error with a code when a comment is found and comments aren't enabled", () => { 
+                let error;
+
+                try {
+                    tokenize("[1, /* foo */ 2]");
+                } catch (ex) {
+                    error = ex;
+                }
+
+                expect(error.code).to.equal("CommentsNotAllowed");
+                expect(error.location).to.deep.equal({ line: 1, column: 5, offset: 4 });
+            });
+
+            it("should throw an unexpected character error when a slash doesn't start a comment and comments aren't enabled", () => { 
+                expect(() => {
+                    tokenize("/ foo");
                 }).to.throw("Unexpected character / found. (1:1)");
             });
 
@@ -566,3 +594,740 @@ describe("tokenize()", () => {
     });
 
 });
+
+describe("tokenize() with eof", () => {
+
+    it("should not add an EOF token by default", () => {
+        const result = tokenize("1");
+        expect(result.map(token => token.type)).to.deep.equal(["Number"]);
+    });
+
+    it("should add an EOF token at the end of the input", () => {
+        const result = tokenize("[1]\n", { eof: true });
+        expect(result[result.length - 1]).to.deep.equal({
+            type: "EOF",
+            value: "",
+            loc: {
+                start: { line: 2, column: 1, offset: 4 },
+                end: { line: 2, column: 1, offset: 4 }
+            }
+        });
+    });
+
+    it("should add an EOF token with a range when ranges are enabled", () => {
+        const result = tokenize("true  ", { eof: true, ranges: true });
+        expect(result[1]).to.deep.equal({
+            type: "EOF",
+            value: "",
+            loc: {
+                start: { line: 1, column: 7, offset: 6 },
+                end: { line: 1, column: 7, offset: 6 }
+            },
+            range: [6, 6]
+        });
+    });
+
+    it("should add an EOF token after a trailing comment", () => {
+        const result = tokenize("1 // one", { eof: true, comments: true });
+        expect(result[2].loc.start).to.deep.equal({ line: 1, column: 9, offset: 8 });
+    });
+
+    it("should add only an EOF token for empty input", () => {
+        const result = tokenize("", { eof: true });
+        expect(result).to.deep.equal([
+            {
+                type: "EOF",
+                value: "",
+                loc: {
+                    start: { line: 1, column: 1, offset: 0 },
+                    end: { line: 1, column: 1, offset: 0 }
+                }
+            }
+        ]);
+    });
+
+});
+
+describe("tokenize() with maxLineLength", () => {
+
+    it("should track columns on very long lines", () => {
+        const text = " ".repeat(2000000) + "true";
+        const result = tokenize(text);
+        expect(result[0].loc.start).to.deep.equal({ line: 1, column: 2000001, offset: 2000000 });
+        expect(result[0].loc.end).to.deep.equal({ line: 1, column: 2000005, offset: 2000004 });
+    });
+
+    it("should allow lines up to the maximum length", () => {
+        const result = tokenize("[1, 2]\n[3]", { maxLineLength: 6 });
+        expect(result).to.have.lengthOf(8);
+    });
+
+    it("should throw an error when a line is longer than the maximum", () => {
+        expect(() => {
+            tokenize("[1]\n[1, 2]", { maxLineLength: 5 });
+        }).to.throw("Line 2 is longer than the maximum of 5 characters. (2:6)");
+    });
+
+    it("should throw an error when a keyword extends past the maximum", () => {
+        expect(() => {
+            tokenize("  true", { maxLineLength: 4 });
+        }).to.throw("Line 1 is longer than the maximum of 4 characters. (1:5)");
+    });
+
+    it("should throw an error at the first digit past the maximum", () => {
+        expect(() => {
+            tokenize("[1.123456]", { maxLineLength: 5 });
+        }).to.throw("Line 1 is longer than the maximum of 5 characters. (1:6)");
+    });
+
+    it("should allow a number that ends at the maximum", () => {
+        const result = tokenize("-12.5\n1", { maxLineLength: 5 });
+        expect(result[0].loc.end).to.deep.equal({ line: 1, column: 6, offset: 5 });
+        expect(result[1].loc.start).to.deep.equal({ line: 2, column: 1, offset: 6 });
+    });
+
+    it("should not count CRLF line breaks toward the maximum", () => {
+        const result = tokenize("[1]\r\n[2]", { maxLineLength: 3 });
+        expect(result).to.have.lengthOf(6);
+    });
+
+});
+
+describe("tokenize() with maxExponentDigits", () => {
+
+    it("should allow exponents up to the maximum number of digits", () => {
+        const result = tokenize("1e+10", { maxExponentDigits: 2 });
+        expect(result[0].value).to.equal("1e+10");
+    });
+
+    it("should throw an error when an exponent has too many digits", () => {
+        expect(() => {
+            tokenize("[0.5, 2E9999]", { maxExponentDigits: 3 });
+        }).to.throw("Number exponent is longer than the maximum of 3 digits. (1:12)");
+    });
+
+});
+
+describe("tokenize() with recordSeparators", () => {
+
+    it("should return a RecordSeparator token for each record separator", () => {
+        const result = tokenize("\u001e1\n\u001e2", { recordSeparators: true });
+
+        expect(result.map(token => token.type)).to.deep.equal(["RecordSeparator", "Number", "RecordSeparator", "Number"]);
+        expect(result[2].loc).to.deep.equal({
+            start: { line: 2, column: 1, offset: 3 },
+            end: { line: 2, column: 2, offset: 4 }
+        });
+    });
+
+    it("should throw an error for a record separator by default", () => {
+        expect(() => {
+            tokenize("\u001e1");
+        }).to.throw("Unexpected character \u001e found. (1:1)");
+    });
+
+});
+
+describe("tokenize() with strictWhitespace", () => {
+
+    it("should throw an error for a no-break space", () => {
+        expect(() => {
+            tokenize("[1,\u00A02]", { strictWhitespace: true });
+        }).to.throw("Irregular whitespace U+00A0 (NO-BREAK SPACE) found. Only spaces, tabs, carriage returns, and line feeds are allowed. (1:4)");
+    });
+
+    it("should throw an error for a line separator", () => {
+        expect(() => {
+            tokenize("[1,\u20282]", { strictWhitespace: true });
+        }).to.throw("Irregular whitespace U+2028 (LINE SEPARATOR) found.");
+    });
+
+    it("should throw an error for irregular whitespace that isn't skipped by default", () => {
+        expect(() => {
+            tokenize("[\u200B1]", { strictWhitespace: true });
+        }).to.throw("Irregular whitespace U+200B (ZERO WIDTH SPACE) found.");
+    });
+
+    it("should throw an error for a byte order mark after the start", () => {
+        expect(() => {
+            tokenize("\uFEFF1\uFEFF", { strictWhitespace: true });
+        }).to.throw("Irregular whitespace U+FEFF (ZERO WIDTH NO-BREAK SPACE) found.");
+    });
+
+    it("should allow spaces, tabs, and line breaks", () => {
+        const result = tokenize(" \t\r\n1\r\n", { strictWhitespace: true });
+        expect(result.map(token => token.value)).to.deep.equal(["1"]);
+    });
+
+    it("should allow irregular whitespace in comments", () => {
+        const result = tokenize("// a\u00A0b\n1", { strictWhitespace: true, comments: true });
+        expect(result.map(token => token.type)).to.deep.equal(["LineComment", "Number"]);
+    });
+
+});
+
+describe("tokenize() with bom", () => {
+
+    it("should return a BOM token for a byte order mark at the start", () => {
+        const result = tokenize("\uFEFF[]", { bom: true });
+        expect(result.map(token => token.type)).to.deep.equal(["BOM", "Punctuator", "Punctuator"]);
+    });
+
+    it("should skip a byte order mark by default", () => {
+        expect(tokenize("\uFEFF[]").map(token => token.type)).to.deep.equal(["Punctuator", "Punctuator"]);
+    });
+
+    it("should not return a BOM token after the start", () => {
+        expect(tokenize("[\uFEFF]", { bom: true }).map(token => token.type)).to.deep.equal(["Punctuator", "Punctuator"]);
+    });
+
+});
+
+describe("tokenize() with shebang", () => {
+
+    it("should return a Shebang token for a first-line shebang", () => {
+        const result = tokenize("#!/usr/bin/env node\n{}", { shebang: true });
+
+        expect(result.map(token => token.type)).to.deep.equal(["Shebang", "Punctuator", "Punctuator"]);
+        expect(result[0].value).to.equal("#!/usr/bin/env node");
+        expect(result[0].loc).to.deep.equal({
+            start: { line: 1, column: 1, offset: 0 },
+            end: { line: 1, column: 20, offset: 19 }
+        });
+    });
+
+    it("should return BOM and Shebang tokens for a shebang after a byte order mark", () => {
+        const result = tokenize("\uFEFF#!/usr/bin/env node\n{}", { bom: true, shebang: true });
+
+        expect(result.map(token => token.type)).to.deep.equal(["BOM", "Shebang", "Punctuator", "Punctuator"]);
+        expect(result[0]).to.deep.equal({
+            type: "BOM",
+            value: "\uFEFF",
+            loc: {
+                start: { line: 1, column: 1, offset: 0 },
+                end: { line: 1, column: 2, offset: 1 }
+            }
+        });
+        expect(result[1].loc.start).to.deep.equal({ line: 1, column: 2, offset: 1 });
+    });
+
+    it("should allow a shebang after a byte order mark without a BOM token", () => {
+        const result = tokenize("\uFEFF#!/usr/bin/env node\n{}", { shebang: true });
+        expect(result.map(token => token.type)).to.deep.equal(["Shebang", "Punctuator", "Punctuator"]);
+    });
+
+    it("should throw an error for a shebang that isn't at the start", () => {
+        expect(() => {
+            tokenize(" #!/usr/bin/env node\n{}", { shebang: true });
+        }).to.throw("Unexpected character # found. (1:2)");
+    });
+
+    it("should throw an error for a shebang by default", () => {
+        expect(() => {
+            tokenize("#!/usr/bin/env node\n{}");
+        }).to.throw("Unexpected character # found. (1:1)");
+    });
+
+});
+
+describe("tokenize() with offsetsOnly", () => {
+
+    it("should return token locations with only offsets", () => {
+        const result = tokenize("{\n  \"a\": 1\n}", { offsetsOnly: true });
+
+        expect(result.map(token => token.loc)).to.deep.equal([
+            { start: { offset: 0 }, end: { offset: 1 } },
+            { start: { offset: 4 }, end: { offset: 7 } },
+            { start: { offset: 7 }, end: { offset: 8 } },
+            { start: { offset: 9 }, end: { offset: 10 } },
+            { start: { offset: 11 }, end: { offset: 12 } }
+        ]);
+    });
+
+    it("should report errors with lines and columns", () => {
+        expect(() => {
+            tokenize("[\r\n1,\r\n @]", { offsetsOnly: true });
+        }).to.throw("Unexpected character @ found. (3:2)");
+    });
+
+    it("should still enforce maxLineLength", () => {
+        expect(() => {
+            tokenize("[\n12345]", { offsetsOnly: true, maxLineLength: 4 });
+        }).to.throw("Line 2 is longer than the maximum of 4 characters.");
+    });
+
+});
+
+describe("tokenize() with recover", () => {
+
+    it("should add an Invalid token and resume at the next structural character", () => {
+        const text = "[1, tru, 3]";
+        const result = tokenize(text, { recover: true });
+
+        expect(result.map(token => token.value)).to.deep.equal(["[", "1", ",", "tru", ",", "3", "]"]);
+        expect(result[3].type).to.equal("Invalid");
+        expect(result[3].loc).to.deep.equal({
+            start: { line: 1, column: 5, offset: 4 },
+            end: { line: 1, column: 8, offset: 7 }
+        });
+        expect(result[3].error.message).to.equal("Unexpected character r found. (1:6)");
+    });
+
+    it("should include the start of the token that couldn't be read", () => {
+        const result = tokenize("{\"a\": \"b\\x\", \"c\": 1}", { recover: true });
+
+        expect(result[3]).to.include({ type: "Invalid", value: "\"b\\x\"" });
+        expect(result.slice(4).map(token => token.value)).to.deep.equal([",", "\"c\"", ":", "1", "}"]);
+    });
+
+    it("should resume at the next line", () => {
+        const result = tokenize("[1,\n @@@ 2\n, 3]", { recover: true });
+
+        expect(result.map(token => token.type)).to.deep.equal(["Punctuator", "Number", "Punctuator", "Invalid", "Punctuator", "Number", "Punctuator"]);
+        expect(result[3].value).to.equal("@@@ 2");
+        expect(result[4].loc.start).to.deep.equal({ line: 3, column: 1, offset: 11 });
+    });
+
+    it("should stop an unterminated string at the next line break", () => {
+        const result = tokenize("[\"abc\n, 2]", { recover: true });
+
+        expect(result.map(token => token.value)).to.deep.equal(["[", "\"abc", ",", "2", "]"]);
+        expect(result[1].type).to.equal("Invalid");
+        expect(result[1].error.message).to.equal("Unexpected end of input found. (2:5)");
+        expect(result[2].loc.start).to.deep.equal({ line: 2, column: 1, offset: 6 });
+    });
+
+    it("should stop an unterminated string at the next structural character", () => {
+        const result = tokenize("[\"abc, def", { recover: true });
+
+        expect(result.map(token => [token.type, token.value])).to.deep.equal([
+            ["Punctuator", "["],
+            ["Invalid", "\"abc"],
+            ["Punctuator", ","],
+            ["Invalid", "def"]
+        ]);
+    });
+
+    it("should skip to the end of the text when there's nothing to resume at", () => {
+        const result = tokenize("[\"abc def", { recover: true, eof: true });
+
+        expect(result.map(token => token.type)).to.deep.equal(["Punctuator", "Invalid", "EOF"]);
+        expect(result[1].error.message).to.equal("Unexpected end of input found. (1:10)");
+    });
+
+    it("should cover the text with tokens", () => {
+        const text = "{a: 1, 'b': [01, -], /* c */ \"d\u00A0\": \"e\" f}";
+        expect(verifyTokenCoverage(text, tokenize(text, { recover: true }))).to.be.null;
+    });
+
+    it("should still throw an error for a line that's too long", () => {
+        expect(() => {
+            tokenize("[1, 2]", { recover: true, maxLineLength: 3 });
+        }).to.throw("Line 1 is longer than the maximum of 3 characters.");
+    });
+
+    it("should add Invalid to the columnar types", () => {
+        const columns = tokenizeColumnar("[x]", { recover: true });
+        expect(columns.typeNames[columns.types[1]]).to.equal("Invalid");
+    });
+
+});
+
+describe("tokenizeColumnar()", () => {
+
+    it("should return the same tokens as tokenize() in columns", () => {
+        const text = "#!/usr/bin/env node\n{\"a\": [1, true, null], // x\n\"b\": \"c\"}";
+        const options = { comments: true, shebang: true, eof: true };
+        const tokens = tokenize(text, options);
+        const columns = tokenizeColumnar(text, options);
+
+        expect(columns.length).to.equal(tokens.length);
+
+        tokens.forEach((token, i) => {
+            expect(columns.typeNames[columns.types[i]]).to.equal(token.type);
+            expect([columns.startOffsets[i], columns.startLines[i], columns.startColumns[i]]).to.deep.equal([token.loc.start.offset, token.loc.start.line, token.loc.start.column]);
+            expect([columns.endOffsets[i], columns.endLines[i], columns.endColumns[i]]).to.deep.equal([token.loc.end.offset, token.loc.end.line, token.loc.end.column]);
+        });
+    });
+
+    it("should return typed arrays trimmed to the number of tokens", () => {
+        const text = "[" + "1,".repeat(1500) + "1]";
+        const columns = tokenizeColumnar(text);
+
+        expect(columns.length).to.equal(3003);
+        expect(columns.types).to.be.instanceOf(Uint8Array);
+        expect(columns.startOffsets).to.be.instanceOf(Uint32Array);
+        expect(columns.endColumns).to.have.lengthOf(3003);
+        expect(columns.endOffsets[3002]).to.equal(text.length);
+    });
+
+    it("should return empty arrays for empty text", () => {
+        const columns = tokenizeColumnar("  ");
+        expect(columns.length).to.equal(0);
+        expect(columns.types).to.have.lengthOf(0);
+    });
+
+    it("should throw the same errors as tokenize()", () => {
+        expect(() => {
+            tokenizeColumnar("[1, @]");
+        }).to.throw("Unexpected character @ found. (1:5)");
+    });
+
+    it("should throw an error when offsetsOnly is used", () => {
+        expect(() => {
+            tokenizeColumnar("[1]", { offsetsOnly: true });
+        }).to.throw(TypeError, "The offsetsOnly option can't be used with tokenizeColumnar().");
+    });
+
+});
+
+describe("tokenFilters", () => {
+
+    const text = "[1, // one\n/* two */ \"three\"]";
+
+    describe("significant()", () => {
+        it("should skip comment tokens", () => {
+            const result = [...tokenFilters.significant(tokenize(text, { comments: true }))];
+            expect(result.map(token => token.value)).to.deep.equal(["[", "1", ",", "\"three\"", "]"]);
+        });
+    });
+
+    describe("commentsOnly()", () => {
+        it("should return only comment tokens", () => {
+            const result = [...tokenFilters.commentsOnly(tokenize(text, { comments: true }))];
+            expect(result.map(token => token.type)).to.deep.equal(["LineComment", "BlockComment"]);
+        });
+
+        it("should return nothing when there are no comments", () => {
+            const result = [...tokenFilters.commentsOnly(tokenize("[1, 2]"))];
+            expect(result).to.deep.equal([]);
+        });
+    });
+
+    describe("punctuationOnly()", () => {
+        it("should return only punctuator tokens", () => {
+            const result = [...tokenFilters.punctuationOnly(tokenize(text, { comments: true }))];
+            expect(result.map(token => token.value)).to.deep.equal(["[", ",", "]"]);
+        });
+    });
+
+    it("should accept any iterable of tokens", () => {
+        const tokens = tokenize("{\"a\":1}").values();
+        const result = [...tokenFilters.punctuationOnly(tokens)];
+        expect(result.map(token => token.value)).to.deep.equal(["{", ":", "}"]);
+    });
+
+});
+
+describe("tokenTextEquals()", () => {
+
+    const text = "[true, \"a\\u0062\", -0]";
+    const tokens = tokenize(text);
+
+    it("should compare the source text of a token", () => {
+        expect(tokenTextEquals(tokens[1], text, "true")).to.be.true;
+        expect(tokenTextEquals(tokens[3], text, "\"a\\u0062\"")).to.be.true;
+        expect(tokenTextEquals(tokens[5], text, "-0")).to.be.true;
+    });
+
+    it("should return false when the text is different", () => {
+        expect(tokenTextEquals(tokens[1], text, "tru")).to.be.false;
+        expect(tokenTextEquals(tokens[1], text, "true,")).to.be.false;
+        expect(tokenTextEquals(tokens[3], text, "\"ab\"")).to.be.false;
+    });
+
+});
+
+describe("matchesKeyword()", () => {
+
+    const tokens = tokenize("[true, false, null, \"null\"]");
+
+    it("should match keyword tokens", () => {
+        expect(matchesKeyword(tokens[1], "true")).to.be.true;
+        expect(matchesKeyword(tokens[3], "false")).to.be.true;
+        expect(matchesKeyword(tokens[5], "null")).to.be.true;
+    });
+
+    it("should not match other keywords or strings", () => {
+        expect(matchesKeyword(tokens[1], "false")).to.be.false;
+        expect(matchesKeyword(tokens[7], "null")).to.be.false;
+    });
+
+});
+
+describe("getPunctuatorKind()", () => {
+
+    it("should return the kind of each punctuator", () => {
+        const tokens = tokenize("{ \"a\": [1, 2] }");
+        const kinds = tokens.filter(token => token.type === "Punctuator").map(getPunctuatorKind);
+
+        expect(kinds).to.deep.equal(["LBrace", "Colon", "LBracket", "Comma", "RBracket", "RBrace"]);
+    });
+
+    it("should return undefined for other tokens", () => {
+        const tokens = tokenize("[\"{\", null, 1] // ,", { comments: true, eof: true });
+        const kinds = tokens.filter(token => token.type !== "Punctuator").map(getPunctuatorKind);
+
+        expect(kinds).to.deep.equal([undefined, undefined, undefined, undefined, undefined]);
+    });
+
+});
+
+describe("getCommentText()", () => {
+
+    function getComment(text) {
+        return tokenize(text, { comments: true })[0];
+    }
+
+    it("should remove gutters and blank lines from a JSDoc-style comment", () => {
+        const token = getComment("/**\n * Hello\n *   code\n *\n * World\n */");
+
+        expect(getCommentText(token)).to.equal("Hello\n  code\n\nWorld");
+    });
+
+    it("should remove common indentation", () => {
+        const token = getComment("/*\n    line one\n      nested\n    line two\n*/");
+
+        expect(getCommentText(token)).to.equal("line one\n  nested\nline two");
+    });
+
+    it("should handle \\r\\n line breaks", () => {
+        const token = getComment("/*\r\n\t * a\r\n\t * b\r\n\t */");
+
+        expect(getCommentText(token)).to.equal("a\nb");
+    });
+
+    it("should trim a single-line block comment", () => {
+        expect(getCommentText(getComment("/* single */"))).to.equal("single");
+    });
+
+    it("should keep gutters when gutter is false", () => {
+        const token = getComment("/*\n * a\n *   b\n */");
+
+        expect(getCommentText(token, { gutter: false })).to.equal("* a\n*   b");
+    });
+
+    it("should keep indentation when dedent is false", () => {
+        const token = getComment("/* first\n   second */");
+
+        expect(getCommentText(token, { dedent: false })).to.equal(" first\n   second");
+    });
+
+    it("should return the text of a line comment", () => {
+        expect(getCommentText(getComment("//   line  "))).to.equal("line");
+        expect(getCommentText(getComment("//   line  "), { dedent: false })).to.equal("   line");
+    });
+
+    it("should not change the token", () => {
+        const token = getComment("/*\n * a\n */");
+
+        getCommentText(token);
+        expect(token.value).to.equal("/*\n * a\n */");
+    });
+
+    it("should throw an error for a token that isn't a comment", () => {
+        expect(() => {
+            getCommentText(tokenize("1")[0]);
+        }).to.throw("Expected a comment token but found Number.");
+    });
+
+});
+
+describe("getTokenSegments()", () => {
+
+    function toRanges(segments) {
+        return segments.map(({ start, end }) => [start.offset, end.offset]);
+    }
+
+    it("should split a long token into segments", () => {
+        const text = "\"abcdefgh\"";
+        const [token] = tokenize(text);
+        const segments = getTokenSegments(token, text, 4);
+
+        expect(toRanges(segments)).to.deep.equal([[0, 4], [4, 8], [8, 10]]);
+        expect(segments[1]).to.deep.equal({
+            start: { line: 1, column: 5, offset: 4 },
+            end: { line: 1, column: 9, offset: 8 }
+        });
+    });
+
+    it("should return one segment for a token that fits", () => {
+        const text = "[12345]";
+        const [, token] = tokenize(text);
+        expect(toRanges(getTokenSegments(token, text, 5))).to.deep.equal([[1, 6]]);
+    });
+
+    it("should not split escape sequences", () => {
+        const text = "\"ab\\u0063d\"";
+        const [token] = tokenize(text);
+        expect(toRanges(getTokenSegments(token, text, 4))).to.deep.equal([[0, 3], [3, 9], [9, 11]]);
+    });
+
+    it("should not split surrogate pairs", () => {
+        const text = "\"😀😀\"";
+        const [token] = tokenize(text);
+        expect(toRanges(getTokenSegments(token, text, 3))).to.deep.equal([[0, 3], [3, 6]]);
+    });
+
+    it("should end segments at line breaks", () => {
+        const text = "/* ab\r\n cd */";
+        const [token] = tokenize(text, { comments: true });
+
+        expect(getTokenSegments(token, text, 10)).to.deep.equal([
+            {
+                start: { line: 1, column: 1, offset: 0 },
+                end: { line: 1, column: 6, offset: 5 }
+            },
+            {
+                start: { line: 2, column: 1, offset: 7 },
+                end: { line: 2, column: 7, offset: 13 }
+            }
+        ]);
+    });
+
+    it("should return no segments for an empty token", () => {
+        const tokens = tokenize("1", { eof: true });
+        expect(getTokenSegments(tokens[1], "1", 10)).to.deep.equal([]);
+    });
+
+    it("should throw an error when the maximum width isn't a positive integer", () => {
+        const text = "1";
+        const [token] = tokenize(text);
+
+        expect(() => {
+            getTokenSegments(token, text, 0);
+        }).to.throw(RangeError, "Maximum width must be a positive integer but was 0.");
+    });
+
+});
+
+describe("verifyTokenCoverage()", () => {
+
+    const text = "#!/usr/bin/env node\r\n{\n\t\"a\": [1e5, true, null], // b\r\n\"c\": /* d */ \"\\u00e9\"\r}";
+    const options = { comments: true, shebang: true, ranges: true, eof: true };
+
+    it("should return null when the tokens cover the text", () => {
+        expect(verifyTokenCoverage(text, tokenize(text, options))).to.be.null;
+    });
+
+    it("should return null for tokens with only offsets", () => {
+        expect(verifyTokenCoverage(text, tokenize(text, { ...options, offsetsOnly: true }))).to.be.null;
+    });
+
+    it("should return null for comments and shebangs that end with \\r\\n", () => {
+        const source = "#!a\r\n/* b\r\n c */ 1 // d\r\n";
+        const tokens = tokenize(source, { comments: true, shebang: true });
+
+        expect(tokens.map(token => token.value)).to.deep.equal(["#!a", "/* b\r\n c */", "1", "// d"]);
+        expect(verifyTokenCoverage(source, tokens)).to.be.null;
+    });
+
+    it("should return null for strings that contain \\r\\n", () => {
+        const source = "[\"a\r\nb\", \"\r\n\"]";
+        const tokens = tokenize(source);
+
+        expect(tokens[1].value).to.equal("\"a\r\nb\"");
+        expect(tokens[1].loc.end).to.deep.equal({ line: 2, column: 3, offset: 7 });
+        expect(tokens[3].value).to.equal("\"\r\n\"");
+        expect(verifyTokenCoverage(source, tokens)).to.be.null;
+    });
+
+    it("should return null when irregular whitespace is between tokens", () => {
+        const source = "[1,\u00A02,\u000B3]";
+        expect(verifyTokenCoverage(source, tokenize(source))).to.be.null;
+    });
+
+    it("should skip synthetic tokens", () => {
+        const source = "[1 2";
+        const { tokens } = parse(source, { tolerant: true, tokens: true });
+
+        expect(tokens.some(token => token.synthetic)).to.be.true;
+        expect(verifyTokenCoverage(source, tokens)).to.be.null;
+    });
+
+    it("should return null for each fixture with \\n and \\r\\n line breaks", () => {
+        const astsPath = "./tests/fixtures/asts";
+
+        fs.readdirSync(astsPath).forEach(fileName => {
+            const contents = fs.readFileSync(path.join(astsPath, fileName), "utf8").replace(/\r/g, "");
+            const source = contents.slice(0, contents.indexOf("---"));
+
+            for (const fixture of [source, source.replace(/\n/g, "\r\n")]) {
+                expect(verifyTokenCoverage(fixture, tokenize(fixture, options)), fileName).to.be.null;
+            }
+        });
+    });
+
+    it("should return a gap when text isn't covered by a token", () => {
+        const tokens = tokenize(text, options).filter(token => token.type !== "BlockComment");
+        const result = verifyTokenCoverage(text, tokens);
+
+        expect(result.kind).to.equal("gap");
+        expect(result.offset).to.equal(text.indexOf("/*"));
+        expect(result.token.value).to.equal("\"\\u00e9\"");
+        expect(result.message).to.equal(`Text at offset ${ text.indexOf("/*") } isn't covered by a token.`);
+    });
+
+    it("should return a gap for text after the last token", () => {
+        const result = verifyTokenCoverage("[] x", tokenize("[]"));
+
+        expect(result).to.deep.equal({
+            kind: "gap",
+            message: "Text at offset 3 isn't covered by a token.",
+            offset: 3,
+            index: -1,
+            token: null
+        });
+    });
+
+    it("should return an overlap when a token starts before the previous one ends", () => {
+        const tokens = tokenize("[10]", { offsetsOnly: true });
+        tokens[1].loc.end.offset = 4;
+        tokens[1].value = "10]";
+
+        const result = verifyTokenCoverage("[10]", tokens);
+
+        expect(result.kind).to.equal("overlap");
+        expect(result.index).to.equal(2);
+        expect(result.message).to.equal("Token 2 starts at offset 3 before the previous token ends at offset 4.");
+    });
+
+    it("should return out-of-range when a token ends after the text", () => {
+        const tokens = tokenize("[]");
+        tokens[1].loc.end.offset = 3;
+
+        expect(verifyTokenCoverage("[]", tokens).kind).to.equal("out-of-range");
+    });
+
+    it("should return a text mismatch when a value doesn't match the source text", () => {
+        const tokens = tokenize("[1]");
+        tokens[1].value = "2";
+
+        const result = verifyTokenCoverage("[1]", tokens);
+
+        expect(result.kind).to.equal("text-mismatch");
+        expect(result.message).to.equal("Token 1 has the value \"2\" but its source text is \"1\".");
+    });
+
+    it("should return a location mismatch when a line or column drifts", () => {
+        const tokens = tokenize("[\n 1]");
+        tokens[1].loc.end.column = 4;
+
+        const result = verifyTokenCoverage("[\n 1]", tokens);
+
+        expect(result.kind).to.equal("location-mismatch");
+        expect(result.offset).to.equal(4);
+        expect(result.message).to.equal("Token 1 has the location 2:4 at offset 4, which is at 2:3.");
+    });
+
+    it("should return a location mismatch when a range doesn't match the offsets", () => {
+        const tokens = tokenize("[1]", { ranges: true });
+        tokens[2].range = [1, 3];
+
+        const result = verifyTokenCoverage("[1]", tokens);
+
+        expect(result.kind).to.equal("location-mismatch");
+        expect(result.message).to.equal("Token 2 has the range [1,3] but offsets 2 to 3.");
+    });
+
+});
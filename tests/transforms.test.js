@@ -0,0 +1,411 @@
+/**
+ * @fileoverview Tests for transforms
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+const { parse, applyEdits, normalizeEscapes, normalizeQuotes, renameKey, setValue, insertMember, insertElement, remove, inferStyle } = require("../api");
+const { expect } = require("chai");
+
+//-----------------------------------------------------------------------------
+// Tests
+//-----------------------------------------------------------------------------
+
+describe("applyEdits()", () => {
+
+    it("should return the text unchanged when there are no edits", () => {
+        expect(applyEdits("[1, 2]", [])).to.equal("[1, 2]");
+    });
+
+    it("should apply edits in any order", () => {
+        const result = applyEdits("[1, 2]", [
+            { range: [4, 5], text: "\"two\"" },
+            { range: [1, 2], text: "\"one\"" }
+        ]);
+        expect(result).to.equal("[\"one\", \"two\"]");
+    });
+
+    it("should apply insertions", () => {
+        const result = applyEdits("[1]", [{ range: [2, 2], text: ", 2" }]);
+        expect(result).to.equal("[1, 2]");
+    });
+
+    it("should throw an error when edits overlap", () => {
+        expect(() => {
+            applyEdits("[1, 2]", [
+                { range: [1, 4], text: "" },
+                { range: [3, 5], text: "" }
+            ]);
+        }).to.throw("Edit at offset 3 overlaps a previous edit.");
+    });
+
+});
+
+describe("normalizeEscapes()", () => {
+
+    it("should remove escapes from forward slashes by default", () => {
+        const text = "{ \"url\": \"http:\\/\\/example.com\" }";
+        const edits = normalizeEscapes(text);
+
+        expect(edits).to.deep.equal([
+            { range: [9, 31], text: "\"http://example.com\"" }
+        ]);
+        expect(applyEdits(text, edits)).to.equal("{ \"url\": \"http://example.com\" }");
+    });
+
+    it("should escape forward slashes when solidus is \"escaped\"", () => {
+        const text = "[\"a/b\", \"c\\/d\"]";
+        const edits = normalizeEscapes(text, { solidus: "escaped" });
+        expect(applyEdits(text, edits)).to.equal("[\"a\\/b\", \"c\\/d\"]");
+    });
+
+    it("should leave forward slashes alone when solidus is \"preserve\"", () => {
+        const text = "[\"a/b\", \"c\\/d\"]";
+        expect(normalizeEscapes(text, { solidus: "preserve" })).to.deep.equal([]);
+    });
+
+    it("should lowercase hex digits when hexCase is \"lower\"", () => {
+        const text = "\"\\u00FF\\u00aB\"";
+        const edits = normalizeEscapes(text, { hexCase: "lower" });
+        expect(applyEdits(text, edits)).to.equal("\"\\u00ff\\u00ab\"");
+    });
+
+    it("should uppercase hex digits when hexCase is \"upper\"", () => {
+        const text = "\"\\u00ff\\u00aB\"";
+        const edits = normalizeEscapes(text, { hexCase: "upper" });
+        expect(applyEdits(text, edits)).to.equal("\"\\u00FF\\u00AB\"");
+    });
+
+    it("should not confuse an escaped backslash with an escape sequence", () => {
+        const text = "\"\\\\/ \\\\u00AB\"";
+        const edits = normalizeEscapes(text, { solidus: "escaped", hexCase: "lower" });
+        expect(applyEdits(text, edits)).to.equal("\"\\\\\\/ \\\\u00AB\"");
+    });
+
+    it("should return no edits when strings are already normalized", () => {
+        expect(normalizeEscapes("{ \"a/b\": \"\\u00ab\" }", { hexCase: "lower" })).to.deep.equal([]);
+    });
+
+    it("should skip comments when comments are enabled", () => {
+        const text = "// http:\\/\\/\n\"\\/\"";
+        const edits = normalizeEscapes(text, { comments: true });
+        expect(applyEdits(text, edits)).to.equal("// http:\\/\\/\n\"/\"");
+    });
+
+});
+
+describe("normalizeQuotes()", () => {
+
+    it("should replace single quotes with double quotes by default", () => {
+        const text = "{ 'name': 'momoa', \"ok\": true }";
+        const edits = normalizeQuotes(text);
+
+        expect(edits).to.deep.equal([
+            { range: [2, 8], text: "\"name\"" },
+            { range: [10, 17], text: "\"momoa\"" }
+        ]);
+        expect(applyEdits(text, edits)).to.equal("{ \"name\": \"momoa\", \"ok\": true }");
+        expect(() => parse(applyEdits(text, edits))).to.not.throw();
+    });
+
+    it("should escape double quotes and unescape single quotes when converting to double quotes", () => {
+        const text = "['it\\'s \"fine\"']";
+        const result = applyEdits(text, normalizeQuotes(text));
+
+        expect(result).to.equal("[\"it's \\\"fine\\\"\"]");
+        expect(parse(result).body.elements[0].value).to.equal("it's \"fine\"");
+    });
+
+    it("should keep other escapes", () => {
+        const text = "'a\\\\b\\n\\u00e9'";
+        expect(applyEdits(text, normalizeQuotes(text))).to.equal("\"a\\\\b\\n\\u00e9\"");
+    });
+
+    it("should replace double quotes with single quotes when quote is \"single\"", () => {
+        const text = "[\"it's\", \"a \\\"b\\\"\", 'c']";
+        const edits = normalizeQuotes(text, { quote: "single" });
+        expect(applyEdits(text, edits)).to.equal("['it\\'s', 'a \"b\"', 'c']");
+    });
+
+    it("should leave strings that aren't closed on the same line alone", () => {
+        expect(normalizeQuotes("['a\n'b']")).to.deep.equal([
+            { range: [4, 7], text: "\"b\"" }
+        ]);
+    });
+
+    it("should skip comments when comments are enabled", () => {
+        const text = "// it's\n/* 'a' */ 'b'";
+        const edits = normalizeQuotes(text, { comments: true });
+        expect(applyEdits(text, edits)).to.equal("// it's\n/* 'a' */ \"b\"");
+    });
+
+    it("should throw an error for an unknown quote style", () => {
+        expect(() => {
+            normalizeQuotes("''", { quote: "backtick" });
+        }).to.throw("Unknown quote style \"backtick\".");
+    });
+
+});
+
+describe("renameKey()", () => {
+
+    const text = `{
+    // the scripts
+    "scripts": {
+        "test"  :   "mocha",
+        "lint": "eslint"
+    }
+}`;
+
+    it("should replace only the member name", () => {
+        const edits = renameKey(text, "/scripts/test", "unit", { comments: true });
+        expect(applyEdits(text, edits)).to.equal(text.replace("\"test\"  :", "\"unit\"  :"));
+    });
+
+    it("should escape the new name", () => {
+        const edits = renameKey(text, ["scripts", "lint"], "a\"b/c", { comments: true });
+        expect(edits[0].text).to.equal("\"a\\\"b/c\"");
+        expect(parse(applyEdits(text, edits), { comments: true }).body.members[0].value.members[1].name.value).to.equal("a\"b/c");
+    });
+
+    it("should accept a document", () => {
+        const ast = parse("{\"a\": {\"b\": 1}}");
+        expect(renameKey(ast, "/a", "x")).to.deep.equal([{ range: [1, 4], text: "\"x\"" }]);
+    });
+
+    it("should rename the last member when names are duplicated", () => {
+        const source = "{\"a\": 1, \"a\": 2}";
+        expect(applyEdits(source, renameKey(source, "/a", "b"))).to.equal("{\"a\": 1, \"b\": 2}");
+    });
+
+    it("should return no edits when the name is unchanged", () => {
+        expect(renameKey("{\"a\": 1}", "/a", "a")).to.deep.equal([]);
+    });
+
+    it("should throw an error when there is no member at the pointer", () => {
+        expect(() => {
+            renameKey("{\"a\": [1]}", "/a/0", "b");
+        }).to.throw("No member found at \"/a/0\".");
+        expect(() => {
+            renameKey("{\"a\": 1}", "", "b");
+        }).to.throw("No member found at \"\".");
+    });
+
+    it("should throw an error when another member already has the new name", () => {
+        expect(() => {
+            renameKey("{\"a\": 1, \"b\": 2}", "/a", "b");
+        }).to.throw("Member \"b\" already exists.");
+    });
+
+});
+
+describe("setValue()", () => {
+
+    const text = `{
+  "name": "momoa", // the name
+  "engines": {
+    "node": ">=10"
+  },
+  "files": []
+}`;
+
+    it("should replace only the value", () => {
+        const edits = setValue(text, "/name", "other", { comments: true });
+        expect(applyEdits(text, edits)).to.equal(text.replace("\"momoa\"", "\"other\""));
+    });
+
+    it("should print objects using the indentation of the text", () => {
+        const edits = setValue(text, "/engines", { node: ">=12", npm: ">=6" }, { comments: true });
+        expect(applyEdits(text, edits)).to.equal(`{
+  "name": "momoa", // the name
+  "engines": {
+    "node": ">=12",
+    "npm": ">=6"
+  },
+  "files": []
+}`);
+    });
+
+    it("should print nested arrays at the right depth", () => {
+        const edits = setValue(text, "/engines/node", ["a", ["b"]], { comments: true });
+        expect(applyEdits(text, edits)).to.include(`    "node": [
+      "a",
+      [
+        "b"
+      ]
+    ]
+  },`);
+    });
+
+    it("should use the indentation inferred by inferStyle()", () => {
+        const source = "{ \"a\": [\n        1\n    ],\n    \"b\": [\n        2\n    ],\n    \"c\": [\n        3\n    ],\n    \"d\": 4\n}";
+        const edits = setValue(source, "/d", [4]);
+
+        expect(inferStyle(source).indent).to.equal("    ");
+        expect(edits[0].text).to.equal("[\n        4\n    ]");
+    });
+
+    it("should use the indent option", () => {
+        const edits = setValue(text, "/files", ["api.js"], { comments: true, indent: "\t" });
+        expect(edits[0].text).to.equal("[\n  \t\"api.js\"\n  ]");
+    });
+
+    it("should print on one line when the text isn't indented", () => {
+        const source = "{\"a\":{\"b\":1}}";
+        expect(applyEdits(source, setValue(source, "/a", { c: [1, 2] }))).to.equal("{\"a\":{\"c\":[1,2]}}");
+    });
+
+    it("should use the line endings of the text", () => {
+        const source = "{\r\n    \"a\": 1\r\n}";
+        expect(applyEdits(source, setValue(source, "/a", [1]))).to.equal("{\r\n    \"a\": [\r\n        1\r\n    ]\r\n}");
+    });
+
+    it("should replace the whole document", () => {
+        expect(applyEdits(" 1 ", setValue(" 1 ", "", null))).to.equal(" null ");
+    });
+
+    it("should throw an error when there is no value at the pointer", () => {
+        expect(() => {
+            setValue("{}", ["a"], 1);
+        }).to.throw("No value found at \"/a\".");
+    });
+
+    it("should throw an error when the value can't be represented", () => {
+        expect(() => {
+            setValue("[1]", "/0", undefined);
+        }).to.throw("Cannot represent a value of type undefined in JSON.");
+    });
+
+});
+
+describe("insertMember()", () => {
+
+    function insert(text, ...args) {
+        return applyEdits(text, insertMember(text, ...args));
+    }
+
+    it("should add a member after the last member on its own line", () => {
+        const text = "{\n  \"a\": 1,\n  \"b\": 2\n}";
+        expect(insert(text, "", "c", [3])).to.equal("{\n  \"a\": 1,\n  \"b\": 2,\n  \"c\": [\n    3\n  ]\n}");
+    });
+
+    it("should keep a line comment with the member it follows", () => {
+        const text = "{\n  \"a\": 1 // one\n}";
+        expect(insert(text, "", "b", 2, { comments: true })).to.equal("{\n  \"a\": 1, // one\n  \"b\": 2\n}");
+    });
+
+    it("should add a member at a position", () => {
+        const text = "{\n    \"a\": 1,\n    \"b\": 2\n}";
+        expect(insert(text, "", "c", 3, { position: 1 })).to.equal("{\n    \"a\": 1,\n    \"c\": 3,\n    \"b\": 2\n}");
+    });
+
+    it("should follow the spacing of members on one line", () => {
+        expect(insert("{\"a\" : 1,  \"b\" : 2}", "", "c", true)).to.equal("{\"a\" : 1,  \"b\" : 2,  \"c\" : true}");
+        expect(insert("{\"a\":1}", "", "b", 2)).to.equal("{\"a\":1,\"b\":2}");
+    });
+
+    it("should add a member to an empty object", () => {
+        expect(insert("{\n  \"a\": {\n  }\n}", "/a", "b", 1)).to.equal("{\n  \"a\": {\n    \"b\": 1\n  }\n}");
+        expect(insert("{\n  \"a\": { }\n}", "/a", "b", 1)).to.equal("{\n  \"a\": { \"b\": 1 }\n}");
+    });
+
+    it("should throw an error when the member already exists", () => {
+        expect(() => {
+            insertMember("{\"a\": 1}", "", "a", 2);
+        }).to.throw("Member \"a\" already exists.");
+    });
+
+    it("should throw an error when there is no object at the pointer", () => {
+        expect(() => {
+            insertMember("{\"a\": []}", "/a", "b", 2);
+        }).to.throw("No object found at \"/a\".");
+    });
+
+});
+
+describe("insertElement()", () => {
+
+    function insert(text, ...args) {
+        return applyEdits(text, insertElement(text, ...args));
+    }
+
+    it("should add an element after the last element", () => {
+        expect(insert("[1, 2]", "", 3)).to.equal("[1, 2, 3]");
+        expect(insert("[\n\t1\n]", "", { a: 1 })).to.equal("[\n\t1,\n\t{\n\t\t\"a\": 1\n\t}\n]");
+    });
+
+    it("should keep a trailing comma at the end", () => {
+        expect(insert("[\n  1,\n]", "", 2)).to.equal("[\n  1,\n  2,\n]");
+        expect(insert("[1, ]", "", 2)).to.equal("[1, 2, ]");
+    });
+
+    it("should add an element at a position", () => {
+        expect(insert("[ 1, 2 ]", "", 0, { position: 0 })).to.equal("[ 0, 1, 2 ]");
+        expect(insert("[\"a\"]", "", "b", { position: 1 })).to.equal("[\"a\",\"b\"]");
+    });
+
+    it("should add an element to an empty array", () => {
+        expect(insert("{\"a\": []}", "/a", 1)).to.equal("{\"a\": [1]}");
+    });
+
+    it("should throw an error when the position is out of range", () => {
+        expect(() => {
+            insertElement("[1]", "", 2, { position: 2 });
+        }).to.throw("Position 2 is out of range.");
+    });
+
+});
+
+describe("remove()", () => {
+
+    function removeAt(text, pointer, options) {
+        return applyEdits(text, remove(text, pointer, options));
+    }
+
+    const text = "{\n  // about a\n  \"a\": 1, // one\n  // about b\n  \"b\": 2 // two\n}";
+
+    it("should remove a member and the comma after it", () => {
+        expect(removeAt(text, "/a", { comments: true })).to.equal("{\n  // about a\n  // one\n  // about b\n  \"b\": 2 // two\n}");
+    });
+
+    it("should remove the last member and the comma before it", () => {
+        expect(removeAt(text, "/b", { comments: true })).to.equal("{\n  // about a\n  \"a\": 1 // one\n  // about b\n  // two\n}");
+    });
+
+    it("should remove attached comments when removeComments is true", () => {
+        expect(removeAt(text, "/a", { comments: true, removeComments: true })).to.equal("{\n  // about b\n  \"b\": 2 // two\n}");
+        expect(removeAt(text, "/b", { comments: true, removeComments: true })).to.equal("{\n  // about a\n  \"a\": 1 // one\n}");
+    });
+
+    it("should remove elements on the same line", () => {
+        expect(removeAt("[1, 2, 3]", "/0")).to.equal("[2, 3]");
+        expect(removeAt("[1, 2, 3]", "/1")).to.equal("[1, 3]");
+        expect(removeAt("[1, 2, 3]", "/2")).to.equal("[1, 2]");
+    });
+
+    it("should keep a trailing comma", () => {
+        expect(removeAt("[\n  1,\n  2,\n]", "/1")).to.equal("[\n  1,\n]");
+    });
+
+    it("should empty the container when removing the only child", () => {
+        expect(removeAt("{\"a\": [ 1 ]}", "/a/0")).to.equal("{\"a\": []}");
+        expect(removeAt("{\n  \"a\": 1\n}", "/a")).to.equal("{}");
+    });
+
+    it("should keep comments when removing the only child", () => {
+        expect(removeAt("{\n  \"a\": 1 // c\n}", "/a", { comments: true })).to.equal("{\n  // c\n}");
+    });
+
+    it("should throw an error when there is no member or element at the pointer", () => {
+        expect(() => {
+            remove("[1]", "/1");
+        }).to.throw("No member or element found at \"/1\".");
+        expect(() => {
+            remove("[1]", "");
+        }).to.throw("No member or element found at \"\".");
+    });
+
+});
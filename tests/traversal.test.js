@@ -8,7 +8,7 @@
 // Imports
 //-----------------------------------------------------------------------------
 
-const { iterator, traverse, types: t } = require("../api");
+const { iterator, traverse, replace, parse, evaluate, types: t } = require("../api");
 const { expect } = require("chai");
 const { spy } = require("sinon");
 
@@ -129,3 +129,74 @@ describe("iterator()", () => {
     });
 
 });
+
+describe("replace()", () => {
+
+    it("should visit nodes in the same order as traverse()", () => {
+        const root = t.document(t.array([t.number(1), t.object([t.member(t.string("a"), t.null())])]));
+        const traverseEnter = spy();
+        const traverseExit = spy();
+        const replaceEnter = spy();
+        const replaceExit = spy();
+
+        traverse(root, { enter: traverseEnter, exit: traverseExit });
+        replace(root, { enter: replaceEnter, exit: replaceExit });
+
+        expect(replaceEnter.args).to.deep.equal(traverseEnter.args);
+        expect(replaceExit.args).to.deep.equal(traverseExit.args);
+    });
+
+    it("should replace nodes returned from exit", () => {
+        const root = parse("{ \"a\": 1, \"b\": [2, true] }");
+
+        replace(root, {
+            exit(node) {
+                if (node.type === "Number") {
+                    return t.string(String(node.value), { loc: node.loc });
+                }
+
+                return undefined;
+            }
+        });
+
+        expect(evaluate(root)).to.deep.equal({ a: "1", b: ["2", true] });
+    });
+
+    it("should traverse nodes returned from enter", () => {
+        const root = t.document(t.null());
+        const visited = [];
+
+        replace(root, {
+            enter(node) {
+                visited.push(node.type);
+
+                if (node.type === "Null") {
+                    return t.array([t.boolean(false)]);
+                }
+
+                return undefined;
+            }
+        });
+
+        expect(visited).to.deep.equal(["Document", "Null", "Boolean"]);
+        expect(evaluate(root)).to.deep.equal([false]);
+    });
+
+    it("should return the replacement when the root is replaced", () => {
+        const root = t.document(t.number(1));
+        const replacement = t.document(t.number(2));
+        const result = replace(root, {
+            enter(node) {
+                return node.type === "Document" ? replacement : undefined;
+            }
+        });
+
+        expect(result).to.equal(replacement);
+    });
+
+    it("should return the original root when nothing is replaced", () => {
+        const root = t.document(t.number(1));
+        expect(replace(root, {})).to.equal(root);
+    });
+
+});
@@ -0,0 +1,80 @@
+/**
+ * @fileoverview Tests for the UTF-8 decoder
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+const { decodeUtf8 } = require("../api");
+const { expect } = require("chai");
+
+//-----------------------------------------------------------------------------
+// Helpers
+//-----------------------------------------------------------------------------
+
+/**
+ * Decodes bytes and returns the error.
+ * @param {Array<int>} bytes The bytes to decode.
+ * @returns {Error} The error.
+ */
+function getError(bytes) {
+    try {
+        decodeUtf8(Uint8Array.from(bytes));
+    } catch (error) {
+        return error;
+    }
+
+    throw new Error("Expected an error.");
+}
+
+//-----------------------------------------------------------------------------
+// Tests
+//-----------------------------------------------------------------------------
+
+describe("decodeUtf8()", () => {
+
+    [
+        "",
+        "{\"a\": 1}",
+        "café 水 😀",
+        "\ufeff[1]\r\n",
+        "x".repeat(10000) + "é"
+    ].forEach(text => {
+        it(`should decode ${JSON.stringify(text.slice(0, 20))}`, () => {
+            expect(decodeUtf8(Buffer.from(text, "utf8"))).to.equal(text);
+        });
+    });
+
+    [
+        ["a lone continuation byte", [0x61, 0x80], 1],
+        ["a truncated sequence", [0x61, 0xe6, 0xb0], 1],
+        ["a sequence interrupted by ASCII", [0xe6, 0x41, 0x41], 0],
+        ["an overlong encoding", [0xc0, 0xaf], 0],
+        ["an overlong three-byte encoding", [0xe0, 0x80, 0xaf], 0],
+        ["an encoded surrogate", [0xed, 0xa0, 0x80], 0],
+        ["a code point above U+10FFFF", [0xf4, 0x90, 0x80, 0x80], 0],
+        ["an invalid leading byte", [0xff], 0]
+    ].forEach(([description, bytes, byteOffset]) => {
+        it(`should throw InvalidUtf8 for ${description}`, () => {
+            const error = getError(bytes);
+
+            expect(error.code).to.equal("InvalidUtf8");
+            expect(error.byteOffset).to.equal(byteOffset);
+            expect(error.index).to.equal(byteOffset);
+        });
+    });
+
+    it("should report the location in the decoded text", () => {
+        const bytes = [...Buffer.from("{\r\n  \"é😀", "utf8"), 0xff];
+        const error = getError(bytes);
+
+        expect(error.message).to.equal("Invalid UTF-8 byte sequence found at byte 12. (2:7)");
+        expect(error.line).to.equal(2);
+        expect(error.column).to.equal(7);
+        expect(error.index).to.equal(9);
+        expect(error.byteOffset).to.equal(12);
+    });
+
+});
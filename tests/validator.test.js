@@ -0,0 +1,155 @@
+/**
+ * @fileoverview Tests for the streaming validator
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+const { createValidator } = require("../api");
+const { expect } = require("chai");
+const fs = require("fs");
+const path = require("path");
+
+//-----------------------------------------------------------------------------
+// Helpers
+//-----------------------------------------------------------------------------
+
+/**
+ * Validates text by pushing it in chunks of the given size.
+ * @param {string} text The text to validate.
+ * @param {int} size The number of characters in each chunk.
+ * @param {Object} [options] Options for the validator.
+ * @returns {Error|null} The error thrown or `null` if the text is valid.
+ */
+function validate(text, size, options) {
+    const validator = createValidator(options);
+
+    try {
+        for (let i = 0; i < text.length; i += size) {
+            validator.push(text.slice(i, i + size));
+        }
+
+        validator.finish();
+    } catch (ex) {
+        return ex;
+    }
+
+    return null;
+}
+
+/**
+ * Validates text in every chunk size and checks that the result is the same.
+ * @param {string} text The text to validate.
+ * @param {Object} [options] Options for the validator.
+ * @returns {Error|null} The error thrown or `null` if the text is valid.
+ */
+function validateInChunks(text, options) {
+    const expected = validate(text, text.length || 1, options);
+
+    for (let size = 1; size < text.length; size++) {
+        const actual = validate(text, size, options);
+
+        expect(actual && actual.message).to.equal(expected && expected.message, `Chunk size ${size}`);
+    }
+
+    return expected;
+}
+
+//-----------------------------------------------------------------------------
+// Tests
+//-----------------------------------------------------------------------------
+
+describe("createValidator()", () => {
+
+    [
+        "{}",
+        "[]",
+        "{ \"a\": [1, -2.5, 3e10, 0, -0.1E-2], \"b\": { \"c\": null } }",
+        "[true, false, null, \"a\\\"b\\\\c\\u00e9\\n\"]",
+        " \t\r\n\"hello\"\r\n",
+        "-1",
+        "\"😀\"",
+        "[1,\u00A0\u20282]"
+    ].forEach(text => {
+        it(`should accept ${JSON.stringify(text)} in any chunk size`, () => {
+            expect(validateInChunks(text)).to.be.null;
+        });
+    });
+
+    it("should accept a large document", () => {
+        const text = fs.readFileSync(path.resolve(__dirname, "fixtures/big/vue-package-lock.json"), "utf8");
+
+        expect(validate(text, 4096)).to.be.null;
+        expect(validate(text, 7)).to.be.null;
+    });
+
+    it("should accept comments when comments are enabled", () => {
+        expect(validateInChunks("/* a */ { // b\n\"c\": 1 /* d */ } // e", { comments: true })).to.be.null;
+    });
+
+    it("should reject comments by default", () => {
+        const error = validateInChunks("// a\n1");
+
+        expect(error.code).to.equal("CommentsNotAllowed");
+        expect(error.message).to.equal("Comments aren't allowed in JSON. Use the comments option to parse JSONC. (1:1)");
+    });
+
+    it("should reject a slash that doesn't start a comment", () => {
+        expect(validateInChunks("[1, /2]").message).to.equal("Unexpected character / found. (1:5)");
+        expect(validateInChunks("1 /").message).to.equal("Unexpected character / found. (1:3)");
+    });
+
+    [
+        ["[1,]", "UnexpectedChar", "Unexpected character ] found. (1:4)"],
+        ["{\"a\":1,}", "UnexpectedChar", "Unexpected character } found. (1:8)"],
+        ["[01]", "UnexpectedChar", "Unexpected character 1 found. (1:3)"],
+        ["{\"a\" 1}", "UnexpectedChar", "Unexpected character 1 found. (1:6)"],
+        ["[1 2]", "UnexpectedChar", "Unexpected character 2 found. (1:4)"],
+        ["[tru]", "UnexpectedChar", "Unexpected character ] found. (1:5)"],
+        ["\"a\tb\"", "UnexpectedChar", "Unexpected character \t found. (1:3)"],
+        ["\"\\x\"", "UnexpectedChar", "Unexpected character x found. (1:3)"],
+        ["\"\\u12g4\"", "UnexpectedChar", "Unexpected character g found. (1:6)"],
+        ["{\"a\":1}}", "UnexpectedChar", "Unexpected character } found. (1:8)"],
+        ["{\"a\":1]", "MismatchedBracket", "Mismatched closing bracket ] found; object opened at 1:1. (1:7)"],
+        ["[\r\n}", "MismatchedBracket", "Mismatched closing bracket } found; array opened at 1:1. (2:1)"],
+        ["{\r\n\"a\":\r\n x}", "UnexpectedChar", "Unexpected character x found. (3:2)"],
+        ["\"😀\" x", "UnexpectedChar", "Unexpected character x found. (1:6)"],
+        ["[1,\u200B2]", "UnexpectedChar", "Unexpected character \u200B found. (1:4)"],
+        ["\"abc", "UnexpectedEOF", "Unexpected end of input found. (1:5)"],
+        ["[1,", "UnexpectedEOF", "Unexpected end of input found. (1:4)"],
+        ["1.", "UnexpectedEOF", "Unexpected end of input found. (1:3)"],
+        ["nul", "UnexpectedEOF", "Unexpected end of input found. (1:4)"],
+        ["", "EmptyDocument", "Document is empty. (1:1)"],
+        [" \n ", "EmptyDocument", "Document is empty. (1:1)"]
+    ].forEach(([text, code, message]) => {
+        it(`should throw ${code} for ${JSON.stringify(text)} in any chunk size`, () => {
+            const error = validateInChunks(text);
+
+            expect(error.code).to.equal(code);
+            expect(error.message).to.equal(message);
+        });
+    });
+
+    it("should count a line break split across chunks once and throw the same error afterward", () => {
+        const validator = createValidator();
+
+        validator.push("[1,\r");
+
+        let error;
+
+        try {
+            validator.push("\nx");
+        } catch (ex) {
+            error = ex;
+        }
+
+        expect(error.line).to.equal(2);
+        expect(error.column).to.equal(1);
+        expect(error.index).to.equal(5);
+        expect(() => validator.push("2]")).to.throw(error.message);
+        expect(() => validator.finish()).to.throw(error.message);
+    });
+
+});
@@ -0,0 +1,111 @@
+/**
+ * @fileoverview Benchmarks comparing Momoa with other JSON parsers on
+ *      real-world fixtures. `jsonc-parser` is only included when it's
+ *      installed.
+ * @author Nicholas C. Zakas
+ */
+
+//-----------------------------------------------------------------------------
+// Imports
+//-----------------------------------------------------------------------------
+
+const Benchmark = require("benchmark");
+const benchmarks = require("beautify-benchmark");
+const { parse } = require("../");
+const { parseValue, parseJsonc } = require("../compat");
+const jsonToAst = require("json-to-ast");
+const fs = require("fs");
+const path = require("path");
+
+let jsoncParser = null;
+
+try {
+    jsoncParser = require("jsonc-parser");
+} catch (error) {
+    console.log("jsonc-parser isn't installed, so it will be skipped.");
+}
+
+//-----------------------------------------------------------------------------
+// Data
+//-----------------------------------------------------------------------------
+
+const fixturesPath = "./tests/fixtures/big";
+const fixtures = fs.readdirSync(fixturesPath)
+    .filter(fileName => fileName.endsWith(".json"))
+    .map(fileName => ({
+        name: fileName,
+        text: fs.readFileSync(path.join(fixturesPath, fileName), "utf8")
+    }));
+
+//-----------------------------------------------------------------------------
+// Helpers
+//-----------------------------------------------------------------------------
+
+let lastResult = null;
+
+/**
+ * Measures how much the heap grows while parsing a text. This is only an
+ * estimate of allocations because garbage collection can happen during
+ * parsing, so it's most accurate with `node --expose-gc`.
+ * @param {Function} fn The function to measure.
+ * @returns {string} The growth in megabytes.
+ */
+function measureHeap(fn) {
+
+    if (global.gc) {
+        global.gc();
+    }
+
+    const before = process.memoryUsage().heapUsed;
+
+    // keep the result alive until after measuring
+    lastResult = fn();
+
+    const after = process.memoryUsage().heapUsed;
+
+    lastResult = null;
+
+    return ((after - before) / 1024 / 1024).toFixed(2);
+}
+
+//-----------------------------------------------------------------------------
+// Tests
+//-----------------------------------------------------------------------------
+
+fixtures.forEach(({ name, text }) => {
+
+    const parsers = {
+        "Momoa parse()": () => parse(text),
+        "Momoa parseValue()": () => parseValue(text),
+        "Momoa parseJsonc()": () => parseJsonc(text),
+        "json-to-ast": () => jsonToAst(text),
+        "JSON.parse()": () => JSON.parse(text)
+    };
+
+    if (jsoncParser) {
+        parsers["jsonc-parser parse()"] = () => jsoncParser.parse(text);
+        parsers["jsonc-parser parseTree()"] = () => jsoncParser.parseTree(text);
+    }
+
+    console.log(`\n${ name } (${ (text.length / 1024).toFixed(0) } KB)\n`);
+    console.log("Heap growth (MB):");
+
+    Object.entries(parsers).forEach(([parserName, fn]) => {
+        console.log(`  ${ parserName }: ${ measureHeap(fn) }`);
+    });
+
+    const suite = new Benchmark.Suite();
+
+    Object.entries(parsers).forEach(([parserName, fn]) => {
+        suite.add(parserName, fn);
+    });
+
+    suite
+        .on("cycle", event => {
+            benchmarks.add(event.target);
+        })
+        .on("complete", () => {
+            benchmarks.log();
+        })
+        .run();
+});
@@ -9,7 +9,7 @@
 
 const Benchmark = require("benchmark");
 const benchmarks = require("beautify-benchmark");
-const { parse, tokens, tokenize } = require("../");
+const { parse, tokens, tokenize, tokenizeColumnar } = require("../");
 const parse2 = require("json-to-ast");
 const parse3 = require("./json-parse.js");
 const fs = require("fs");
@@ -20,6 +20,11 @@ const fs = require("fs");
 
 const vuePkgLock = fs.readFileSync("./tests/fixtures/big/vue-package-lock.json", "utf8");
 
+// mostly numbers, like a data file, to measure number scanning
+const numbers = JSON.stringify(
+    Array.from({ length: 20000 }, (_, i) => [i * 1.5e-3, -i, i % 7 === 0])
+);
+
 //-----------------------------------------------------------------------------
 // Tests
 //-----------------------------------------------------------------------------
@@ -37,6 +42,15 @@ suite
 .add("parseJson.js", () => {
     const result = parse3(vuePkgLock);
 })
+.add("Momoa (numbers)", () => {
+    const result = parse(numbers);
+})
+.add("Momoa tokenize() (numbers)", () => {
+    const result = tokenize(numbers);
+})
+.add("Momoa tokenizeColumnar() (numbers)", () => {
+    const result = tokenizeColumnar(numbers);
+})
 .on("cycle", (event) => {
     benchmarks.add(event.target);
 })
@@ -1,6 +1,7 @@
 
 /**
- * @fileoverview Tool to generate test data files
+ * @fileoverview Tool to generate test data files. Run with `--check` to
+ *      report the files that are out of date without changing them.
  * @author Nicholas C. Zakas
  */
 
@@ -31,6 +32,9 @@ const unknownInput = [
 // Main
 //-----------------------------------------------------------------------------
 
+const check = process.argv.includes("--check");
+const outdated = [];
+
 const astsPath = "./tests/fixtures/asts";
 fs.readdirSync(astsPath).forEach(fileName => {
 
@@ -42,5 +46,19 @@ fs.readdirSync(astsPath).forEach(fileName => {
     const text = contents.slice(0, separatorIndex - 1);
     const json = contents.slice(separatorIndex + 4).trim();
     const result = parse(text, { tokens: true, comments: true, ranges: true });
-    fs.writeFileSync(filePath, text + "\n---\n" + JSON.stringify(result, null, "    "), "utf8");
+    const expected = JSON.stringify(result, null, "    ");
+
+    if (check) {
+        if (json !== expected) {
+            outdated.push(filePath);
+        }
+    } else {
+        fs.writeFileSync(filePath, text + "\n---\n" + expected, "utf8");
+    }
 });
+
+if (outdated.length) {
+    console.error("These files are out of date. Run `npm run regen` to update them:");
+    outdated.forEach(filePath => console.error(`  ${ filePath }`));
+    process.exitCode = 1;
+}